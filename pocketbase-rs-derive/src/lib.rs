@@ -0,0 +1,309 @@
+//! Derive macros for `pocketbase-rs`.
+//!
+//! This crate is not meant to be used directly: enable the `derive` feature of
+//! `pocketbase-rs` instead.
+
+#![deny(missing_docs)]
+#![warn(clippy::nursery)]
+#![warn(clippy::pedantic)]
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::{Data, DeriveInput, Fields, FieldsNamed, LitStr, Token, parse_macro_input};
+
+/// Derives `pocketbase_rs::PocketBaseRecord` for a struct.
+///
+/// The collection name is given with `#[pocketbase(collection = "...")]`. The id is read
+/// from the `id` field, or from the field marked with `#[pocketbase(id)]`.
+///
+/// The derive also generates a `<Name>Fields` struct holding a `pocketbase_rs::Field` per
+/// struct field, returned by `<Name>::fields()`. The field names follow the
+/// `#[serde(rename = "...")]` and `#[serde(rename_all = "...")]` attributes, and fields
+/// marked with `#[serde(skip)]` or `#[serde(flatten)]` are left out.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize, PocketBaseRecord)]
+/// #[pocketbase(collection = "articles")]
+/// struct Article {
+///     id: String,
+///     title: String,
+/// }
+///
+/// let filter = Article::fields().title.like("rust");
+/// ```
+#[proc_macro_derive(PocketBaseRecord, attributes(pocketbase))]
+pub fn derive_pocketbase_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let collection = collection_name(input)?;
+    let fields = named_fields(input)?;
+    let id_field = id_field(input, fields)?;
+    let field_names = field_names(input, fields)?;
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let fields_name = format_ident!("{name}Fields");
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields_doc = format!("The fields of [`{name}`], returned by [`{name}::fields()`].");
+    let field_idents = field_names.iter().map(|(ident, _)| ident);
+    let field_docs = field_names
+        .iter()
+        .map(|(_, field_name)| format!("The `{field_name}` field."));
+    let field_values = field_names.iter().map(|(ident, field_name)| {
+        quote! { #ident: ::pocketbase_rs::Field::new(#field_name) }
+    });
+
+    Ok(quote! {
+        #[doc = #fields_doc]
+        #[derive(Clone, Copy, Debug)]
+        #vis struct #fields_name {
+            #(
+                #[doc = #field_docs]
+                pub #field_idents: ::pocketbase_rs::Field,
+            )*
+        }
+
+        impl #impl_generics #name #type_generics #where_clause {
+            /// Returns the fields of the record, to build filters and sorts without typos.
+            #[must_use]
+            #vis const fn fields() -> #fields_name {
+                #fields_name {
+                    #(#field_values,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::pocketbase_rs::PocketBaseRecord for #name #type_generics #where_clause {
+            const COLLECTION: &'static str = #collection;
+
+            fn id(&self) -> &str {
+                ::core::convert::AsRef::<str>::as_ref(&self.#id_field)
+            }
+        }
+    })
+}
+
+/// Reads the `#[pocketbase(collection = "...")]` attribute of the struct.
+fn collection_name(input: &DeriveInput) -> syn::Result<LitStr> {
+    let mut collection = None;
+
+    for attribute in &input.attrs {
+        if !attribute.path().is_ident("pocketbase") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("collection") {
+                collection = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported attribute, expected `collection = \"...\"`"))
+            }
+        })?;
+    }
+
+    collection.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "missing `#[pocketbase(collection = \"...\")]` attribute",
+        )
+    })
+}
+
+/// Returns the named fields of the struct.
+fn named_fields(input: &DeriveInput) -> syn::Result<&FieldsNamed> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "PocketBaseRecord can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "PocketBaseRecord can only be derived for structs with named fields",
+        ));
+    };
+
+    Ok(fields)
+}
+
+/// Finds the field marked with `#[pocketbase(id)]`, or the field named `id`.
+fn id_field(input: &DeriveInput, fields: &FieldsNamed) -> syn::Result<syn::Ident> {
+    let mut marked = None;
+
+    for field in &fields.named {
+        for attribute in &field.attrs {
+            if !attribute.path().is_ident("pocketbase") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("id") {
+                    marked.clone_from(&field.ident);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported attribute, expected `id`"))
+                }
+            })?;
+        }
+    }
+
+    marked
+        .or_else(|| {
+            fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.clone())
+                .find(|ident| ident == "id")
+        })
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                &input.ident,
+                "missing `id` field, mark the id field with `#[pocketbase(id)]`",
+            )
+        })
+}
+
+/// Returns the struct fields along with their serialized names, following the serde attributes.
+fn field_names(
+    input: &DeriveInput,
+    fields: &FieldsNamed,
+) -> syn::Result<Vec<(syn::Ident, String)>> {
+    let mut rename_all = None;
+
+    for attribute in &input.attrs {
+        if !attribute.path().is_ident("serde") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                if meta.input.peek(Token![=]) {
+                    rename_all = Some(meta.value()?.parse::<LitStr>()?);
+                } else {
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("deserialize") {
+                            rename_all = Some(meta.value()?.parse::<LitStr>()?);
+                            Ok(())
+                        } else {
+                            skip_serde_meta(&meta)
+                        }
+                    })?;
+                }
+                Ok(())
+            } else {
+                skip_serde_meta(&meta)
+            }
+        })?;
+    }
+
+    let mut names = Vec::new();
+
+    for field in &fields.named {
+        let Some(ident) = field.ident.clone() else {
+            continue;
+        };
+
+        let mut rename = None;
+        let mut skipped = false;
+
+        for attribute in &field.attrs {
+            if !attribute.path().is_ident("serde") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") || meta.path.is_ident("flatten") {
+                    skipped = true;
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    if meta.input.peek(Token![=]) {
+                        rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    } else {
+                        meta.parse_nested_meta(|meta| {
+                            if meta.path.is_ident("deserialize") {
+                                rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                                Ok(())
+                            } else {
+                                skip_serde_meta(&meta)
+                            }
+                        })?;
+                    }
+                    Ok(())
+                } else {
+                    skip_serde_meta(&meta)
+                }
+            })?;
+        }
+
+        if skipped {
+            continue;
+        }
+
+        let name = match (rename, &rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => apply_rename_rule(&ident.unraw().to_string(), rule)?,
+            (None, None) => ident.unraw().to_string(),
+        };
+
+        names.push((ident, name));
+    }
+
+    Ok(names)
+}
+
+/// Consumes a serde attribute item that has no effect on the field names.
+fn skip_serde_meta(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| skip_serde_meta(&meta))?;
+    }
+
+    Ok(())
+}
+
+/// Applies a serde `rename_all` rule to a `snake_case` field name.
+fn apply_rename_rule(field: &str, rule: &LitStr) -> syn::Result<String> {
+    let capitalize = |word: &str| {
+        let mut chars = word.chars();
+        chars.next().map_or_else(String::new, |first| {
+            first.to_uppercase().chain(chars).collect()
+        })
+    };
+
+    let words = field.split('_');
+
+    Ok(match rule.value().as_str() {
+        "lowercase" | "snake_case" => field.to_owned(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_uppercase(),
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_uppercase().replace('_', "-"),
+        "PascalCase" => words.map(capitalize).collect(),
+        "camelCase" => {
+            let pascal: String = words.map(capitalize).collect();
+            let mut chars = pascal.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_lowercase().chain(chars).collect()
+            })
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                rule,
+                "unknown serde `rename_all` rule",
+            ));
+        }
+    })
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tokio_util::sync::CancellationToken;
+
+use crate::PocketBase;
+
+/// The pending requests that can be cancelled by a newer request with the same key.
+#[derive(Debug, Default)]
+pub struct PendingRequests {
+    entries: Mutex<HashMap<String, (u64, CancellationToken)>>,
+    next_id: AtomicU64,
+}
+
+/// A request registered in [`PendingRequests`], unregistered once dropped.
+pub struct PendingRequest {
+    requests: Arc<PendingRequests>,
+    key: String,
+    id: u64,
+    token: CancellationToken,
+}
+
+impl PendingRequests {
+    /// Registers a request, cancelling the pending request with the same key.
+    ///
+    /// The returned token is cancelled by the next request with the same key, and when the
+    /// given parent token is cancelled.
+    pub fn register(
+        self: &Arc<Self>,
+        key: String,
+        parent: Option<&CancellationToken>,
+    ) -> PendingRequest {
+        let token = parent.map_or_else(CancellationToken::new, CancellationToken::child_token);
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+
+        let previous = self
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.clone(), (id, token.clone()));
+
+        if let Some((_, previous)) = previous {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(request_key = %key, "cancelling duplicate PocketBase request");
+
+            previous.cancel();
+        }
+
+        PendingRequest {
+            requests: self.clone(),
+            key,
+            id,
+            token,
+        }
+    }
+
+    fn cancel(&self, key: &str) {
+        let entry = self
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(key);
+
+        if let Some((_, token)) = entry {
+            token.cancel();
+        }
+    }
+
+    fn cancel_all(&self) {
+        let entries =
+            std::mem::take(&mut *self.entries.lock().unwrap_or_else(PoisonError::into_inner));
+
+        for (_, token) in entries.into_values() {
+            token.cancel();
+        }
+    }
+}
+
+impl PendingRequest {
+    /// Returns the token cancelling the request.
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+}
+
+impl Drop for PendingRequest {
+    fn drop(&mut self) {
+        let mut entries = self
+            .requests
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // The entry may already belong to a newer request with the same key
+        if entries.get(&self.key).is_some_and(|(id, _)| *id == self.id) {
+            entries.remove(&self.key);
+        }
+    }
+}
+
+impl PocketBase {
+    /// Enables or disables the auto-cancellation of duplicate requests.
+    ///
+    /// When enabled, sending a read (`get_list()`, `get_one()`, `get_first_list_item()` or
+    /// `count()`) cancels the pending read with the same method and URL, e.g. when a list is
+    /// queried on every keystroke of a search field. The cancelled request returns a
+    /// `Cancelled` error. This mirrors the auto-cancellation of the JS SDK.
+    ///
+    /// A request can opt out with `.no_auto_cancel()`, or be given a key of its own with
+    /// `.request_key()`, which also enables the cancellation for it when disabled on the client.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.set_auto_cancellation(true);
+    /// ```
+    pub fn set_auto_cancellation(&self, enabled: bool) {
+        self.auto_cancellation.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether the auto-cancellation of duplicate requests is enabled.
+    pub(crate) fn auto_cancellation(&self) -> bool {
+        self.auto_cancellation.load(Ordering::Relaxed)
+    }
+
+    /// Cancels the pending request with the given key, if any.
+    ///
+    /// The key of a request is the one given with `.request_key()`, or its method and URL
+    /// (e.g. `GET http://localhost:8090/api/collections/articles/records?page=1`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.cancel_request("articles-search");
+    /// ```
+    pub fn cancel_request(&self, key: &str) {
+        self.pending_requests.cancel(key);
+    }
+
+    /// Cancels all the pending requests that can be auto-cancelled.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // e.g. when leaving a page
+    /// pb.cancel_all_requests();
+    /// ```
+    pub fn cancel_all_requests(&self) {
+        self.pending_requests.cancel_all();
+    }
+}
@@ -0,0 +1,131 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use super::Backups;
+use crate::PocketBase;
+use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+
+#[derive(Clone, Default, Serialize)]
+struct BackupCreateRequest<'a> {
+    #[serde(skip_serializing_if = "str::is_empty")]
+    name: &'a str,
+}
+
+/// Builder for creating a new backup.
+///
+/// The builder can be awaited directly, or executed through [`BackupCreateBuilder::call()`].
+pub struct BackupCreateBuilder<'a> {
+    client: &'a PocketBase,
+    name: &'a str,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Backups<'a> {
+    /// Create a new backup of the current `PocketBase` instance.
+    ///
+    /// `name` must be a `.zip` file name (e.g. `nightly.zip`). When empty,
+    /// `PocketBase` generates one.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.backups()
+    ///     .create("nightly.zip")
+    ///     .timeout(Duration::from_mins(10))
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn create(&self, name: &'a str) -> BackupCreateBuilder<'a> {
+        BackupCreateBuilder {
+            client: self.client,
+            name,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> BackupCreateBuilder<'a> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_mins(10))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<(), RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<(), RequestError> {
+        let url = format!("{}/api/backups", self.client.base_url);
+
+        let body = BackupCreateRequest { name: self.name };
+
+        let response = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_post_json(&url, &body)),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RequestError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> IntoFuture for BackupCreateBuilder<'a> {
+    type Output = Result<(), RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
@@ -0,0 +1,140 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use tokio_util::sync::CancellationToken;
+
+use super::Backups;
+use crate::PocketBase;
+use crate::error::{ApiErrorResponse, RequestError};
+use crate::files::download::FileDownload;
+use crate::options::{RequestOptions, cancellable};
+
+/// Builder for downloading a backup.
+///
+/// The builder can be awaited directly, or executed through [`BackupDownloadBuilder::call()`].
+pub struct BackupDownloadBuilder<'a> {
+    client: &'a PocketBase,
+    key: &'a str,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Backups<'a> {
+    /// Download a single backup file.
+    ///
+    /// A superuser file token is automatically requested to access the file.
+    /// The returned [`FileDownload`] can be streamed, avoiding to buffer the whole backup in memory.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use futures_util::StreamExt;
+    ///
+    /// let backup = pb
+    ///     .backups()
+    ///     .download("nightly.zip")
+    ///     .timeout(Duration::from_hours(1))
+    ///     .await?;
+    /// let mut stream = backup.stream();
+    ///
+    /// while let Some(chunk) = stream.next().await {
+    ///     file.write_all(&chunk?).await?;
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn download(&self, key: &'a str) -> BackupDownloadBuilder<'a> {
+        BackupDownloadBuilder {
+            client: self.client,
+            key,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> BackupDownloadBuilder<'a> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the backup has been read.
+    /// Streaming a large backup can take longer than the client timeout.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_hours(1))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the backup file.
+    pub async fn call(self) -> Result<FileDownload, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<FileDownload, RequestError> {
+        let token = self.client.files().get_token().await?;
+
+        let url = self.client.backups().url(&[self.key]);
+
+        let request = self.options.apply(
+            self.client
+                .with_authorization_token(self.client.reqwest_client.get(&url))
+                .query(&[("token", token)]),
+        );
+
+        let response = self.client.send_request(request).await?;
+
+        match response.status() {
+            reqwest::StatusCode::OK => Ok(FileDownload { response }),
+            reqwest::StatusCode::BAD_REQUEST => Err(RequestError::BadRequest(
+                ApiErrorResponse::from_response(response).await,
+            )),
+            reqwest::StatusCode::UNAUTHORIZED => Err(RequestError::Unauthorized),
+            reqwest::StatusCode::FORBIDDEN => Err(RequestError::Forbidden),
+            reqwest::StatusCode::NOT_FOUND => Err(RequestError::NotFound),
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(RequestError::TooManyRequests),
+            _ => Err(RequestError::from_response(response).await),
+        }
+    }
+}
+
+impl<'a> IntoFuture for BackupDownloadBuilder<'a> {
+    type Output = Result<FileDownload, RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
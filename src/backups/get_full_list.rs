@@ -0,0 +1,118 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use tokio_util::sync::CancellationToken;
+
+use super::{BackupFileInfo, Backups};
+use crate::PocketBase;
+use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+
+/// Builder for fetching the list of all available backup files.
+///
+/// The builder can be awaited directly, or executed through [`BackupGetFullListBuilder::call()`].
+pub struct BackupGetFullListBuilder<'a> {
+    client: &'a PocketBase,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Backups<'a> {
+    /// Fetch the list of all available backup files.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let backups = pb.backups().get_full_list().await?;
+    ///
+    /// for backup in backups {
+    ///     println!("{} ({} bytes)", backup.key, backup.size);
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn get_full_list(&self) -> BackupGetFullListBuilder<'a> {
+        BackupGetFullListBuilder {
+            client: self.client,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> BackupGetFullListBuilder<'a> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(30))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<Vec<BackupFileInfo>, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<Vec<BackupFileInfo>, RequestError> {
+        let url = format!("{}/api/backups", self.client.base_url);
+
+        let response = self
+            .client
+            .send_request(self.options.apply(self.client.request_get(&url, None)))
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RequestError::from_response(response).await);
+        }
+
+        response
+            .json::<Vec<BackupFileInfo>>()
+            .await
+            .map_err(|error| RequestError::ParseError(error.to_string()))
+    }
+}
+
+impl<'a> IntoFuture for BackupGetFullListBuilder<'a> {
+    type Output = Result<Vec<BackupFileInfo>, RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
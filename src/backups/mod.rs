@@ -0,0 +1,179 @@
+use serde::Deserialize;
+
+use crate::PocketBase;
+
+pub mod create;
+pub mod delete;
+pub mod download;
+pub mod get_full_list;
+pub mod restore;
+pub mod upload;
+
+/// Provides access to the backups API of a `PocketBase` instance.
+///
+/// Instances of this struct are created using the [`PocketBase::backups`] method.
+/// All operations require the client to be authenticated as a superuser.
+pub struct Backups<'a> {
+    pub(crate) client: &'a PocketBase,
+}
+
+/// Represents a single backup file.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct BackupFileInfo {
+    /// The backup file name, used as its identifier.
+    pub key: String,
+    /// The backup file size in bytes.
+    pub size: u64,
+    /// The timestamp when the backup file was last modified.
+    pub modified: String,
+}
+
+impl Backups<'_> {
+    /// Returns the URL of a backups endpoint, with the given path segments percent-encoded
+    /// so that a key can't change the path or the query of the request.
+    fn url(&self, segments: &[&str]) -> String {
+        let base_url = &self.client.base_url;
+
+        // Base URLs are validated when creating the client, this is only a safety net.
+        let Ok(mut url) = reqwest::Url::parse(base_url) else {
+            return format!("{base_url}/api/backups/{}", segments.join("/"));
+        };
+
+        if let Ok(mut path_segments) = url.path_segments_mut() {
+            path_segments
+                .pop_if_empty()
+                .extend(["api", "backups"])
+                .extend(segments);
+        }
+
+        url.to_string()
+    }
+}
+
+impl PocketBase {
+    /// Creates a new [`Backups`] instance to manage the backups of the `PocketBase` instance.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.admin_auth_with_password("admin@example.com", "YOUR_PASSWORD")
+    ///     .await?;
+    ///
+    /// pb.backups().create("nightly.zip").await?;
+    /// ```
+    #[must_use]
+    pub const fn backups(&self) -> Backups<'_> {
+        Backups { client: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::PocketBase;
+    use crate::error::RequestError;
+
+    #[tokio::test]
+    async fn timeouts_are_unreachable() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/api/backups");
+            then.status(200).delay(Duration::from_secs(5)).body("[]");
+        });
+
+        let pb = PocketBase::builder(&server.base_url())
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let result = pb.backups().get_full_list().await;
+
+        assert!(matches!(result, Err(RequestError::Unreachable)));
+    }
+
+    #[tokio::test]
+    async fn download_timeout_overrides_the_client_timeout() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(POST).path("/api/files/token");
+            then.status(200).json_body(json!({ "token": "file_token" }));
+        });
+
+        server.mock(|when, then| {
+            when.method(GET).path("/api/backups/nightly.zip");
+            then.status(200)
+                .delay(Duration::from_millis(300))
+                .body("zip");
+        });
+
+        let pb = PocketBase::builder(&server.base_url())
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let backup = pb
+            .backups()
+            .download("nightly.zip")
+            .timeout(Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        assert_eq!(backup.bytes().await.unwrap(), "zip");
+    }
+
+    #[tokio::test]
+    async fn keys_are_path_encoded() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(POST).path("/api/files/token");
+            then.status(200).json_body(json!({ "token": "file_token" }));
+        });
+
+        let download = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/backups/nightly%2F..%3Fa%23b.zip")
+                .query_param("token", "file_token");
+            then.status(200).body("zip");
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let backup = pb.backups().download("nightly/..?a#b.zip").await.unwrap();
+
+        assert_eq!(backup.bytes().await.unwrap(), "zip");
+        download.assert();
+    }
+
+    #[tokio::test]
+    async fn missing_backup() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(POST).path("/api/files/token");
+            then.status(200).json_body(json!({ "token": "file_token" }));
+        });
+
+        server.mock(|when, then| {
+            when.method(GET).path("/api/backups/nightly.zip");
+            then.status(404).json_body(json!({
+                "status": 404,
+                "message": "The requested resource wasn't found.",
+                "data": {},
+            }));
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let result = pb.backups().download("nightly.zip").await;
+
+        assert!(matches!(result, Err(RequestError::NotFound)));
+    }
+}
@@ -0,0 +1,134 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use reqwest::multipart::{Form, Part};
+use tokio_util::sync::CancellationToken;
+
+use super::Backups;
+use crate::PocketBase;
+use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+
+/// Builder for uploading a backup.
+///
+/// The builder can be awaited directly, or executed through [`BackupUploadBuilder::call()`].
+pub struct BackupUploadBuilder<'a> {
+    client: &'a PocketBase,
+    name: &'a str,
+    zip: reqwest::Body,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Backups<'a> {
+    /// Upload an existing backup file.
+    ///
+    /// `name` is the `.zip` file name used as the backup key. `zip` accepts anything that
+    /// can be turned into a request body, including a stream (see [`reqwest::Body::wrap_stream`]).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let zip = std::fs::read("./nightly.zip")?;
+    ///
+    /// pb.backups()
+    ///     .upload("nightly.zip", zip)
+    ///     .timeout(Duration::from_mins(30))
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn upload(&self, name: &'a str, zip: impl Into<reqwest::Body>) -> BackupUploadBuilder<'a> {
+        BackupUploadBuilder {
+            client: self.client,
+            name,
+            zip: zip.into(),
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> BackupUploadBuilder<'a> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read,
+    /// including sending the backup.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_mins(10))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<(), RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<(), RequestError> {
+        let url = format!("{}/api/backups/upload", self.client.base_url);
+
+        let part = Part::stream(self.zip)
+            .file_name(self.name.to_owned())
+            .mime_str("application/zip")?;
+
+        let form = Form::new().part("file", part);
+
+        let response = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_post_form(&url, form)),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RequestError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> IntoFuture for BackupUploadBuilder<'a> {
+    type Output = Result<(), RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
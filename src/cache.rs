@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use reqwest::header::{AUTHORIZATION, ETAG, HeaderMap, HeaderValue, IF_NONE_MATCH};
+use reqwest::{Request, RequestBuilder, Response, StatusCode};
+
+use crate::{PocketBase, TransportError};
+
+/// The maximum amount of responses kept in the cache, the oldest ones being evicted first.
+const MAX_ENTRIES: usize = 1024;
+
+/// In-memory cache of the responses of the requests sent with `.cache(ttl)`.
+#[derive(Debug, Default)]
+pub struct ResponseCache {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+    hasher: RandomState,
+}
+
+#[derive(Clone, Debug)]
+struct CachedResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    etag: Option<HeaderValue>,
+    stored_at: Instant,
+}
+
+impl CachedResponse {
+    fn to_response(&self) -> Response {
+        let mut response = http::Response::new(self.body.clone());
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers.clone();
+
+        Response::from(response)
+    }
+}
+
+impl ResponseCache {
+    /// Returns the cache key of a request: its method, URL and (hashed) auth token.
+    fn key(&self, request: &Request) -> String {
+        let auth = request
+            .headers()
+            .get(AUTHORIZATION)
+            .map_or(0, |token| self.hasher.hash_one(token.as_bytes()));
+
+        format!("{} {} {auth:x}", request.method(), request.url())
+    }
+
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned()
+    }
+
+    fn insert(&self, key: String, response: CachedResponse) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if entries.len() >= MAX_ENTRIES
+            && !entries.contains_key(&key)
+            && let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, response)| response.stored_at)
+                .map(|(key, _)| key.clone())
+        {
+            entries.remove(&oldest);
+        }
+
+        entries.insert(key, response);
+    }
+
+    fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl PocketBase {
+    /// Clears the responses cached by the requests sent with `.cache(ttl)`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.collection("categories").update::<Category>("record_id_123", category).call().await?;
+    ///
+    /// // Don't serve the outdated categories from the cache
+    /// pb.clear_cache();
+    /// ```
+    pub fn clear_cache(&self) {
+        self.response_cache.clear();
+    }
+
+    /// Sends the request, serving it from the cache when a response younger than `ttl` was stored.
+    ///
+    /// Outdated responses having an `ETag` are revalidated with an `If-None-Match` request.
+    /// Only successful responses are cached. Without a `ttl`, the request is sent as-is.
+    pub(crate) async fn send_cached(
+        &self,
+        ttl: Option<Duration>,
+        request: RequestBuilder,
+    ) -> Result<Response, TransportError> {
+        let Some(ttl) = ttl else {
+            return self.send_request(request).await;
+        };
+
+        let (http_client, request) = request.build_split();
+        let mut request = request?;
+
+        let key = self.response_cache.key(&request);
+        let cached = self.response_cache.get(&key);
+
+        if let Some(cached) = &cached {
+            if cached.stored_at.elapsed() < ttl {
+                return Ok(cached.to_response());
+            }
+
+            if let Some(etag) = &cached.etag {
+                request.headers_mut().insert(IF_NONE_MATCH, etag.clone());
+            }
+        }
+
+        let response = self
+            .send_request(RequestBuilder::from_parts(http_client, request))
+            .await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(mut cached) = cached
+        {
+            cached.stored_at = Instant::now();
+            self.response_cache.insert(key, cached.clone());
+
+            return Ok(cached.to_response());
+        }
+
+        if !response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let cached = CachedResponse {
+            status,
+            etag: headers.get(ETAG).cloned(),
+            headers,
+            body,
+            stored_at: Instant::now(),
+        };
+
+        let response = cached.to_response();
+        self.response_cache.insert(key, cached);
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,313 @@
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use reqwest::header::{CONTENT_TYPE, HeaderName};
+use reqwest::{Response, StatusCode};
+
+use crate::TransportError;
+
+/// Header set on the responses of the requests rejected by an open circuit breaker.
+const CIRCUIT_OPEN_HEADER: HeaderName = HeaderName::from_static("x-pocketbase-rs-circuit-open");
+
+/// Policy used to stop sending requests to an unreachable `PocketBase` instance.
+///
+/// After `failure_threshold` consecutive connection errors or timeouts, the circuit opens:
+/// every request then fails fast with [`RequestError::CircuitOpen`](crate::RequestError::CircuitOpen)
+/// for the `cooldown` duration, without reaching the network (the operations having their own
+/// error type fail with their `CircuitOpen` variant). Once the cooldown is over, a single request
+/// is sent to probe the instance, the others failing fast until it completes: the circuit closes
+/// if it gets a response, and opens again otherwise.
+///
+/// The circuit is shared by all the clones of the client.
+///
+/// # Example
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use pocketbase_rs::{CircuitBreaker, PocketBase};
+///
+/// let pb = PocketBase::builder("http://localhost:8090")
+///     .circuit_breaker(CircuitBreaker {
+///         failure_threshold: 3,
+///         cooldown: Duration::from_secs(10),
+///     })
+///     .build()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    /// The amount of consecutive connection errors or timeouts opening the circuit *(default to 5)*.
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before a request is sent again *(default to 30s)*.
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The state of a [`CircuitBreaker`], shared by the clones of a client.
+#[derive(Debug)]
+pub struct CircuitBreakerState {
+    policy: CircuitBreaker,
+    circuit: Mutex<Circuit>,
+}
+
+#[derive(Debug, Default)]
+struct Circuit {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+    /// Whether a request probing the instance after the cooldown is in flight.
+    probing: bool,
+}
+
+/// A request let through the circuit, whose outcome is reported with [`Permit::record()`].
+pub struct Permit<'a> {
+    state: &'a CircuitBreakerState,
+    probe: bool,
+}
+
+impl CircuitBreakerState {
+    /// Creates a closed circuit, to be shared by the clones of a client.
+    pub fn shared(policy: CircuitBreaker) -> Arc<Self> {
+        Arc::new(Self {
+            policy,
+            circuit: Mutex::default(),
+        })
+    }
+
+    /// Lets a request through, or returns `None` if it should fail fast.
+    ///
+    /// Once the cooldown is over, only the first request is let through, until it completes.
+    pub fn acquire(&self) -> Option<Permit<'_>> {
+        let mut circuit = self.circuit.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let probe = match circuit.open_until {
+            None => false,
+            Some(open_until) if Instant::now() < open_until || circuit.probing => return None,
+            Some(_) => {
+                circuit.probing = true;
+                true
+            }
+        };
+        drop(circuit);
+
+        Some(Permit { state: self, probe })
+    }
+
+    /// Updates the circuit with the outcome of a request.
+    fn record(&self, result: &Result<Response, TransportError>) {
+        let mut circuit = self.circuit.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match result {
+            Err(error) if error.is_connect() || error.is_timeout() => {
+                circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+
+                if circuit.consecutive_failures >= self.policy.failure_threshold {
+                    circuit.open_until = Some(Instant::now() + self.policy.cooldown);
+
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        failures = circuit.consecutive_failures,
+                        cooldown = ?self.policy.cooldown,
+                        "PocketBase circuit breaker opened"
+                    );
+                }
+            }
+            Err(_) => {}
+            Ok(_) => *circuit = Circuit::default(),
+        }
+    }
+}
+
+impl Permit<'_> {
+    /// Updates the circuit with the outcome of the request.
+    pub fn record(self, result: &Result<Response, TransportError>) {
+        self.state.record(result);
+    }
+}
+
+impl Drop for Permit<'_> {
+    /// Lets another request probe the instance, including when this one was cancelled.
+    fn drop(&mut self) {
+        if self.probe {
+            self.state
+                .circuit
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .probing = false;
+        }
+    }
+}
+
+/// Builds the response returned in place of a request rejected by an open circuit.
+pub fn open_response() -> Response {
+    let body = serde_json::json!({
+        "status": 503,
+        "message": "The circuit breaker is open, the request was not sent.",
+        "data": {},
+    });
+
+    let response = http::Response::builder()
+        .status(StatusCode::SERVICE_UNAVAILABLE)
+        .header(CONTENT_TYPE, "application/json")
+        .header(CIRCUIT_OPEN_HEADER, "1")
+        .body(body.to_string())
+        .unwrap_or_default();
+
+    Response::from(response)
+}
+
+/// Returns `true` if the response was returned in place of a request rejected by an open circuit.
+pub fn is_open_response(response: &Response) -> bool {
+    response.headers().contains_key(CIRCUIT_OPEN_HEADER)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use reqwest::{Method, Response, StatusCode};
+    use serde_json::{Value, json};
+
+    use super::{CircuitBreaker, CircuitBreakerState};
+    use crate::{AuthenticationError, CreateError, PocketBase, RequestMetrics, TransportError};
+
+    fn failure() -> Result<Response, TransportError> {
+        Err(TransportError::new("connection refused"))
+    }
+
+    #[tokio::test]
+    async fn single_probe_after_the_cooldown() {
+        let state = CircuitBreakerState::shared(CircuitBreaker {
+            failure_threshold: 2,
+            cooldown: Duration::from_millis(50),
+        });
+
+        for _ in 0..2 {
+            state.acquire().unwrap().record(&failure());
+        }
+
+        assert!(state.acquire().is_none());
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+
+        let probe = state.acquire().unwrap();
+        assert!(state.acquire().is_none());
+
+        // A cancelled probe lets another request probe the instance
+        drop(probe);
+        let probe = state.acquire().unwrap();
+        assert!(state.acquire().is_none());
+
+        probe.record(&Ok(Response::from(http::Response::new(""))));
+        assert!(state.acquire().is_some());
+        assert!(state.acquire().is_some());
+    }
+
+    #[tokio::test]
+    async fn failed_probe_opens_the_circuit_again() {
+        let state = CircuitBreakerState::shared(CircuitBreaker {
+            failure_threshold: 1,
+            cooldown: Duration::from_millis(50),
+        });
+
+        state.acquire().unwrap().record(&failure());
+        tokio::time::sleep(Duration::from_millis(60)).await;
+
+        state.acquire().unwrap().record(&failure());
+        assert!(state.acquire().is_none());
+    }
+
+    #[tokio::test]
+    async fn operation_errors() {
+        let pb = PocketBase::builder("http://127.0.0.1:1")
+            .circuit_breaker(CircuitBreaker {
+                failure_threshold: 1,
+                cooldown: Duration::from_mins(1),
+            })
+            .build()
+            .unwrap();
+
+        let create = || {
+            pb.collection("articles")
+                .create::<Value>(json!({ "title": "Hello" }))
+                .call()
+        };
+
+        assert!(matches!(create().await, Err(CreateError::Unreachable(_))));
+        assert!(matches!(create().await, Err(CreateError::CircuitOpen)));
+
+        let auth = pb
+            .collection("users")
+            .auth_with_password("test@example.com", "password")
+            .await;
+
+        assert!(matches!(auth, Err(AuthenticationError::CircuitOpen)));
+    }
+
+    #[tokio::test]
+    async fn set_on_a_shared_client() {
+        let pb = PocketBase::new("http://127.0.0.1:1");
+        let clone = pb.clone();
+
+        pb.set_circuit_breaker(CircuitBreaker {
+            failure_threshold: 1,
+            cooldown: Duration::from_mins(1),
+        });
+
+        let create = || {
+            clone
+                .collection("articles")
+                .create::<Value>(json!({ "title": "Hello" }))
+                .call()
+        };
+
+        assert!(matches!(create().await, Err(CreateError::Unreachable(_))));
+        assert!(matches!(create().await, Err(CreateError::CircuitOpen)));
+    }
+
+    #[tokio::test]
+    async fn open_circuit_has_no_status_in_the_metrics() {
+        struct Statuses(Arc<Mutex<Vec<Option<StatusCode>>>>);
+
+        impl RequestMetrics for Statuses {
+            fn on_request_complete(
+                &self,
+                _endpoint: &str,
+                _method: &Method,
+                status: Option<StatusCode>,
+                _duration: Duration,
+                _bytes: Option<u64>,
+            ) {
+                self.0.lock().unwrap().push(status);
+            }
+        }
+
+        let statuses = Arc::new(Mutex::new(Vec::new()));
+
+        let pb = PocketBase::builder("http://127.0.0.1:1")
+            .circuit_breaker(CircuitBreaker {
+                failure_threshold: 1,
+                cooldown: Duration::from_mins(1),
+            })
+            .metrics(Statuses(statuses.clone()))
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            let result = pb
+                .collection("articles")
+                .get_one::<Value>("abc123")
+                .call()
+                .await;
+            assert!(result.is_err());
+        }
+
+        assert_eq!(*statuses.lock().unwrap(), [None, None]);
+    }
+}
@@ -0,0 +1,135 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use tokio_util::sync::CancellationToken;
+
+use super::Collections;
+use super::model::CollectionModel;
+use crate::PocketBase;
+use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+
+/// Builder for creating a new collection.
+///
+/// The builder can be awaited directly, or executed through [`CollectionsCreateBuilder::call()`].
+pub struct CollectionsCreateBuilder<'a> {
+    client: &'a PocketBase,
+    collection: &'a CollectionModel,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Collections<'a> {
+    /// Create a new collection.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use pocketbase_rs::{CollectionField, CollectionModel, CollectionType};
+    ///
+    /// let collection = pb
+    ///     .collections()
+    ///     .create(&CollectionModel {
+    ///         name: "articles".to_string(),
+    ///         collection_type: CollectionType::Base,
+    ///         fields: vec![CollectionField {
+    ///             name: "title".to_string(),
+    ///             field_type: "text".to_string(),
+    ///             ..Default::default()
+    ///         }],
+    ///         list_rule: Some(String::new()),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn create(&self, collection: &'a CollectionModel) -> CollectionsCreateBuilder<'a> {
+        CollectionsCreateBuilder {
+            client: self.client,
+            collection,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> CollectionsCreateBuilder<'a> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(30))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<CollectionModel, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<CollectionModel, RequestError> {
+        let url = format!("{}/api/collections", self.client.base_url);
+
+        let response = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_post_json(&url, self.collection)),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RequestError::from_response(response).await);
+        }
+
+        response
+            .json::<CollectionModel>()
+            .await
+            .map_err(|error| RequestError::ParseError(error.to_string()))
+    }
+}
+
+impl<'a> IntoFuture for CollectionsCreateBuilder<'a> {
+    type Output = Result<CollectionModel, RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
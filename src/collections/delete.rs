@@ -0,0 +1,116 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use tokio_util::sync::CancellationToken;
+
+use super::Collections;
+use crate::PocketBase;
+use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+
+/// Builder for deleting a collection.
+///
+/// The builder can be awaited directly, or executed through [`CollectionsDeleteBuilder::call()`].
+pub struct CollectionsDeleteBuilder<'a> {
+    client: &'a PocketBase,
+    id_or_name: &'a str,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Collections<'a> {
+    /// Delete a single collection by its ID or name.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.collections().delete("articles").await?;
+    /// ```
+    #[must_use]
+    pub const fn delete(&self, id_or_name: &'a str) -> CollectionsDeleteBuilder<'a> {
+        CollectionsDeleteBuilder {
+            client: self.client,
+            id_or_name,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> CollectionsDeleteBuilder<'a> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(30))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<(), RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<(), RequestError> {
+        let url = format!(
+            "{}/api/collections/{}",
+            self.client.base_url, self.id_or_name
+        );
+
+        let response = self
+            .client
+            .send_request(self.options.apply(self.client.request_delete(&url)))
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RequestError::from_response(response).await);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> IntoFuture for CollectionsDeleteBuilder<'a> {
+    type Output = Result<(), RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
@@ -0,0 +1,305 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use tokio_util::sync::CancellationToken;
+
+use super::Collections;
+use super::model::CollectionModel;
+use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+use crate::{PocketBase, RecordList};
+
+/// Builder for fetching a paginated list of collections.
+pub struct CollectionsGetListBuilder<'a> {
+    client: &'a PocketBase,
+    page: Option<String>,
+    per_page: Option<String>,
+    sort: Option<&'a str>,
+    filter: Option<&'a str>,
+    skip_total: bool,
+    options: RequestOptions<'a>,
+}
+
+/// Builder for fetching all collections.
+///
+/// The builder can be awaited directly, or executed through [`CollectionsGetFullListBuilder::call()`].
+pub struct CollectionsGetFullListBuilder<'a> {
+    client: &'a PocketBase,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Collections<'a> {
+    /// Fetch a paginated list of collections.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let collections = pb
+    ///     .collections()
+    ///     .get_list()
+    ///     .filter("type='auth'")
+    ///     .sort("name")
+    ///     .call()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn get_list(&self) -> CollectionsGetListBuilder<'a> {
+        CollectionsGetListBuilder {
+            client: self.client,
+            page: None,
+            per_page: None,
+            sort: None,
+            filter: None,
+            skip_total: false,
+            options: RequestOptions::new(),
+        }
+    }
+
+    /// Fetch all collections, handling pagination automatically.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let collections = pb.collections().get_full_list().await?;
+    /// ```
+    #[must_use]
+    pub const fn get_full_list(&self) -> CollectionsGetFullListBuilder<'a> {
+        CollectionsGetFullListBuilder {
+            client: self.client,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> CollectionsGetListBuilder<'a> {
+    /// The page (aka. offset) of the paginated list (default to 1).
+    pub fn page(mut self, page: u16) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+
+    /// Set the max returned collections per page (default: 30, max: 500).
+    pub fn per_page(mut self, per_page: u16) -> Self {
+        self.per_page = Some(per_page.to_string());
+        self
+    }
+
+    /// Specify the collections order attribute(s).
+    /// Add `-`/`+` (default) in front of the attribute for DESC / ASC order.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .sort("-created,name")
+    /// ```
+    pub const fn sort(mut self, sort: &'a str) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Filter the returned collections.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .filter("type='auth' && system=false")
+    /// ```
+    pub const fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Skip total count query for better performance.
+    ///
+    /// When enabled, `totalItems` and `totalPages` will be `-1`.
+    pub const fn skip_total(mut self, skip_total: bool) -> Self {
+        self.skip_total = skip_total;
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the paginated results.
+    pub async fn call(self) -> Result<RecordList<CollectionModel>, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<RecordList<CollectionModel>, RequestError> {
+        let url = format!("{}/api/collections", self.client.base_url);
+
+        let mut query_parameters: Vec<(&str, &str)> = vec![];
+
+        if let Some(page) = self.page.as_deref() {
+            query_parameters.push(("page", page));
+        }
+
+        if let Some(per_page) = self.per_page.as_deref() {
+            query_parameters.push(("perPage", per_page));
+        }
+
+        if let Some(sort) = self.sort {
+            query_parameters.push(("sort", sort));
+        }
+
+        if let Some(filter) = self.filter {
+            query_parameters.push(("filter", filter));
+        }
+
+        if self.skip_total {
+            query_parameters.push(("skipTotal", "true"));
+        }
+
+        let response = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_get(&url, Some(query_parameters))),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RequestError::from_response(response).await);
+        }
+
+        response
+            .json::<RecordList<CollectionModel>>()
+            .await
+            .map_err(|error| RequestError::ParseError(error.to_string()))
+    }
+}
+
+impl<'a> CollectionsGetFullListBuilder<'a> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(30))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the requests and return all the collections.
+    ///
+    /// The options apply to each page request.
+    pub async fn call(self) -> Result<Vec<CollectionModel>, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<Vec<CollectionModel>, RequestError> {
+        let mut all_collections = Vec::new();
+        let mut page = 1u16;
+
+        loop {
+            let mut request = self
+                .client
+                .collections()
+                .get_list()
+                .page(page)
+                .per_page(500)
+                .skip_total(true);
+
+            request.options = self.options.clone();
+
+            let collections = request.call().await?;
+
+            let items_count = collections.items.len();
+            all_collections.extend(collections.items);
+
+            if items_count < 500 {
+                break;
+            }
+
+            page += 1;
+        }
+
+        Ok(all_collections)
+    }
+}
+
+impl<'a> IntoFuture for CollectionsGetFullListBuilder<'a> {
+    type Output = Result<Vec<CollectionModel>, RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::PocketBase;
+
+pub mod create;
+pub mod delete;
+pub mod get_list;
+pub mod get_one;
+pub mod model;
+pub mod scaffolds;
+pub mod update;
+
+/// Provides access to the collections management API of a `PocketBase` instance.
+///
+/// Instances of this struct are created using the [`PocketBase::collections`] method.
+/// All operations require the client to be authenticated as a superuser.
+pub struct Collections<'a> {
+    pub(crate) client: &'a PocketBase,
+}
+
+impl PocketBase {
+    /// Creates a new [`Collections`] instance to manage the collections (schema) of `PocketBase`.
+    ///
+    /// Not to be confused with [`PocketBase::collection`], which gives access to the records
+    /// of a single collection.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.admin_auth_with_password("admin@example.com", "YOUR_PASSWORD")
+    ///     .await?;
+    ///
+    /// let articles = pb.collections().get_one("articles").await?;
+    ///
+    /// println!("Articles have {} fields", articles.fields.len());
+    /// ```
+    #[must_use]
+    pub const fn collections(&self) -> Collections<'_> {
+        Collections { client: self }
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The type of a `PocketBase` collection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectionType {
+    /// A regular collection storing records.
+    #[default]
+    Base,
+    /// A collection whose records can authenticate.
+    Auth,
+    /// A read-only collection whose records are the result of a SQL query.
+    View,
+}
+
+/// Represents the definition (schema) of a `PocketBase` collection.
+///
+/// Options specific to the collection type (e.g. `viewQuery` for view collections,
+/// `passwordAuth` or `oauth2` for auth collections) are kept in `options`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionModel {
+    /// The collection's unique ID. Leave empty to let `PocketBase` generate one.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    /// The unique collection name.
+    pub name: String,
+    /// The collection type.
+    #[serde(rename = "type")]
+    pub collection_type: CollectionType,
+    /// Whether the collection is a system collection.
+    #[serde(default)]
+    pub system: bool,
+    /// The collection fields.
+    #[serde(default)]
+    pub fields: Vec<CollectionField>,
+    /// The collection indexes, as raw SQL `CREATE INDEX` statements.
+    #[serde(default)]
+    pub indexes: Vec<String>,
+    /// API rule for listing records. `None` means only superusers can perform the action.
+    #[serde(default)]
+    pub list_rule: Option<String>,
+    /// API rule for viewing a single record. `None` means only superusers can perform the action.
+    #[serde(default)]
+    pub view_rule: Option<String>,
+    /// API rule for creating records. `None` means only superusers can perform the action.
+    #[serde(default)]
+    pub create_rule: Option<String>,
+    /// API rule for updating records. `None` means only superusers can perform the action.
+    #[serde(default)]
+    pub update_rule: Option<String>,
+    /// API rule for deleting records. `None` means only superusers can perform the action.
+    #[serde(default)]
+    pub delete_rule: Option<String>,
+    /// Any other collection option.
+    #[serde(flatten)]
+    pub options: Map<String, Value>,
+}
+
+/// Represents a single field of a `PocketBase` collection.
+///
+/// Options specific to the field type (e.g. `max`, `required`, `collectionId`) are kept in `options`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CollectionField {
+    /// The field's unique ID. Leave empty to let `PocketBase` generate one.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    /// The field name.
+    pub name: String,
+    /// The field type *(example: `text`, `number`, `relation`)*.
+    #[serde(rename = "type")]
+    pub field_type: String,
+    /// Whether the field is a system field.
+    #[serde(default)]
+    pub system: bool,
+    /// Whether the field is hidden from the API responses.
+    #[serde(default)]
+    pub hidden: bool,
+    /// Whether the field is used as a preview when displaying relations in the dashboard.
+    #[serde(default)]
+    pub presentable: bool,
+    /// Any other field option.
+    #[serde(flatten)]
+    pub options: Map<String, Value>,
+}
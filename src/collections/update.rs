@@ -0,0 +1,136 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use super::Collections;
+use super::model::CollectionModel;
+use crate::PocketBase;
+use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+
+/// Builder for updating a collection.
+///
+/// The builder can be awaited directly, or executed through [`CollectionsUpdateBuilder::call()`].
+pub struct CollectionsUpdateBuilder<'a, T> {
+    client: &'a PocketBase,
+    id_or_name: &'a str,
+    changes: &'a T,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Collections<'a> {
+    /// Update a single collection by its ID or name.
+    ///
+    /// `changes` can be a full [`CollectionModel`] or any serializable partial
+    /// representation of it (e.g. a `serde_json::Value`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let collection = pb
+    ///     .collections()
+    ///     .update("articles", &serde_json::json!({ "listRule": "" }))
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn update<T: Serialize + Send + Sync>(
+        &self,
+        id_or_name: &'a str,
+        changes: &'a T,
+    ) -> CollectionsUpdateBuilder<'a, T> {
+        CollectionsUpdateBuilder {
+            client: self.client,
+            id_or_name,
+            changes,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a, T: Serialize + Send + Sync> CollectionsUpdateBuilder<'a, T> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(30))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<CollectionModel, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<CollectionModel, RequestError> {
+        let url = format!(
+            "{}/api/collections/{}",
+            self.client.base_url, self.id_or_name
+        );
+
+        let response = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_patch_json(&url, self.changes)),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RequestError::from_response(response).await);
+        }
+
+        response
+            .json::<CollectionModel>()
+            .await
+            .map_err(|error| RequestError::ParseError(error.to_string()))
+    }
+}
+
+impl<'a, T: Serialize + Send + Sync> IntoFuture for CollectionsUpdateBuilder<'a, T> {
+    type Output = Result<CollectionModel, RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
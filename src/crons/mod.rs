@@ -0,0 +1,41 @@
+use serde::Deserialize;
+
+use crate::PocketBase;
+
+pub mod get_full_list;
+pub mod run;
+
+/// Provides access to the crons API of a `PocketBase` instance.
+///
+/// Instances of this struct are created using the [`PocketBase::crons`] method.
+/// All operations require the client to be authenticated as a superuser.
+pub struct Crons<'a> {
+    pub(crate) client: &'a PocketBase,
+}
+
+/// Represents a registered cron job.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CronJob {
+    /// The cron job identifier *(example: `__pbLogsCleanup__`)*.
+    pub id: String,
+    /// The cron expression scheduling the job *(example: `0 */6 * * *`)*.
+    pub expression: String,
+}
+
+impl PocketBase {
+    /// Creates a new [`Crons`] instance to list and run the registered cron jobs.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.admin_auth_with_password("admin@example.com", "YOUR_PASSWORD")
+    ///     .await?;
+    ///
+    /// pb.crons().run("__pbLogsCleanup__").await?;
+    /// ```
+    #[must_use]
+    pub const fn crons(&self) -> Crons<'_> {
+        Crons { client: self }
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+use crate::FilterValue;
+
+/// The value of a `created` or `updated` record field, as returned by `PocketBase`
+/// *(e.g. `2024-01-31 12:00:00.000Z`)*.
+///
+/// Empty when the collection has no such field. Enable the `chrono` or `time` feature
+/// to parse it with [`Timestamp::to_chrono()`] or [`Timestamp::to_time()`].
+///
+/// # Example
+/// ```rust,ignore
+/// if let Some(created) = article.created.to_chrono()? {
+///     println!("{} days old", (Utc::now() - created).num_days());
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(String);
+
+impl Timestamp {
+    /// Returns the timestamp as returned by `PocketBase`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the record has no such field.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Parses the timestamp into a `chrono::DateTime<Utc>`.
+    ///
+    /// Returns `Ok(None)` when the timestamp is empty.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>, String> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        self.0
+            .parse()
+            .map(Some)
+            .map_err(|error| format!("invalid datetime `{}`: {error}", self.0))
+    }
+
+    /// Parses the timestamp into a `time::OffsetDateTime`, in UTC.
+    ///
+    /// Returns `Ok(None)` when the timestamp is empty.
+    #[cfg(feature = "time")]
+    pub fn to_time(&self) -> Result<Option<time::OffsetDateTime>, String> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        time::OffsetDateTime::parse(
+            &self.0.replacen(' ', "T", 1),
+            &time::format_description::well_known::Rfc3339,
+        )
+        .map(|datetime| Some(datetime.to_offset(time::UtcOffset::UTC)))
+        .map_err(|error| format!("invalid datetime `{}`: {error}", self.0))
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Timestamp> for String {
+    fn from(value: Timestamp) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self(
+            Option::<String>::deserialize(deserializer)?.unwrap_or_default(),
+        ))
+    }
+}
+
+/// Formats a datetime the way `PocketBase` stores them: `2024-01-31 12:00:00.000Z`.
+#[cfg(feature = "chrono")]
+fn format_chrono<Tz: chrono::TimeZone>(datetime: &chrono::DateTime<Tz>) -> String {
+    datetime
+        .with_timezone(&chrono::Utc)
+        .format("%Y-%m-%d %H:%M:%S%.3fZ")
+        .to_string()
+}
+
+/// Formats a datetime the way `PocketBase` stores them: `2024-01-31 12:00:00.000Z`.
+#[cfg(feature = "time")]
+fn format_time(datetime: time::OffsetDateTime) -> String {
+    const FORMAT: &[time::format_description::BorrowedFormatItem<'_>] = time::macros::format_description!(
+        "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond digits:3]Z"
+    );
+
+    datetime
+        .to_offset(time::UtcOffset::UTC)
+        .format(FORMAT)
+        .unwrap_or_default()
+}
+
+/// Renders the datetime as a `PocketBase` date literal, e.g. `'2024-01-31 12:00:00.000Z'`.
+///
+/// # Example
+/// ```rust,ignore
+/// let filter = Filter::gte("created", Utc::now() - Duration::days(7));
+/// ```
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for FilterValue {
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        Self::String(format_chrono(&value))
+    }
+}
+
+/// Renders the datetime as a `PocketBase` date literal, e.g. `'2024-01-31 12:00:00.000Z'`.
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for FilterValue {
+    fn from(value: time::OffsetDateTime) -> Self {
+        Self::String(format_time(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timestamp;
+
+    #[test]
+    fn missing_timestamp_is_empty() {
+        let timestamp = serde_json::from_str::<Timestamp>("null").unwrap();
+
+        assert!(timestamp.is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn to_chrono() {
+        let timestamp = Timestamp::from("2024-01-31 12:00:00.123Z".to_owned());
+
+        assert_eq!(
+            timestamp.to_chrono().unwrap().unwrap().to_rfc3339(),
+            "2024-01-31T12:00:00.123+00:00"
+        );
+        assert_eq!(Timestamp::default().to_chrono(), Ok(None));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn to_time() {
+        let timestamp = Timestamp::from("2024-01-31 12:00:00.123Z".to_owned());
+
+        assert_eq!(
+            timestamp.to_time().unwrap().unwrap(),
+            time::macros::datetime!(2024-01-31 12:00:00.123 UTC)
+        );
+        assert_eq!(Timestamp::default().to_time(), Ok(None));
+    }
+}
@@ -3,13 +3,24 @@
 use core::fmt;
 use std::collections::HashMap;
 
+use reqwest::header::HeaderMap;
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::TransportError;
+pub use crate::builder::BuildError;
+#[cfg(feature = "oauth2-flow")]
+pub use crate::records::auth::auth_with_oauth2_flow::OAuth2FlowError;
 pub use crate::records::auth::auth_with_password::AuthenticationError;
+pub use crate::records::auth::confirm_verification::ConfirmVerificationError;
+pub use crate::records::auth::email_change::EmailChangeError;
 pub use crate::records::auth::impersonate::ImpersonateError;
 pub use crate::records::crud::create::CreateError;
+pub use crate::records::crud::delete::DeleteError;
+pub use crate::records::crud::export::ExportError;
+pub use crate::records::crud::import::{ImportFailure, ImportLineError};
 pub use crate::records::crud::update::UpdateError;
+pub use crate::settings::test::SettingsTestError;
 
 /// This error represents the error returned by the `PocketBase`
 /// instance in case of a 400 error.
@@ -27,7 +38,7 @@ pub struct BadRequestResponse {
 ///
 /// This struct holds detailed information about a single validation error,
 /// including the field name, error code, and a user-friendly message.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct BadRequestError {
     /// Name of the field.
     pub name: String,
@@ -37,6 +48,34 @@ pub struct BadRequestError {
     pub message: String,
 }
 
+impl BadRequestError {
+    /// The code of the errors returned for a value already used by another record.
+    pub const NOT_UNIQUE: &str = "validation_not_unique";
+
+    /// Returns `true` if the value of the field is already used by another record.
+    #[must_use]
+    pub fn is_not_unique(&self) -> bool {
+        self.code == Self::NOT_UNIQUE
+    }
+
+    /// Returns the field whose value is already used by another record, if it is the only
+    /// field that was rejected, so that the other errors are never discarded.
+    pub(crate) fn not_unique_field(errors: &[Self]) -> Option<String> {
+        match errors {
+            [error] if error.is_not_unique() => Some(error.name.clone()),
+            _ => None,
+        }
+    }
+
+    fn not_unique(field: &str) -> Self {
+        Self {
+            name: field.to_owned(),
+            code: Self::NOT_UNIQUE.to_owned(),
+            message: "Value must be unique.".to_owned(),
+        }
+    }
+}
+
 impl fmt::Display for BadRequestError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}: {} {}", self.name, self.code, self.message)
@@ -44,7 +83,7 @@ impl fmt::Display for BadRequestError {
 }
 
 /// Represents one of the fields that caused the Bad Request error.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct BadRequestField {
     /// Error code *(example: `validation_required`)*.
     pub code: String,
@@ -52,6 +91,217 @@ pub struct BadRequestField {
     pub message: String,
 }
 
+/// The error payload returned by the `PocketBase` API along an unsuccessful response.
+///
+/// ```json
+/// {
+///   "status": 400,
+///   "message": "Failed to create record.",
+///   "data": {
+///     "title": { "code": "validation_required", "message": "Missing required value." }
+///   }
+/// }
+/// ```
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct ApiErrorResponse {
+    /// HTTP Status Code.
+    #[serde(default, alias = "code")]
+    pub status: u16,
+    /// Description given by `PocketBase` about why the error happened.
+    #[serde(default)]
+    pub message: String,
+    /// The fields that caused the error, if any.
+    #[serde(default)]
+    pub data: HashMap<String, BadRequestField>,
+    /// The HTTP headers of the response *(example: an `X-Request-Id` header set by a proxy)*.
+    ///
+    /// Empty when the error was not read from a response.
+    #[serde(skip)]
+    pub headers: Box<HeaderMap>,
+}
+
+impl ApiErrorResponse {
+    /// Creates an error payload with the given status and message, and no field errors.
+    #[must_use]
+    pub fn new(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+            data: HashMap::new(),
+            headers: Box::default(),
+        }
+    }
+
+    /// Returns the field-level validation errors, sorted by field name.
+    #[must_use]
+    pub fn field_errors(&self) -> Vec<BadRequestError> {
+        let mut errors: Vec<BadRequestError> = self
+            .data
+            .iter()
+            .map(|(name, field)| BadRequestError {
+                name: name.clone(),
+                code: field.code.clone(),
+                message: field.message.clone(),
+            })
+            .collect();
+
+        errors.sort_by(|a, b| a.name.cmp(&b.name));
+        errors
+    }
+
+    /// Reads the error payload of an unsuccessful response.
+    ///
+    /// If the body is not a `PocketBase` error payload, the status code and its
+    /// canonical reason are used instead.
+    pub(crate) async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status();
+        let headers = Box::new(response.headers().clone());
+
+        let mut error = match response.json::<Self>().await {
+            Ok(mut error) => {
+                if error.status == 0 {
+                    error.status = status.as_u16();
+                }
+                error
+            }
+            Err(_) => Self::new(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default(),
+            ),
+        };
+
+        error.headers = headers;
+        error
+    }
+
+    fn from_field_errors(status: u16, message: &str, errors: Vec<BadRequestError>) -> Self {
+        Self {
+            status,
+            message: message.to_owned(),
+            data: errors
+                .into_iter()
+                .map(|error| {
+                    (
+                        error.name,
+                        BadRequestField {
+                            code: error.code,
+                            message: error.message,
+                        },
+                    )
+                })
+                .collect(),
+            headers: Box::default(),
+        }
+    }
+}
+
+impl fmt::Display for ApiErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        for error in self.field_errors() {
+            write!(f, " [{error}]")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The raw content of an unexpected response returned by the `PocketBase` API.
+///
+/// The status code and body are kept as-is, so that the response can be inspected
+/// (or logged) even when this crate does not know how to handle it.
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    /// HTTP Status Code.
+    pub status: u16,
+    /// The body of the response, as text.
+    pub body: String,
+    /// The error payload, if the body is a `PocketBase` error payload.
+    pub error: Option<ApiErrorResponse>,
+    /// The HTTP headers of the response *(example: an `X-Request-Id` header set by a proxy)*.
+    ///
+    /// Empty for the items of a batch request, which have no headers of their own.
+    pub headers: Box<HeaderMap>,
+}
+
+impl RawResponse {
+    /// Reads the status code and body of a response.
+    pub(crate) async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.text().await.unwrap_or_default();
+
+        Self::new(status, headers, body)
+    }
+
+    /// Keeps the status code, headers and body of a response whose body was already read.
+    pub(crate) fn new(status: u16, headers: HeaderMap, body: String) -> Self {
+        let headers = Box::new(headers);
+
+        let error = serde_json::from_str::<ApiErrorResponse>(&body)
+            .ok()
+            .filter(|error| !error.message.is_empty())
+            .map(|mut error| {
+                if error.status == 0 {
+                    error.status = status;
+                }
+                error.headers.clone_from(&headers);
+                error
+            });
+
+        Self {
+            status,
+            body,
+            error,
+            headers,
+        }
+    }
+}
+
+impl fmt::Display for RawResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "status {}", self.status)?;
+
+        if !self.body.is_empty() {
+            write!(f, ", body: {}", self.body)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents the errors that can be obtained when validating a collection name.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CollectionNameError {
+    /// The collection name is empty.
+    #[error("Collection name cannot be empty")]
+    Empty,
+    /// The collection name contains characters other than alphanumeric characters and underscores.
+    #[error(
+        "Collection name contains invalid characters. Only alphanumeric characters and underscores are allowed: {0}"
+    )]
+    InvalidCharacters(String),
+}
+
+/// Represents the errors that can be obtained when validating a record id.
+///
+/// See [`validate_record_id()`](crate::validate_record_id).
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RecordIdError {
+    /// The record id is empty.
+    #[error("Record id cannot be empty")]
+    Empty,
+    /// The record id is longer than the maximum length allowed by `PocketBase`.
+    #[error("Record id is too long ({0} characters). At most 255 characters are allowed")]
+    TooLong(usize),
+    /// The record id contains characters other than alphanumeric characters, underscores and hyphens.
+    #[error(
+        "Record id contains invalid characters. Only alphanumeric characters, underscores and hyphens are allowed: {0}"
+    )]
+    InvalidCharacters(String),
+}
+
 /// Represents errors when interacting with the `PocketBase` API.
 ///
 /// This enum provides a set of error types that may occur during
@@ -62,8 +312,24 @@ pub enum RequestError {
     /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response.
     ///
     /// Your request may be missing fields or its content doesn't match what `PocketBase` expects to receive.
+    /// List and view requests also return this error for a malformed `sort` or `expand` parameter,
+    /// except for list requests carrying a filter, reported as [`RequestError::InvalidFilter`].
+    /// The field-level validation details are available through [`ApiErrorResponse::field_errors()`].
     #[error("Bad Request: Something went wrong while processing your request. {0}")]
-    BadRequest(String),
+    BadRequest(ApiErrorResponse),
+    /// The filter given to a list request was rejected by `PocketBase`.
+    ///
+    /// Any [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") response
+    /// to a list request carrying a filter is reported as such, as `PocketBase` only answers with a
+    /// generic message for an invalid filter. Holds the filter that was sent, along with the error
+    /// payload returned by `PocketBase`.
+    #[error("Invalid Filter: {} (filter: `{filter}`)", response.message)]
+    InvalidFilter {
+        /// The rejected filter.
+        filter: String,
+        /// The error payload returned by `PocketBase`.
+        response: ApiErrorResponse,
+    },
     /// Communication with the `PocketBase` API was successful,
     /// but returned a [401 Unauthorized]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/401") HTTP error response.
     ///
@@ -90,6 +356,14 @@ pub enum RequestError {
         "Unreachable: The PocketBase API interaction timed out, or the service may be offline."
     )]
     Unreachable,
+    /// The request was cancelled through its cancellation token.
+    #[error("Cancelled: The request was cancelled.")]
+    Cancelled,
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    ///
+    /// See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("Circuit Open: The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
     /// Too many requests were sent to the API.
     ///
     /// The server is rate limiting requests. Wait before retrying.
@@ -97,9 +371,402 @@ pub enum RequestError {
         "Too Many Requests: The server is rate limiting requests. Please wait before retrying."
     )]
     TooManyRequests,
-    /// Unhandled error.
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a response this crate doesn't know how to handle.
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
+    #[error("Unexpected Response: The PocketBase API returned an unexpected response ({0}).")]
+    UnexpectedResponse(RawResponse),
+    /// The given record id can't be valid, the request was not sent.
+    #[error("Invalid Record Id: {0}")]
+    InvalidRecordId(RecordIdError),
+    /// The request could not be sent, or its response could not be read, for another reason
+    /// than a timeout or a connection failure *(example: an invalid header value or a broken
+    /// TLS handshake)*.
+    #[error("Transport Error: {0}")]
+    Transport(TransportError),
+}
+
+/// Timeouts and connection failures are reported as [`RequestError::Unreachable`], the
+/// other transport errors are kept as-is.
+impl From<TransportError> for RequestError {
+    fn from(error: TransportError) -> Self {
+        if error.is_timeout() || error.is_connect() {
+            Self::Unreachable
+        } else {
+            Self::Transport(error)
+        }
+    }
+}
+
+impl From<reqwest::Error> for RequestError {
+    fn from(error: reqwest::Error) -> Self {
+        TransportError::from(error).into()
+    }
+}
+
+impl RequestError {
+    /// Maps an unsuccessful response from the `PocketBase` API into a [`RequestError`].
+    ///
+    /// For [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") responses,
+    /// the error payload sent by `PocketBase` is kept. For unhandled status codes, the raw response is kept.
+    pub(crate) async fn from_response(response: reqwest::Response) -> Self {
+        if crate::circuit_breaker::is_open_response(&response) {
+            return Self::CircuitOpen;
+        }
+
+        match response.status() {
+            reqwest::StatusCode::BAD_REQUEST => {
+                Self::BadRequest(ApiErrorResponse::from_response(response).await)
+            }
+            reqwest::StatusCode::UNAUTHORIZED => Self::Unauthorized,
+            reqwest::StatusCode::FORBIDDEN => Self::Forbidden,
+            reqwest::StatusCode::NOT_FOUND => Self::NotFound,
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Self::TooManyRequests,
+            _ => Self::UnexpectedResponse(RawResponse::from_response(response).await),
+        }
+    }
+
+    /// Returns the HTTP headers of the unsuccessful response, when they were kept, e.g. to
+    /// read the `X-Request-Id` header set by a proxy when tracing a failed query.
     ///
-    /// Usually emitted when something unexpected happened, and isn't handled correctly by this crate.
-    #[error("Unhandled Error: An unexpected error occurred.")]
-    Unhandled,
+    /// # Example
+    /// ```rust,ignore
+    /// if let Err(error) = pb.collection("articles").get_list::<Article>().call().await {
+    ///     let request_id = error.headers().and_then(|headers| headers.get("X-Request-Id"));
+    ///     tracing::warn!(?request_id, %error, "failed to list articles");
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn headers(&self) -> Option<&HeaderMap> {
+        match self {
+            Self::BadRequest(response) | Self::InvalidFilter { response, .. } => {
+                Some(&response.headers)
+            }
+            Self::UnexpectedResponse(response) => Some(&response.headers),
+            _ => None,
+        }
+    }
+
+    /// Turns a [`RequestError::BadRequest`] into a [`RequestError::InvalidFilter`] when the
+    /// list request had a filter.
+    pub(crate) fn with_filter(self, filter: Option<&str>) -> Self {
+        match (self, filter) {
+            (Self::BadRequest(response), Some(filter)) => Self::InvalidFilter {
+                filter: filter.to_owned(),
+                response,
+            },
+            (error, _) => error,
+        }
+    }
+}
+
+/// A single error type that every error of this crate can be converted into.
+///
+/// Each operation returns its own error enum, describing precisely what can go wrong.
+/// When these details are not needed, the `?` operator converts any of them into a
+/// `PocketBaseError`, so that the generic cases can be matched once, while still giving
+/// access to the error payload returned by `PocketBase`.
+///
+/// # Example
+/// ```rust,ignore
+/// async fn publish(pb: &PocketBase, article: Article) -> Result<(), PocketBaseError> {
+///     pb.collection("articles").create(article).call().await?;
+///     pb.collection("drafts").delete("DRAFT_ID").await?;
+///
+///     Ok(())
+/// }
+///
+/// match publish(&pb, article).await {
+///     Err(error) if error.status() == Some(404) => println!("Missing collection"),
+///     Err(PocketBaseError::Api(response)) => {
+///         for field_error in response.field_errors() {
+///             println!("{field_error}");
+///         }
+///     }
+///     _ => {}
+/// }
+/// ```
+#[derive(Error, Debug)]
+pub enum PocketBaseError {
+    /// Communication with the `PocketBase` API was successful, but returned an unsuccessful response.
+    #[error("The PocketBase API returned an error ({status}): {0}", status = .0.status)]
+    Api(ApiErrorResponse),
+    /// The collection has Multi-Factor Authentication enabled: authenticate again with
+    /// another method passing the given `mfa_id` to complete the login.
+    #[error(
+        "Authentication requires another factor. Complete the MFA challenge using the given mfaId."
+    )]
+    MfaRequired {
+        /// The id of the pending MFA session.
+        mfa_id: String,
+    },
+    /// Communication with the `PocketBase` API failed.
+    #[error("The communication with the PocketBase API failed: {0}")]
+    Unreachable(String),
+    /// The response could not be parsed into the expected data structure.
+    #[error("Could not parse response into the expected data structure: {0}")]
+    ParseError(String),
+    /// The request was cancelled through its cancellation token.
+    #[error("The request was cancelled.")]
+    Cancelled,
+    /// The operation was not completed in time, e.g. when the user didn't complete an `OAuth2`
+    /// authorization.
+    #[error("The operation was not completed in time.")]
+    TimedOut,
+    /// The `OAuth2` redirect state doesn't match the one of the flow, e.g. when the redirect
+    /// was forged or meant for another client.
+    #[error("The OAuth2 redirect state doesn't match the flow.")]
+    OAuth2StateMismatch,
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    #[error("The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
+    /// The given input was rejected before any request was sent.
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+    /// The response from the `PocketBase` API was unexpected.
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
+    #[error("An unexpected response was returned by the PocketBase API: {0}")]
+    UnexpectedResponse(RawResponse),
+}
+
+impl PocketBaseError {
+    /// Returns the HTTP status code returned by the `PocketBase` API, if any.
+    #[must_use]
+    pub const fn status(&self) -> Option<u16> {
+        match self {
+            Self::Api(response) => Some(response.status),
+            Self::MfaRequired { .. } => Some(401),
+            _ => None,
+        }
+    }
+
+    /// Returns the error payload returned by the `PocketBase` API, if any.
+    #[must_use]
+    pub const fn api_response(&self) -> Option<&ApiErrorResponse> {
+        match self {
+            Self::Api(response) => Some(response),
+            _ => None,
+        }
+    }
+
+    fn api(status: u16, error: &impl fmt::Display) -> Self {
+        Self::Api(ApiErrorResponse::new(status, error.to_string()))
+    }
+
+    fn field_errors(status: u16, error: &impl fmt::Display, errors: Vec<BadRequestError>) -> Self {
+        Self::Api(ApiErrorResponse::from_field_errors(
+            status,
+            &error.to_string(),
+            errors,
+        ))
+    }
+}
+
+impl From<RawResponse> for PocketBaseError {
+    fn from(response: RawResponse) -> Self {
+        match response.error {
+            Some(error) => Self::Api(error),
+            None => Self::UnexpectedResponse(response),
+        }
+    }
+}
+
+impl From<RequestError> for PocketBaseError {
+    fn from(error: RequestError) -> Self {
+        match error {
+            RequestError::BadRequest(response) | RequestError::InvalidFilter { response, .. } => {
+                Self::Api(response)
+            }
+            RequestError::Unauthorized => Self::api(401, &error),
+            RequestError::Forbidden => Self::api(403, &error),
+            RequestError::NotFound => Self::api(404, &error),
+            RequestError::TooManyRequests => Self::api(429, &error),
+            RequestError::ParseError(message) => Self::ParseError(message),
+            RequestError::Unreachable => Self::Unreachable(error.to_string()),
+            RequestError::Cancelled => Self::Cancelled,
+            RequestError::CircuitOpen => Self::CircuitOpen,
+            RequestError::UnexpectedResponse(response) => response.into(),
+            RequestError::InvalidRecordId(error) => error.into(),
+            RequestError::Transport(error) => Self::Unreachable(error.to_string()),
+        }
+    }
+}
+
+impl From<CreateError> for PocketBaseError {
+    fn from(error: CreateError) -> Self {
+        match error {
+            CreateError::BadRequest(errors) => {
+                Self::field_errors(400, &"Failed to create record.", errors)
+            }
+            CreateError::NotUnique { ref field } => {
+                Self::field_errors(400, &error, vec![BadRequestError::not_unique(field)])
+            }
+            CreateError::Unauthorized => Self::api(401, &error),
+            CreateError::Forbidden => Self::api(403, &error),
+            CreateError::NotFound => Self::api(404, &error),
+            CreateError::TooManyRequests => Self::api(429, &error),
+            CreateError::Unreachable(message) => Self::Unreachable(message),
+            CreateError::CircuitOpen => Self::CircuitOpen,
+            CreateError::Cancelled => Self::Cancelled,
+            CreateError::InvalidRequest(message) => Self::InvalidInput(message),
+            CreateError::ParseError(message) => Self::ParseError(message),
+            CreateError::UnexpectedResponse(response) => response.into(),
+        }
+    }
+}
+
+impl From<UpdateError> for PocketBaseError {
+    fn from(error: UpdateError) -> Self {
+        match error {
+            UpdateError::BadRequest(errors) => {
+                Self::field_errors(400, &"Failed to update record.", errors)
+            }
+            UpdateError::NotUnique { ref field } => {
+                Self::field_errors(400, &error, vec![BadRequestError::not_unique(field)])
+            }
+            UpdateError::Unauthorized => Self::api(401, &error),
+            UpdateError::Forbidden => Self::api(403, &error),
+            UpdateError::NotFound => Self::api(404, &error),
+            UpdateError::TooManyRequests => Self::api(429, &error),
+            UpdateError::Unreachable(message) => Self::Unreachable(message),
+            UpdateError::CircuitOpen => Self::CircuitOpen,
+            UpdateError::Cancelled => Self::Cancelled,
+            UpdateError::ParseError(message) => Self::ParseError(message),
+            UpdateError::UnexpectedResponse(response) => response.into(),
+            UpdateError::InvalidRecord(message) => Self::InvalidInput(message),
+            UpdateError::InvalidRecordId(error) => error.into(),
+        }
+    }
+}
+
+impl From<DeleteError> for PocketBaseError {
+    fn from(error: DeleteError) -> Self {
+        match error {
+            DeleteError::BadRequest(response) => Self::Api(response),
+            DeleteError::Unauthorized => Self::api(401, &error),
+            DeleteError::Forbidden => Self::api(403, &error),
+            DeleteError::NotFound => Self::api(404, &error),
+            DeleteError::TooManyRequests => Self::api(429, &error),
+            DeleteError::Unreachable(message) => Self::Unreachable(message),
+            DeleteError::CircuitOpen => Self::CircuitOpen,
+            DeleteError::Cancelled => Self::Cancelled,
+            DeleteError::InvalidRecordId(error) => error.into(),
+            DeleteError::UnexpectedResponse(response) => response.into(),
+        }
+    }
+}
+
+impl From<AuthenticationError> for PocketBaseError {
+    fn from(error: AuthenticationError) -> Self {
+        match error {
+            AuthenticationError::InvalidCredentials
+            | AuthenticationError::EmptyField { .. }
+            | AuthenticationError::IdentityMustBeEmail => Self::api(400, &error),
+            AuthenticationError::HttpError(error) => Self::Unreachable(error.to_string()),
+            AuthenticationError::CircuitOpen => Self::CircuitOpen,
+            AuthenticationError::Cancelled => Self::Cancelled,
+            AuthenticationError::UnexpectedResponse(response) => response.into(),
+            AuthenticationError::MissingCollection => Self::InvalidInput(error.to_string()),
+            AuthenticationError::MfaRequired { mfa_id } => Self::MfaRequired { mfa_id },
+        }
+    }
+}
+
+#[cfg(feature = "oauth2-flow")]
+impl From<OAuth2FlowError> for PocketBaseError {
+    fn from(error: OAuth2FlowError) -> Self {
+        match error {
+            OAuth2FlowError::ProviderNotFound(_) => Self::InvalidInput(error.to_string()),
+            OAuth2FlowError::Request(error) => error.into(),
+            OAuth2FlowError::Provider(_) => Self::api(400, &error),
+            OAuth2FlowError::StateMismatch => Self::OAuth2StateMismatch,
+            OAuth2FlowError::TimedOut => Self::TimedOut,
+            OAuth2FlowError::Cancelled => Self::Cancelled,
+            OAuth2FlowError::Authentication(error) => error.into(),
+        }
+    }
+}
+
+impl From<ConfirmVerificationError> for PocketBaseError {
+    fn from(error: ConfirmVerificationError) -> Self {
+        match error {
+            ConfirmVerificationError::InvalidToken => Self::api(400, &error),
+            ConfirmVerificationError::BadRequest(errors) => {
+                Self::field_errors(400, &"Failed to confirm verification.", errors)
+            }
+            ConfirmVerificationError::Unreachable(message) => Self::Unreachable(message),
+            ConfirmVerificationError::CircuitOpen => Self::CircuitOpen,
+            ConfirmVerificationError::UnexpectedResponse(response) => response.into(),
+        }
+    }
+}
+
+impl From<EmailChangeError> for PocketBaseError {
+    fn from(error: EmailChangeError) -> Self {
+        match error {
+            EmailChangeError::InvalidToken | EmailChangeError::WrongPassword => {
+                Self::api(400, &error)
+            }
+            EmailChangeError::BadRequest(errors) => {
+                Self::field_errors(400, &"Failed to change email.", errors)
+            }
+            EmailChangeError::Unauthorized => Self::api(401, &error),
+            EmailChangeError::Forbidden => Self::api(403, &error),
+            EmailChangeError::Unreachable(message) => Self::Unreachable(message),
+            EmailChangeError::CircuitOpen => Self::CircuitOpen,
+            EmailChangeError::UnexpectedResponse(response) => response.into(),
+        }
+    }
+}
+
+impl From<ImpersonateError> for PocketBaseError {
+    fn from(error: ImpersonateError) -> Self {
+        match error {
+            ImpersonateError::BadRequest => Self::api(400, &error),
+            ImpersonateError::Unauthorized => Self::api(401, &error),
+            ImpersonateError::Forbidden => Self::api(403, &error),
+            ImpersonateError::NotFound => Self::api(404, &error),
+            ImpersonateError::Unreachable(message) => Self::Unreachable(message),
+            ImpersonateError::CircuitOpen => Self::CircuitOpen,
+            ImpersonateError::Cancelled => Self::Cancelled,
+            ImpersonateError::UnexpectedResponse(response) => response.into(),
+        }
+    }
+}
+
+impl From<SettingsTestError> for PocketBaseError {
+    fn from(error: SettingsTestError) -> Self {
+        match error {
+            SettingsTestError::TestFailed(message) => Self::api(400, &message),
+            SettingsTestError::BadRequest(errors) => {
+                Self::field_errors(400, &"Failed to test the settings.", errors)
+            }
+            SettingsTestError::Unauthorized => Self::api(401, &error),
+            SettingsTestError::Forbidden => Self::api(403, &error),
+            SettingsTestError::Unreachable(message) => Self::Unreachable(message),
+            SettingsTestError::CircuitOpen => Self::CircuitOpen,
+            SettingsTestError::Cancelled => Self::Cancelled,
+            SettingsTestError::UnexpectedResponse(response) => response.into(),
+        }
+    }
+}
+
+impl From<BuildError> for PocketBaseError {
+    fn from(error: BuildError) -> Self {
+        Self::InvalidInput(error.to_string())
+    }
+}
+
+impl From<CollectionNameError> for PocketBaseError {
+    fn from(error: CollectionNameError) -> Self {
+        Self::InvalidInput(error.to_string())
+    }
+}
+
+impl From<RecordIdError> for PocketBaseError {
+    fn from(error: RecordIdError) -> Self {
+        Self::InvalidInput(error.to_string())
+    }
 }
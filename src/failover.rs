@@ -0,0 +1,179 @@
+use reqwest::{Request, Response, StatusCode};
+
+use crate::builder::{BuildError, parse_base_url};
+use crate::routing;
+use crate::{PocketBase, TransportError};
+
+/// Policy used to send the reads to read replicas when the primary `PocketBase` instance fails.
+///
+/// The requests are sent to the primary instance, given as the base URL of the client. When a
+/// read (`GET` or `HEAD` request) fails with a connection error, a timeout or one of the
+/// `failover_on` status codes, it is sent again to each replica in turn, until one of them
+/// answers. The writes are only ever sent to the primary instance.
+///
+/// When the client has a [read base URL](crate::PocketBaseBuilder::read_base_url), the reads
+/// fail over from it instead of from the primary instance.
+///
+/// The replicas must share the data and the auth secrets of the primary instance, e.g. when
+/// replicated with Litestream: the same auth token is sent to all the instances.
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::{FailoverPolicy, PocketBase};
+///
+/// let pb = PocketBase::builder("http://primary:8090")
+///     .failover(FailoverPolicy::new(["http://replica-1:8090", "http://replica-2:8090"]))
+///     .build()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct FailoverPolicy {
+    /// The base URLs of the read replicas, tried in order.
+    pub replicas: Vec<String>,
+    /// The HTTP status codes making a read fail over *(default to 502, 503 and 504)*.
+    ///
+    /// Connection errors and timeouts always make a read fail over.
+    pub failover_on: Vec<StatusCode>,
+}
+
+impl FailoverPolicy {
+    /// Creates a policy failing over to the given read replicas.
+    #[must_use]
+    pub fn new(replicas: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            replicas: replicas.into_iter().map(Into::into).collect(),
+            failover_on: vec![
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+        }
+    }
+
+    /// Checks the URLs of the replicas, removing their trailing slashes.
+    pub(crate) fn validated(mut self) -> Result<Self, BuildError> {
+        for replica in &mut self.replicas {
+            *replica = parse_base_url(replica).map_err(|error| match error {
+                BuildError::InvalidUrl(error) => {
+                    BuildError::InvalidUrl(format!("{replica}: {error}"))
+                }
+                error => error,
+            })?;
+        }
+
+        Ok(self)
+    }
+
+    /// Returns `true` if the read should be sent to the next replica.
+    fn should_fail_over(&self, result: &Result<Response, TransportError>) -> bool {
+        match result {
+            Ok(response) => self.failover_on.contains(&response.status()),
+            Err(error) => error.is_connect() || error.is_timeout(),
+        }
+    }
+}
+
+/// Sends the request to the primary instance, then to the replicas if it is a read that failed.
+pub async fn send_with_failover(
+    client: &PocketBase,
+    policy: &FailoverPolicy,
+    http_client: &reqwest::Client,
+    request: Request,
+) -> Result<Response, TransportError> {
+    if !routing::is_read(&request) {
+        return client.send_once(http_client, request).await;
+    }
+
+    // The replica requests are prepared first, as sending the request consumes it
+    let replica_requests: Vec<Request> = policy
+        .replicas
+        .iter()
+        .filter_map(|replica| replica_request(client, &request, replica))
+        .collect();
+
+    let mut result = client.send_once(http_client, request).await;
+
+    for replica_request in replica_requests {
+        if !policy.should_fail_over(&result) {
+            break;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(url = %replica_request.url(), "failing over PocketBase request to a replica");
+
+        result = client.send_once(http_client, replica_request).await;
+    }
+
+    result
+}
+
+/// Returns a copy of the request, sent to the given replica instead of the primary instance.
+fn replica_request(client: &PocketBase, request: &Request, replica: &str) -> Option<Request> {
+    // The read may have been routed to the read base URL
+    let base_url = client.read_base_url.as_deref().unwrap_or(&client.base_url);
+
+    let replica_request = request.try_clone().and_then(|mut replica_request| {
+        *replica_request.url_mut() = routing::rebase(request.url(), base_url, replica)?;
+        Some(replica_request)
+    });
+
+    #[cfg(feature = "tracing")]
+    if replica_request.is_none() {
+        tracing::warn!(url = %request.url(), replica, "PocketBase request can't fail over to a replica");
+    }
+
+    replica_request
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::{Value, json};
+
+    use super::FailoverPolicy;
+    use crate::PocketBase;
+
+    #[tokio::test]
+    async fn reads_fail_over_from_a_non_normalized_base_url() {
+        let replica = MockServer::start_async().await;
+
+        let read = replica.mock(|when, then| {
+            when.method(GET)
+                .path("/api/collections/articles/records/abc123");
+            then.status(200).json_body(json!({ "id": "abc123" }));
+        });
+
+        // Nothing listens on the primary instance
+        let pb = PocketBase::builder("HTTP://127.0.0.1:1/")
+            .failover(FailoverPolicy::new([replica.base_url()]))
+            .build()
+            .unwrap();
+
+        pb.collection("articles")
+            .get_one::<Value>("abc123")
+            .call()
+            .await
+            .unwrap();
+
+        read.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn writes_never_fail_over() {
+        let replica = MockServer::start_async().await;
+
+        let write = replica.mock(|when, then| {
+            when.method(DELETE);
+            then.status(204);
+        });
+
+        let pb = PocketBase::builder("http://127.0.0.1:1")
+            .failover(FailoverPolicy::new([replica.base_url()]))
+            .build()
+            .unwrap();
+
+        let result = pb.collection("articles").delete("abc123").call().await;
+
+        assert!(result.is_err());
+        write.assert_hits(0);
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+use std::ops::Deref;
+
+use crate::{Filter, FilterValue, GeoPoint, Sort};
+
+/// The name of a record field, usable in the filter and sort builders.
+///
+/// With the `derive` feature, deriving `PocketBaseRecord` generates a `fields()` accessor
+/// returning one `Field` per struct field. Misspelled field names then fail to compile,
+/// instead of being rejected by the server with a `400 Bad Request`.
+///
+/// A `Field` dereferences to `&str`, so it can also be given to any method taking a field name.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize, PocketBaseRecord)]
+/// #[pocketbase(collection = "articles")]
+/// struct Article {
+///     id: String,
+///     title: String,
+///     views: u32,
+/// }
+///
+/// let fields = Article::fields();
+///
+/// let articles = pb
+///     .records::<Article>()
+///     .get_list()
+///     .filter(&fields.title.like("rust").and(fields.views.gt(100)))
+///     .sort(&fields.views.desc())
+///     .call()
+///     .await?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Field(&'static str);
+
+impl Field {
+    /// Creates a field reference from its name in the collection.
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    /// Returns the name of the field.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.0
+    }
+
+    /// `field = value`
+    #[must_use]
+    pub fn eq(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::eq(self.0, value)
+    }
+
+    /// `field != value`
+    #[must_use]
+    pub fn ne(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::ne(self.0, value)
+    }
+
+    /// `field > value`
+    #[must_use]
+    pub fn gt(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::gt(self.0, value)
+    }
+
+    /// `field >= value`
+    #[must_use]
+    pub fn gte(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::gte(self.0, value)
+    }
+
+    /// `field < value`
+    #[must_use]
+    pub fn lt(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::lt(self.0, value)
+    }
+
+    /// `field <= value`
+    #[must_use]
+    pub fn lte(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::lte(self.0, value)
+    }
+
+    /// `field ~ value` *(contains, case-insensitive)*
+    #[must_use]
+    pub fn like(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::like(self.0, value)
+    }
+
+    /// `field !~ value` *(does not contain, case-insensitive)*
+    #[must_use]
+    pub fn not_like(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::not_like(self.0, value)
+    }
+
+    /// `field ?= value` *(at least one of the multiple values is equal)*
+    #[must_use]
+    pub fn any_eq(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::any_eq(self.0, value)
+    }
+
+    /// `field ?~ value` *(at least one of the multiple values contains)*
+    #[must_use]
+    pub fn any_like(self, value: impl Into<FilterValue>) -> Filter {
+        Filter::any_like(self.0, value)
+    }
+
+    /// `geoDistance(field.lon, field.lat, point.lon, point.lat) <= km`
+    #[must_use]
+    pub fn geo_within(self, point: GeoPoint, km: f64) -> Filter {
+        Filter::geo_within(self.0, point, km)
+    }
+
+    /// `geoDistance(field.lon, field.lat, point.lon, point.lat) > km`
+    #[must_use]
+    pub fn geo_beyond(self, point: GeoPoint, km: f64) -> Filter {
+        Filter::geo_beyond(self.0, point, km)
+    }
+
+    /// Sort by this field in ascending order.
+    #[must_use]
+    pub fn asc(self) -> Sort {
+        Sort::asc(self.0)
+    }
+
+    /// Sort by this field in descending order.
+    #[must_use]
+    pub fn desc(self) -> Sort {
+        Sort::desc(self.0)
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl Deref for Field {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl AsRef<str> for Field {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl From<Field> for String {
+    fn from(field: Field) -> Self {
+        field.0.to_owned()
+    }
+}
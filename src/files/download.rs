@@ -0,0 +1,211 @@
+use bytes::Bytes;
+use futures_util::{Stream, TryStreamExt};
+use tokio_util::sync::CancellationToken;
+
+use super::Files;
+use super::url::Thumb;
+use crate::PocketBase;
+use crate::error::{ApiErrorResponse, RequestError};
+use crate::options::{RequestOptions, cancellable};
+
+/// Builder for downloading a file stored in `PocketBase`.
+pub struct FileDownloadBuilder<'a> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    record_id: &'a str,
+    filename: &'a str,
+    thumb: Option<Thumb>,
+    token: Option<&'a str>,
+    protected: bool,
+    options: RequestOptions<'a>,
+}
+
+/// A successfully requested file, whose content can be read at once or streamed.
+pub struct FileDownload {
+    pub(crate) response: reqwest::Response,
+}
+
+impl<'a> Files<'a> {
+    /// Downloads a file stored in a record's `file` field.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let file = pb
+    ///     .files()
+    ///     .download("documents", "RECORD_ID", "contract_52iwbgds7l.pdf")
+    ///     .protected(true)
+    ///     .call()
+    ///     .await?;
+    ///
+    /// println!("Content-Type: {:?}", file.content_type());
+    ///
+    /// let content = file.bytes().await?;
+    /// ```
+    #[must_use]
+    pub const fn download(
+        &self,
+        collection_name: &'a str,
+        record_id: &'a str,
+        filename: &'a str,
+    ) -> FileDownloadBuilder<'a> {
+        FileDownloadBuilder {
+            client: self.client,
+            collection_name,
+            record_id,
+            filename,
+            thumb: None,
+            token: None,
+            protected: false,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> FileDownloadBuilder<'a> {
+    /// Download a thumbnail of the image instead of the original file.
+    pub const fn thumb(mut self, thumb: Thumb) -> Self {
+        self.thumb = Some(thumb);
+        self
+    }
+
+    /// Use the given file token to access a protected file.
+    pub const fn token(mut self, token: &'a str) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// The file is protected: a file token is automatically requested for the
+    /// authenticated client before downloading it.
+    ///
+    /// Ignored if a token was given with [`FileDownloadBuilder::token()`].
+    pub const fn protected(mut self, protected: bool) -> Self {
+        self.protected = protected;
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the file.
+    pub async fn call(self) -> Result<FileDownload, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<FileDownload, RequestError> {
+        let files = self.client.files();
+
+        let requested_token = match (self.token, self.protected) {
+            (None, true) => Some(files.get_token().await?),
+            _ => None,
+        };
+
+        let mut url_builder = files.get_url(self.collection_name, self.record_id, self.filename);
+
+        if let Some(thumb) = self.thumb {
+            url_builder = url_builder.thumb(thumb);
+        }
+
+        if let Some(token) = self.token.or(requested_token.as_deref()) {
+            url_builder = url_builder.token(token);
+        }
+
+        let url = url_builder.build();
+
+        let request = self.options.apply(
+            self.client
+                .with_authorization_token(self.client.reqwest_client.get(&url)),
+        );
+
+        let Ok(response) = self.client.send_request(request).await else {
+            return Err(RequestError::Unreachable);
+        };
+
+        match response.status() {
+            reqwest::StatusCode::OK => Ok(FileDownload { response }),
+            reqwest::StatusCode::BAD_REQUEST => Err(RequestError::BadRequest(
+                ApiErrorResponse::from_response(response).await,
+            )),
+            reqwest::StatusCode::UNAUTHORIZED => Err(RequestError::Unauthorized),
+            reqwest::StatusCode::FORBIDDEN => Err(RequestError::Forbidden),
+            reqwest::StatusCode::NOT_FOUND => Err(RequestError::NotFound),
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(RequestError::TooManyRequests),
+            _ => Err(RequestError::from_response(response).await),
+        }
+    }
+}
+
+impl FileDownload {
+    /// The `Content-Type` of the file, as returned by `PocketBase`.
+    #[must_use]
+    pub fn content_type(&self) -> Option<&str> {
+        self.response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+    }
+
+    /// The size of the file in bytes, if known.
+    #[must_use]
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// Read the whole file content in memory.
+    pub async fn bytes(self) -> Result<Bytes, RequestError> {
+        self.response
+            .bytes()
+            .await
+            .map_err(|_| RequestError::Unreachable)
+    }
+
+    /// Stream the file content chunk by chunk, without buffering it in memory.
+    pub fn stream(self) -> impl Stream<Item = Result<Bytes, RequestError>> {
+        self.response
+            .bytes_stream()
+            .map_err(|_| RequestError::Unreachable)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::PocketBase;
+
+pub mod download;
+pub mod token;
+pub mod url;
+
+/// Provides access to the files served by a `PocketBase` instance.
+///
+/// Instances of this struct are created using the [`PocketBase::files`] method.
+pub struct Files<'a> {
+    pub(crate) client: &'a PocketBase,
+}
+
+impl PocketBase {
+    /// Creates a new [`Files`] instance to interact with the files stored in `PocketBase`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// let url = pb
+    ///     .files()
+    ///     .get_url("foxes", "RECORD_ID", "vulpes_vulpes_4cm1ahzq3u.jpg")
+    ///     .thumb(Thumb::Center(100, 100))
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub const fn files(&self) -> Files<'_> {
+        Files { client: self }
+    }
+}
@@ -0,0 +1,55 @@
+use serde::Deserialize;
+
+use super::Files;
+use crate::error::{ApiErrorResponse, RequestError};
+
+#[derive(Deserialize)]
+struct FileTokenResponse {
+    token: String,
+}
+
+impl Files<'_> {
+    /// Requests a new short-lived file token, used to access protected files.
+    ///
+    /// The client must be authenticated.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let token = pb.files().get_token().await?;
+    ///
+    /// let url = pb
+    ///     .files()
+    ///     .get_url("documents", "RECORD_ID", "contract_52iwbgds7l.pdf")
+    ///     .token(&token)
+    ///     .build();
+    /// ```
+    pub async fn get_token(&self) -> Result<String, RequestError> {
+        let url = format!("{}/api/files/token", self.client.base_url);
+
+        let request = self
+            .client
+            .send_request(self.client.request_post(&url))
+            .await;
+
+        match request {
+            Ok(response) => match response.status() {
+                reqwest::StatusCode::OK => {
+                    let data = response.json::<FileTokenResponse>().await;
+
+                    match data {
+                        Ok(data) => Ok(data.token),
+                        Err(error) => Err(RequestError::ParseError(error.to_string())),
+                    }
+                }
+                reqwest::StatusCode::BAD_REQUEST => Err(RequestError::BadRequest(
+                    ApiErrorResponse::from_response(response).await,
+                )),
+                reqwest::StatusCode::UNAUTHORIZED => Err(RequestError::Unauthorized),
+                reqwest::StatusCode::FORBIDDEN => Err(RequestError::Forbidden),
+                reqwest::StatusCode::TOO_MANY_REQUESTS => Err(RequestError::TooManyRequests),
+                _ => Err(RequestError::from_response(response).await),
+            },
+            Err(_) => Err(RequestError::Unreachable),
+        }
+    }
+}
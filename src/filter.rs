@@ -0,0 +1,305 @@
+use std::fmt;
+use std::ops::Deref;
+
+use crate::GeoPoint;
+
+/// A value compared against a record field in a [`Filter`].
+///
+/// Strings are always quoted and escaped when rendered, so user input can be
+/// safely used as a filter value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterValue {
+    /// A string literal, rendered as `'value'`.
+    String(String),
+    /// An integer literal.
+    Integer(i64),
+    /// A floating point literal.
+    Float(f64),
+    /// A boolean literal, rendered as `true` or `false`.
+    Bool(bool),
+    /// The `null` literal.
+    Null,
+}
+
+impl fmt::Display for FilterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(value) => write!(f, "'{}'", escape_filter_value(value)),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Null => write!(f, "null"),
+        }
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&String> for FilterValue {
+    fn from(value: &String) -> Self {
+        Self::String(value.clone())
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for FilterValue {
+    fn from(value: i32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<i64> for FilterValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<u32> for FilterValue {
+    fn from(value: u32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<f64> for FilterValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl<T: Into<Self>> From<Option<T>> for FilterValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+/// A `PocketBase` filter expression.
+///
+/// Values given to the comparison constructors are escaped, preventing filter
+/// injection when building a filter from user input.
+///
+/// A `Filter` dereferences to `&str`, so it can be given as-is to the `.filter()`
+/// method of the list builders.
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::Filter;
+///
+/// let filter = Filter::eq("language", "en").and(Filter::gt("created", "2024-01-01 00:00:00"));
+///
+/// let articles = pb
+///     .collection("articles")
+///     .get_list::<Article>()
+///     .filter(&filter)
+///     .call()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    expression: String,
+    compound: bool,
+}
+
+impl Filter {
+    /// Creates a filter from a raw expression.
+    ///
+    /// Placeholders in the form of `{:name}` can be replaced with escaped values
+    /// using [`Filter::bind()`]. This is the equivalent of the JS SDK's `pb.filter()`.
+    ///
+    /// **The expression itself is not escaped: never build it from user input.**
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let filter = Filter::raw("title ~ {:title} && created >= {:created}")
+    ///     .bind("title", user_input)
+    ///     .bind("created", "2024-01-01 00:00:00");
+    /// ```
+    #[must_use]
+    pub fn raw(expression: impl Into<String>) -> Self {
+        Self {
+            expression: expression.into(),
+            compound: true,
+        }
+    }
+
+    /// Replaces every `{:name}` placeholder with the given escaped value.
+    #[must_use]
+    pub fn bind(mut self, name: &str, value: impl Into<FilterValue>) -> Self {
+        let placeholder = format!("{{:{name}}}");
+        self.expression = self
+            .expression
+            .replace(&placeholder, &value.into().to_string());
+        self
+    }
+
+    fn compare(field: &str, operator: &str, value: impl Into<FilterValue>) -> Self {
+        Self {
+            expression: format!("{field} {operator} {}", value.into()),
+            compound: false,
+        }
+    }
+
+    /// `field = value`
+    #[must_use]
+    pub fn eq(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, "=", value)
+    }
+
+    /// `field != value`
+    #[must_use]
+    pub fn ne(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, "!=", value)
+    }
+
+    /// `field > value`
+    #[must_use]
+    pub fn gt(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, ">", value)
+    }
+
+    /// `field >= value`
+    #[must_use]
+    pub fn gte(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, ">=", value)
+    }
+
+    /// `field < value`
+    #[must_use]
+    pub fn lt(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, "<", value)
+    }
+
+    /// `field <= value`
+    #[must_use]
+    pub fn lte(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, "<=", value)
+    }
+
+    /// `field ~ value` *(contains, case-insensitive)*
+    #[must_use]
+    pub fn like(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, "~", value)
+    }
+
+    /// `field !~ value` *(does not contain, case-insensitive)*
+    #[must_use]
+    pub fn not_like(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, "!~", value)
+    }
+
+    /// `field ?= value` *(at least one of the multiple values is equal)*
+    #[must_use]
+    pub fn any_eq(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, "?=", value)
+    }
+
+    /// `field ?~ value` *(at least one of the multiple values contains)*
+    #[must_use]
+    pub fn any_like(field: &str, value: impl Into<FilterValue>) -> Self {
+        Self::compare(field, "?~", value)
+    }
+
+    /// `geoDistance(field.lon, field.lat, point.lon, point.lat) <= km`
+    ///
+    /// Matches the records whose `geoPoint` field is at most `km` kilometers away from `point`.
+    #[must_use]
+    pub fn geo_within(field: &str, point: GeoPoint, km: f64) -> Self {
+        Self::geo_distance(field, point, "<=", km)
+    }
+
+    /// `geoDistance(field.lon, field.lat, point.lon, point.lat) > km`
+    ///
+    /// Matches the records whose `geoPoint` field is more than `km` kilometers away from `point`.
+    #[must_use]
+    pub fn geo_beyond(field: &str, point: GeoPoint, km: f64) -> Self {
+        Self::geo_distance(field, point, ">", km)
+    }
+
+    fn geo_distance(field: &str, point: GeoPoint, operator: &str, km: f64) -> Self {
+        Self {
+            expression: format!(
+                "geoDistance({field}.lon, {field}.lat, {}, {}) {operator} {km}",
+                point.lon, point.lat
+            ),
+            compound: false,
+        }
+    }
+
+    /// Combines two filters with `&&`.
+    #[must_use]
+    pub fn and(self, other: Self) -> Self {
+        self.combine("&&", other)
+    }
+
+    /// Combines two filters with `||`.
+    #[must_use]
+    pub fn or(self, other: Self) -> Self {
+        self.combine("||", other)
+    }
+
+    fn combine(self, operator: &str, other: Self) -> Self {
+        Self {
+            expression: format!("{} {operator} {}", self.grouped(), other.grouped()),
+            compound: true,
+        }
+    }
+
+    fn grouped(self) -> String {
+        if self.compound {
+            format!("({})", self.expression)
+        } else {
+            self.expression
+        }
+    }
+
+    /// Returns the rendered filter expression.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.expression
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+impl Deref for Filter {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.expression
+    }
+}
+
+impl AsRef<str> for Filter {
+    fn as_ref(&self) -> &str {
+        &self.expression
+    }
+}
+
+impl From<Filter> for String {
+    fn from(filter: Filter) -> Self {
+        filter.expression
+    }
+}
+
+/// Escapes single quotes so that the value can be safely used inside a quoted filter literal.
+pub fn escape_filter_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'")
+}
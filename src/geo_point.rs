@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// The value of a `geoPoint` record field.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Place {
+///     id: String,
+///     name: String,
+///     location: GeoPoint,
+/// }
+///
+/// let sofia = GeoPoint::new(23.32, 42.69);
+///
+/// let nearby = pb
+///     .collection("places")
+///     .get_list::<Place>()
+///     .filter(&Filter::geo_within("location", sofia, 25.0))
+///     .call()
+///     .await?;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+    /// The longitude, in degrees.
+    pub lon: f64,
+    /// The latitude, in degrees.
+    pub lat: f64,
+}
+
+impl GeoPoint {
+    /// Creates a point from its longitude and latitude, in degrees.
+    #[must_use]
+    pub const fn new(lon: f64, lat: f64) -> Self {
+        Self { lon, lat }
+    }
+}
@@ -0,0 +1,189 @@
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError};
+
+use reqwest::header::HeaderValue;
+use reqwest::{Request, RequestBuilder, Response};
+
+use crate::{PocketBase, TransportError, circuit_breaker, failover};
+
+/// A hook invoked with every outgoing request, right before it is sent.
+pub type BeforeSendHook = Arc<dyn Fn(&mut Request) + Send + Sync>;
+
+/// A hook invoked with every response, right after it is received.
+pub type AfterSendHook = Arc<dyn Fn(&Response) + Send + Sync>;
+
+impl PocketBase {
+    /// Registers a hook called with every outgoing request, right before it is sent.
+    ///
+    /// The request can be freely modified, e.g. to add headers or sign it. Hooks are
+    /// called in the order they were registered, after the auth token has been set,
+    /// and once per attempt when the request is retried.
+    ///
+    /// This is the equivalent of the JS SDK's `beforeSend`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use reqwest::header::HeaderValue;
+    ///
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.before_send(|request| {
+    ///     request
+    ///         .headers_mut()
+    ///         .insert("X-Tenant", HeaderValue::from_static("acme"));
+    /// });
+    /// ```
+    pub fn before_send(&self, hook: impl Fn(&mut Request) + Send + Sync + 'static) {
+        self.before_send_hooks
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Arc::new(hook));
+    }
+
+    /// Registers a hook called with every response, right after it is received.
+    ///
+    /// Hooks are called in the order they were registered, before the response is
+    /// processed by the client. Requests that failed without a response (e.g. a
+    /// connection error) don't trigger the hooks.
+    ///
+    /// This is the equivalent of the JS SDK's `afterSend`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.after_send(|response| {
+    ///     println!("{} {}", response.status(), response.url());
+    /// });
+    /// ```
+    pub fn after_send(&self, hook: impl Fn(&Response) + Send + Sync + 'static) {
+        self.after_send_hooks
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Arc::new(hook));
+    }
+
+    /// Sends the request through the HTTP client, calling the registered hooks.
+    pub(crate) async fn transmit(
+        &self,
+        http_client: &reqwest::Client,
+        mut request: Request,
+    ) -> Result<Response, TransportError> {
+        let circuit_breaker = self.circuit_breaker();
+
+        let permit = match &circuit_breaker {
+            Some(circuit_breaker) => {
+                let Some(permit) = circuit_breaker.acquire() else {
+                    return Ok(circuit_breaker::open_response());
+                };
+
+                Some(permit)
+            }
+            None => None,
+        };
+
+        self.route(&mut request);
+
+        // The hooks are cloned so that they are not called while holding a lock,
+        // allowing them to use the client.
+        let before_send_hooks = self
+            .before_send_hooks
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        for hook in &before_send_hooks {
+            hook(&mut request);
+        }
+
+        let response = match &self.failover {
+            Some(failover) => {
+                failover::send_with_failover(self, failover, http_client, request).await
+            }
+            None => self.send_once(http_client, request).await,
+        };
+
+        if let Some(permit) = permit {
+            permit.record(&response);
+        }
+
+        let response = response?;
+
+        let after_send_hooks = self
+            .after_send_hooks
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        for hook in &after_send_hooks {
+            hook(&response);
+        }
+
+        Ok(response)
+    }
+
+    /// Sends the request through the transport, or the HTTP client, without calling the hooks.
+    ///
+    /// The request is signed here, as each attempt may be sent to a different replica.
+    pub(crate) async fn send_once(
+        &self,
+        http_client: &reqwest::Client,
+        #[cfg_attr(not(feature = "signing"), allow(unused_mut))] mut request: Request,
+    ) -> Result<Response, TransportError> {
+        #[cfg(feature = "signing")]
+        self.sign(&mut request);
+
+        match &self.transport {
+            Some(transport) => transport.execute(request).await,
+            None => Ok(http_client.execute(request).await?),
+        }
+    }
+
+    /// Builds the request and sends it through [`PocketBase::transmit()`].
+    pub(crate) async fn transmit_builder(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<Response, TransportError> {
+        let (http_client, request) = request.build_split();
+
+        self.transmit(&http_client, request?).await
+    }
+}
+
+impl PocketBase {
+    /// Sets a new request ID on the request, if enabled and not already set.
+    ///
+    /// Fails if the request can't be built, with the error it would have failed with when sent.
+    pub(crate) fn with_request_id(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<RequestBuilder, TransportError> {
+        let Some(header) = &self.request_id_header else {
+            return Ok(request);
+        };
+
+        let (http_client, request) = request.build_split();
+        let mut request = request?;
+
+        if !request.headers().contains_key(header)
+            && let Ok(value) = HeaderValue::from_str(&generate_request_id())
+        {
+            request.headers_mut().insert(header.clone(), value);
+        }
+
+        Ok(RequestBuilder::from_parts(http_client, request))
+    }
+}
+
+/// Generates a random 128-bit ID, formatted as 32 hexadecimal characters.
+fn generate_request_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    // `RandomState` is randomly seeded, the counter keeps the IDs unique within the process
+    let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let high = RandomState::new().hash_one(counter);
+    let low = RandomState::new().hash_one(counter);
+
+    format!("{high:016x}{low:016x}")
+}
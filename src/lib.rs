@@ -8,7 +8,7 @@
 //! use pocketbase_rs::{PocketBase, Collection, RequestError};
 //! use serde::Deserialize;
 //!
-//! #[derive(Default, Deserialize, Clone)]
+//! #[derive(Deserialize)]
 //! struct Article {
 //!     title: String,
 //!     content: String,
@@ -16,7 +16,7 @@
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn Error>> {
-//!     let mut pb = PocketBase::new("http://localhost:8090");
+//!     let pb = PocketBase::new("http://localhost:8090");
 //!
 //!     let auth_data = pb
 //!         .collection("users")
@@ -34,22 +34,118 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! - `chrono`: adds [`Timestamp::to_chrono()`], and date literals from `chrono` datetimes in filters.
+//! - `time`: adds [`Timestamp::to_time()`], and date literals from `time` datetimes in filters.
+//! - `derive`: adds `#[derive(PocketBaseRecord)]`, see [`PocketBaseRecord`].
+//! - `tracing`: instruments every request with a `pocketbase.request` span (method, endpoint,
+//!   collection, status and latency), and emits debug events on retries and auth token refreshes.
+//! - `oauth2-flow`: adds `Collection::auth_with_oauth2()`, authenticating with an `OAuth2`
+//!   provider through the realtime API, like the JS SDK's `authWithOAuth2()`.
+//! - `signing`: adds `PocketBase::set_request_signer()`, signing every outgoing request (e.g. with an HMAC).
+//! - `testing`: adds the [`testing`] module, to unit test code using a client without a
+//!   running server.
 
 #![deny(missing_docs)]
 #![warn(clippy::nursery)]
 #![warn(clippy::pedantic)]
 #![allow(clippy::missing_errors_doc)]
 #![allow(clippy::module_name_repetitions)]
+#![allow(clippy::result_large_err)]
 #![allow(dead_code)]
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use hooks::{AfterSendHook, BeforeSendHook};
 
+pub use backups::{BackupFileInfo, Backups};
+pub use builder::PocketBaseBuilder;
+pub use circuit_breaker::CircuitBreaker;
+pub use collections::Collections;
+pub use collections::model::{CollectionField, CollectionModel, CollectionType};
+pub use collections::scaffolds::CollectionScaffolds;
+pub use crons::{CronJob, Crons};
+pub use datetime::Timestamp;
 pub use error::*;
+pub use failover::FailoverPolicy;
+pub use field::Field;
+pub use files::Files;
+pub use files::download::FileDownload;
+pub use files::url::Thumb;
+pub use filter::{Filter, FilterValue};
+pub use geo_point::GeoPoint;
+pub use metrics::RequestMetrics;
+#[cfg(feature = "derive")]
+pub use pocketbase_rs_derive::PocketBaseRecord;
+pub use pool::PocketBasePool;
+pub use realtime::{CollectionSubscribeBuilder, Realtime, RecordAction, RecordEvent, Subscription};
+pub use record_id::{generate_record_id, validate_record_id};
+pub use records::auth::auto_refresh::AutoRefresh;
+pub use records::auth::external_auths::ExternalAuth;
 pub use records::auth::{AuthStore, AuthStoreRecord};
+pub use records::crud::get_full_list::PartialList;
+pub use records::crud::get_list::Pages;
+pub use records::crud::import::{ImportProgress, ImportReport};
+pub use records::crud::list_options::ListOptions;
+pub use records::json_value::JsonValue;
+pub use records::model::{DynamicRecord, Expand, Record};
+pub use records::raw::RawBody;
+pub use records::relation::{InlineExpand, Relation, Relations};
+pub use records::system::{AuthOrigin, Mfa, Otp, Superuser, SystemCollections};
+pub use records::typed::{PocketBaseRecord, Records};
+pub use records::view::ViewCollection;
+pub use reqwest::Method;
 use reqwest::RequestBuilder;
 pub use reqwest::multipart::{Form, Part};
+pub use retry::RetryPolicy;
+pub use send::SendBuilder;
 use serde::{Deserialize, Serialize};
+pub use settings::Settings;
+pub use settings::model::{AppSettings, MetaSettings, S3Settings, SmtpSettings};
+pub use settings::test::{EmailTemplate, S3Filesystem};
+#[cfg(feature = "signing")]
+pub use signing::{RequestSigner, SigningRequest};
+pub use sort::Sort;
+pub use tokio_util::sync::CancellationToken;
+pub use transport::{HttpTransport, TransportError, TransportFuture};
 
+pub(crate) mod auto_cancel;
+pub(crate) mod backups;
+pub(crate) mod builder;
+pub(crate) mod cache;
+pub(crate) mod circuit_breaker;
+pub(crate) mod collections;
+pub(crate) mod crons;
+pub(crate) mod datetime;
 pub mod error;
+pub(crate) mod failover;
+pub(crate) mod field;
+pub(crate) mod files;
+pub(crate) mod filter;
+pub(crate) mod geo_point;
+pub(crate) mod hooks;
+pub(crate) mod metrics;
+pub(crate) mod options;
+pub(crate) mod pool;
+pub(crate) mod realtime;
+pub(crate) mod record_id;
 pub(crate) mod records;
+pub(crate) mod retry;
+pub(crate) mod routing;
+pub(crate) mod send;
+pub(crate) mod settings;
+#[cfg(feature = "signing")]
+pub(crate) mod signing;
+pub(crate) mod sort;
+#[cfg(feature = "tracing")]
+pub(crate) mod telemetry;
+#[cfg(test)]
+mod test_utils;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub(crate) mod transport;
 
 /// Represents a specific collection in a `PocketBase` database.
 ///
@@ -60,11 +156,12 @@ pub(crate) mod records;
 /// this struct.
 ///
 /// # Fields
-/// - `client`: A mutable reference to the `PocketBase` client instance.
+/// - `client`: A reference to the `PocketBase` client instance.
 ///   This allows the `Collection` to send requests to `PocketBase`.
 /// - `name`: The name of the collection being interacted with.
+#[derive(Clone, Copy)]
 pub struct Collection<'a> {
-    pub(crate) client: &'a mut PocketBase,
+    pub(crate) client: &'a PocketBase,
     pub(crate) name: &'a str,
 }
 
@@ -76,14 +173,14 @@ impl PocketBase {
     /// by this method.
     ///
     /// # Arguments
-    /// * `collection_name` - The name of the collection to interact with, provided as a static string.
+    /// * `collection_name` - The name of the collection to interact with.
     ///
     /// # Returns
     /// A [`Collection`] instance configured for the specified collection.
     ///
     /// # Example
     /// ```rust,ignore
-    /// let mut pb = PocketBase::new("http://localhost:8090");
+    /// let pb = PocketBase::new("http://localhost:8090");
     ///
     /// pb.collection("users")
     ///     .auth_with_password("YOUR_EMAIL_OR_USERNAME", "YOUR_PASSWORD")
@@ -100,25 +197,53 @@ impl PocketBase {
     /// # Panics
     ///
     /// This method will panic if the collection name is empty or contains invalid characters.
-    pub fn collection(&mut self, collection_name: &'static str) -> Collection {
+    /// Use [`PocketBase::try_collection()`] for collection names known at runtime only.
+    #[must_use]
+    pub fn collection<'a>(&'a self, collection_name: &'a str) -> Collection<'a> {
+        match self.try_collection(collection_name) {
+            Ok(collection) => collection,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Creates a new [`Collection`] instance for the specified collection name,
+    /// returning an error instead of panicking if the name is invalid.
+    ///
+    /// Useful when the collection name comes from a configuration or user input.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let collection_name = format!("{tenant}_articles");
+    ///
+    /// let articles = pb
+    ///     .try_collection(&collection_name)?
+    ///     .get_list::<Article>()
+    ///     .call()
+    ///     .await?;
+    /// ```
+    pub fn try_collection<'a>(
+        &'a self,
+        collection_name: &'a str,
+    ) -> Result<Collection<'a>, CollectionNameError> {
         // Validate collection name
-        assert!(
-            !collection_name.is_empty(),
-            "Collection name cannot be empty"
-        );
+        if collection_name.is_empty() {
+            return Err(CollectionNameError::Empty);
+        }
 
         // Collection names should only contain alphanumeric characters and underscores
-        assert!(
-            collection_name
-                .chars()
-                .all(|c| c.is_alphanumeric() || c == '_'),
-            "Collection name contains invalid characters. Only alphanumeric characters and underscores are allowed"
-        );
+        if !collection_name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_')
+        {
+            return Err(CollectionNameError::InvalidCharacters(
+                collection_name.to_owned(),
+            ));
+        }
 
-        Collection {
+        Ok(Collection {
             client: self,
             name: collection_name,
-        }
+        })
     }
 }
 
@@ -156,6 +281,20 @@ pub struct RecordList<T> {
     pub items: Vec<T>,
 }
 
+/// A successful response from the `PocketBase` API, along with its HTTP metadata.
+///
+/// Returned by the `call_with_response()` variants of the builders, for when the
+/// status code or the headers (e.g. a `X-Request-Id` set by a reverse proxy) are needed.
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// The HTTP status code of the response.
+    pub status: reqwest::StatusCode,
+    /// The HTTP headers of the response.
+    pub headers: reqwest::header::HeaderMap,
+    /// The parsed response body.
+    pub data: T,
+}
+
 /// Response structure for API errors from `PocketBase`.
 #[derive(Deserialize, Debug)]
 pub(crate) struct ErrorResponse {
@@ -167,11 +306,18 @@ pub(crate) struct ErrorResponse {
     pub data: Option<serde_json::Value>,
 }
 
+/// A callback invoked whenever the auth store of a client changes.
+pub(crate) type AuthChangeListener = Arc<dyn Fn(Option<&AuthStore>) + Send + Sync>;
+
 /// A `PocketBase` client for sending requests to a `PocketBase` instance.
 ///
 /// The `Debug` implementation for this struct redacts sensitive authentication data
 /// to prevent accidental exposure in logs.
 ///
+/// The auth store is kept behind a lock, so every method only needs `&self`: a single
+/// client can be shared across tasks, e.g. as an `Arc<PocketBase>` in a web server state.
+/// Clones of a client share the same auth store, as well as the same connection pool.
+///
 /// # Example
 /// ```rust,ignore
 /// use std::error::Error;
@@ -186,7 +332,7 @@ pub(crate) struct ErrorResponse {
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn Error>> {
-///     let mut pb = PocketBase::new("http://localhost:8090");
+///     let pb = PocketBase::new("http://localhost:8090");
 ///
 ///     pb.collection("users")
 ///         .auth_with_password("YOUR_EMAIL_OR_USERNAME", "YOUR_PASSWORD")
@@ -206,28 +352,78 @@ pub(crate) struct ErrorResponse {
 #[derive(Clone)]
 pub struct PocketBase {
     pub(crate) base_url: String,
-    pub(crate) auth_store: Option<AuthStore>,
+    pub(crate) read_base_url: Option<String>,
+    pub(crate) auth_store: Arc<RwLock<Option<AuthStore>>>,
+    pub(crate) auth_change_listeners: Arc<RwLock<Vec<AuthChangeListener>>>,
+    pub(crate) before_send_hooks: Arc<RwLock<Vec<BeforeSendHook>>>,
+    pub(crate) after_send_hooks: Arc<RwLock<Vec<AfterSendHook>>>,
+    #[cfg(feature = "signing")]
+    pub(crate) request_signer: Arc<RwLock<Option<RequestSigner>>>,
     pub(crate) reqwest_client: reqwest::Client,
+    pub(crate) transport: Option<Arc<dyn HttpTransport>>,
+    pub(crate) metrics: Arc<RwLock<Option<Arc<dyn RequestMetrics>>>>,
+    pub(crate) retry_policy: Arc<RwLock<Option<RetryPolicy>>>,
+    pub(crate) circuit_breaker: Arc<RwLock<Option<Arc<circuit_breaker::CircuitBreakerState>>>>,
+    pub(crate) failover: Option<FailoverPolicy>,
+    pub(crate) request_id_header: Option<reqwest::header::HeaderName>,
+    pub(crate) auto_refresh: Arc<RwLock<Option<AutoRefresh>>>,
+    pub(crate) auth_refresh_lock: Arc<tokio::sync::Mutex<()>>,
+    pub(crate) auto_cancellation: Arc<AtomicBool>,
+    pub(crate) pending_requests: Arc<auto_cancel::PendingRequests>,
+    pub(crate) response_cache: Arc<cache::ResponseCache>,
+    pub(crate) realtime: Arc<realtime::RealtimeState>,
 }
 
 impl std::fmt::Debug for PocketBase {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("PocketBase")
+        let mut debug = f.debug_struct("PocketBase");
+
+        debug
             .field("base_url", &self.base_url)
+            .field("read_base_url", &self.read_base_url)
+            .field("auth_store", &self.auth_store().map(|_| "***REDACTED***"))
             .field(
-                "auth_store",
-                &self.auth_store.as_ref().map(|_| "***REDACTED***"),
-            )
+                "auth_change_listeners",
+                &self
+                    .auth_change_listeners
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .len(),
+            );
+
+        #[cfg(feature = "signing")]
+        debug.field(
+            "request_signer",
+            &self
+                .request_signer
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .is_some(),
+        );
+
+        debug
             .field("reqwest_client", &"Client")
-            .finish()
+            .field("metrics", &self.metrics().map(|_| "RequestMetrics"))
+            .field("retry_policy", &self.retry_policy())
+            .field("circuit_breaker", &self.circuit_breaker())
+            .field("failover", &self.failover)
+            .field("request_id_header", &self.request_id_header)
+            .field("auto_refresh", &self.auto_refresh())
+            .field("auto_cancellation", &self.auto_cancellation())
+            .finish_non_exhaustive()
     }
 }
 
 impl PocketBase {
     /// Creates a new instance of the `PocketBase` client.
     ///
+    /// To configure the client (timeouts, headers, proxy, TLS, ...) or to handle an
+    /// invalid URL without panicking, use [`PocketBase::builder()`] instead.
+    ///
     /// # Example
     /// ```rust
+    /// use pocketbase_rs::PocketBase;
+    ///
     /// let pb = PocketBase::new("http://localhost:8090");
     /// // Use the client for further operations like authentication or fetching records
     /// ```
@@ -236,25 +432,9 @@ impl PocketBase {
     /// This method will panic if the provided `base_url` is not a valid URL.
     #[must_use]
     pub fn new(base_url: &str) -> Self {
-        // Validate URL format
-        let trimmed_url = base_url.trim_end_matches('/');
-        assert!(
-            trimmed_url.starts_with("http://") || trimmed_url.starts_with("https://"),
-            "Invalid base_url: must start with http:// or https://"
-        );
-
-        // Create client with sensible defaults
-        let client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
-            .connect_timeout(std::time::Duration::from_secs(10))
+        Self::builder(base_url)
             .build()
-            .expect("Failed to create HTTP client");
-
-        Self {
-            base_url: trimmed_url.to_string(),
-            auth_store: None,
-            reqwest_client: client,
-        }
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 
     /// Creates a new `PocketBase` client with a custom reqwest client.
@@ -263,6 +443,8 @@ impl PocketBase {
     /// ```rust
     /// use std::time::Duration;
     ///
+    /// use pocketbase_rs::PocketBase;
+    ///
     /// let reqwest_client = reqwest::Client::builder()
     ///     .timeout(Duration::from_secs(60))
     ///     .build()
@@ -285,11 +467,189 @@ impl PocketBase {
 
         Self {
             base_url: trimmed_url.to_string(),
-            auth_store: None,
+            read_base_url: None,
+            auth_store: Arc::default(),
+            auth_change_listeners: Arc::default(),
+            before_send_hooks: Arc::default(),
+            after_send_hooks: Arc::default(),
+            #[cfg(feature = "signing")]
+            request_signer: Arc::default(),
             reqwest_client: client,
+            transport: None,
+            metrics: Arc::default(),
+            retry_policy: Arc::default(),
+            circuit_breaker: Arc::default(),
+            failover: None,
+            request_id_header: None,
+            auto_refresh: Arc::default(),
+            auth_refresh_lock: Arc::default(),
+            auto_cancellation: Arc::default(),
+            pending_requests: Arc::default(),
+            response_cache: Arc::default(),
+            realtime: Arc::default(),
+        }
+    }
+
+    /// Enables the automatic retry of failed idempotent requests, using the given policy.
+    ///
+    /// Requests are retried on connection errors, timeouts and on the status codes listed
+    /// in the policy, honoring the delay asked by a `Retry-After` header.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.set_retry_policy(RetryPolicy::default());
+    /// ```
+    pub fn set_retry_policy(&self, retry_policy: RetryPolicy) {
+        *self
+            .retry_policy
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(retry_policy);
+    }
+
+    /// Enables the circuit breaker, failing fast while the `PocketBase` instance is unreachable.
+    ///
+    /// See [`CircuitBreaker`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.set_circuit_breaker(CircuitBreaker::default());
+    /// ```
+    pub fn set_circuit_breaker(&self, circuit_breaker: CircuitBreaker) {
+        *self
+            .circuit_breaker
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(
+            circuit_breaker::CircuitBreakerState::shared(circuit_breaker),
+        );
+    }
+
+    /// Reports every request to the given metrics recorder.
+    ///
+    /// See [`RequestMetrics`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.set_metrics(PrometheusMetrics);
+    /// ```
+    pub fn set_metrics(&self, metrics: impl RequestMetrics + 'static) {
+        *self.metrics.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(metrics));
+    }
+
+    /// Enables the automatic refresh of the auth token before it expires.
+    ///
+    /// See [`AutoRefresh`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.set_auto_refresh(AutoRefresh::new(Duration::from_secs(30 * 60)));
+    /// ```
+    pub fn set_auto_refresh(&self, auto_refresh: AutoRefresh) {
+        *self
+            .auto_refresh
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(auto_refresh);
+    }
+
+    /// The metrics recorder requests are reported to, if any.
+    pub(crate) fn metrics(&self) -> Option<Arc<dyn RequestMetrics>> {
+        self.metrics
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The policy failed requests are retried with, if any.
+    pub(crate) fn retry_policy(&self) -> Option<RetryPolicy> {
+        self.retry_policy
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The state of the circuit breaker, if enabled.
+    pub(crate) fn circuit_breaker(&self) -> Option<Arc<circuit_breaker::CircuitBreakerState>> {
+        self.circuit_breaker
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The auth token auto-refresh settings, if enabled.
+    pub(crate) fn auto_refresh(&self) -> Option<AutoRefresh> {
+        self.auto_refresh
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Sends the given request, refreshing the auth token first if [`AutoRefresh`] is enabled.
+    ///
+    /// The request ID header, if enabled, is set here so that it is kept across retries.
+    ///
+    /// The request is reported to the [`RequestMetrics`] recorder, if any.
+    pub(crate) async fn send_request(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, crate::TransportError> {
+        let request = self.with_request_id(request)?;
+
+        match self.metrics() {
+            Some(metrics) => metrics::send_measured(self, metrics.as_ref(), request).await,
+            None => self.trace(request).await,
         }
     }
 
+    /// Sends the given request, inside a `pocketbase.request` span with the `tracing` feature.
+    pub(crate) async fn trace(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, crate::TransportError> {
+        #[cfg(feature = "tracing")]
+        let response = telemetry::send_instrumented(self, request).await;
+
+        #[cfg(not(feature = "tracing"))]
+        let response = self.dispatch(request).await;
+
+        response
+    }
+
+    /// Sends the given request through the auth refresh and retry layers.
+    pub(crate) async fn dispatch(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, crate::TransportError> {
+        if let Some(auto_refresh) = self.auto_refresh() {
+            return records::auth::auto_refresh::send_with_auto_refresh(
+                self,
+                &auto_refresh,
+                request,
+            )
+            .await;
+        }
+
+        self.execute(request).await
+    }
+
+    /// Sends the given request, retrying it according to the client's [`RetryPolicy`], if any.
+    pub(crate) async fn execute(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, crate::TransportError> {
+        if let Some(retry_policy) = self.retry_policy() {
+            return retry::send_with_retry(self, &retry_policy, request).await;
+        }
+
+        self.transmit_builder(request).await
+    }
+
     /// Retrieves the current auth store, if available.
     ///
     /// # Example
@@ -306,7 +666,46 @@ impl PocketBase {
     /// ```
     #[must_use]
     pub fn auth_store(&self) -> Option<AuthStore> {
-        self.auth_store.clone()
+        self.auth_store
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Restores a previously saved auth store, authenticating the client with it.
+    ///
+    /// The token is not checked against the server: call
+    /// [`Collection::auth_refresh()`] to make sure the session is still valid.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // Save the session when the application exits...
+    /// if let Some(auth_store) = pb.auth_store() {
+    ///     std::fs::write("session.json", serde_json::to_string(&auth_store)?)?;
+    /// }
+    ///
+    /// // ...and resume it on the next launch.
+    /// let auth_store: AuthStore = serde_json::from_str(&std::fs::read_to_string("session.json")?)?;
+    ///
+    /// let pb = PocketBase::new("http://localhost:8090");
+    /// pb.load_auth_store(auth_store);
+    /// ```
+    pub fn load_auth_store(&self, auth_store: AuthStore) {
+        self.update_auth_store(auth_store);
+    }
+
+    /// De-authenticates the client by dropping its token and auth record.
+    ///
+    /// Since clones of a client share the same auth store, they are de-authenticated too.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.clear_auth_store();
+    ///
+    /// assert!(pb.auth_store().is_none());
+    /// ```
+    pub fn clear_auth_store(&self) {
+        self.set_auth_store(None);
     }
 
     /// Retrieves the current authentication token, if available.
@@ -326,6 +725,8 @@ impl PocketBase {
     #[must_use]
     pub fn token(&self) -> Option<String> {
         self.auth_store
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
             .as_ref()
             .map(|auth_store| auth_store.token.clone())
     }
@@ -342,8 +743,51 @@ impl PocketBase {
         self.base_url.clone()
     }
 
-    pub(crate) fn update_auth_store(&mut self, new_auth_store: AuthStore) {
-        self.auth_store = Some(new_auth_store);
+    /// Registers a callback invoked whenever the auth store changes: on authentication,
+    /// refresh, when a saved session is loaded, or when the auth store is cleared.
+    ///
+    /// The callback receives the new auth store, or `None` when the client is de-authenticated.
+    /// It is called synchronously, so it should not block.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.on_auth_change(|auth_store| match auth_store {
+    ///     Some(auth_store) => keyring.set_password(&auth_store.token),
+    ///     None => keyring.delete_password(),
+    /// });
+    /// ```
+    pub fn on_auth_change(&self, callback: impl Fn(Option<&AuthStore>) + Send + Sync + 'static) {
+        self.auth_change_listeners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Arc::new(callback));
+    }
+
+    pub(crate) fn update_auth_store(&self, new_auth_store: AuthStore) {
+        self.set_auth_store(Some(new_auth_store));
+    }
+
+    fn set_auth_store(&self, new_auth_store: Option<AuthStore>) {
+        let mut auth_store = self
+            .auth_store
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        *auth_store = new_auth_store;
+        let new_auth_store = auth_store.clone();
+        drop(auth_store);
+
+        // The listeners are cloned so that they are not called while holding a lock,
+        // allowing them to use the client.
+        let listeners = self
+            .auth_change_listeners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        for listener in listeners {
+            listener(new_auth_store.as_ref());
+        }
     }
 }
 
@@ -363,8 +807,8 @@ impl PocketBase {
         &self,
         request_builder: reqwest::RequestBuilder,
     ) -> reqwest::RequestBuilder {
-        if let Some(auth_store) = self.auth_store() {
-            request_builder.bearer_auth(auth_store.token)
+        if let Some(token) = self.token() {
+            request_builder.bearer_auth(token)
         } else {
             request_builder
         }
@@ -396,7 +840,7 @@ impl PocketBase {
     ///
     /// # Returns
     /// A `reqwest::RequestBuilder` for the `PATCH` request.
-    pub(crate) fn request_patch_json<T: Default + Serialize + Clone + Send>(
+    pub(crate) fn request_patch_json<T: Serialize + Send>(
         &self,
         endpoint: &str,
         params: &T,
@@ -405,6 +849,22 @@ impl PocketBase {
         self.with_authorization_token(request_builder)
     }
 
+    /// Creates a PATCH request builder with a form body for the specified endpoint.
+    ///
+    /// This method initializes a `PATCH` request to the given endpoint with a multipart form body,
+    /// and adds an authorization token if available.
+    ///
+    /// # Arguments
+    /// * `endpoint` - The API endpoint to send the `PATCH` request to.
+    /// * `form` - A `reqwest::multipart::Form` representing the form data for the request.
+    ///
+    /// # Returns
+    /// A `reqwest::RequestBuilder` for the `PATCH` request.
+    pub(crate) fn request_patch_form(&self, endpoint: &str, form: Form) -> RequestBuilder {
+        let request_builder = self.reqwest_client.patch(endpoint).multipart(form);
+        self.with_authorization_token(request_builder)
+    }
+
     /// Creates a POST request builder with JSON body for the specified endpoint.
     ///
     /// This method initializes a `POST` request to the given endpoint with a JSON body,
@@ -416,7 +876,7 @@ impl PocketBase {
     ///
     /// # Returns
     /// A `reqwest::RequestBuilder` for the `POST` request.
-    pub(crate) fn request_post_json<T: Default + Serialize + Clone + Send>(
+    pub(crate) fn request_post_json<T: Serialize + Send>(
         &self,
         endpoint: &str,
         params: &T,
@@ -0,0 +1,116 @@
+use std::time::{Duration, Instant};
+
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+
+use crate::{PocketBase, TransportError, circuit_breaker};
+
+/// Records metrics about the requests sent by a [`PocketBase`] client, e.g. to export
+/// latency histograms to Prometheus.
+///
+/// [`on_request_complete()`](Self::on_request_complete) is called once per request, after
+/// its retries, as soon as the response headers are received. Responses served from the
+/// client cache are not recorded.
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::RequestMetrics;
+///
+/// struct PrometheusMetrics;
+///
+/// impl RequestMetrics for PrometheusMetrics {
+///     fn on_request_complete(
+///         &self,
+///         endpoint: &str,
+///         method: &Method,
+///         status: Option<StatusCode>,
+///         duration: Duration,
+///         _bytes: Option<u64>,
+///     ) {
+///         let status = status.map_or("error".to_owned(), |status| status.as_u16().to_string());
+///
+///         metrics::histogram!(
+///             "pocketbase_request_duration_seconds",
+///             "endpoint" => endpoint.to_owned(),
+///             "method" => method.to_string(),
+///             "status" => status,
+///         )
+///         .record(duration.as_secs_f64());
+///     }
+/// }
+///
+/// let pb = PocketBase::builder("http://localhost:8090")
+///     .metrics(PrometheusMetrics)
+///     .build()?;
+/// ```
+pub trait RequestMetrics: Send + Sync {
+    /// Called when a request is complete.
+    ///
+    /// - `endpoint` is the path of the request, with the record IDs, file names and backup
+    ///   keys replaced by placeholders to keep the amount of distinct endpoints low
+    ///   *(example: `/api/collections/articles/records/:id`)*.
+    /// - `status` is `None` when the request failed without a response (e.g. a connection error),
+    ///   or was not sent as the [circuit breaker](crate::CircuitBreaker) is open.
+    /// - `duration` covers the retries and the automatic refresh of the auth token, if any.
+    /// - `bytes` is the size of the response body, when given by its `Content-Length` header.
+    fn on_request_complete(
+        &self,
+        endpoint: &str,
+        method: &Method,
+        status: Option<StatusCode>,
+        duration: Duration,
+        bytes: Option<u64>,
+    );
+}
+
+/// Sends the request, reporting its outcome to the given metrics recorder.
+pub async fn send_measured(
+    client: &PocketBase,
+    metrics: &dyn RequestMetrics,
+    request: RequestBuilder,
+) -> Result<Response, TransportError> {
+    let (http_client, request) = request.build_split();
+    let request = request?;
+
+    let endpoint = endpoint(request.url().path());
+    let method = request.method().clone();
+    let start = Instant::now();
+
+    let result = client
+        .trace(RequestBuilder::from_parts(http_client, request))
+        .await;
+
+    // The response of an open circuit is synthetic, the request was never sent
+    let (status, bytes) = result
+        .as_ref()
+        .ok()
+        .filter(|response| !circuit_breaker::is_open_response(response))
+        .map_or((None, None), |response| {
+            (Some(response.status()), response.content_length())
+        });
+
+    metrics.on_request_complete(&endpoint, &method, status, start.elapsed(), bytes);
+
+    result
+}
+
+/// Returns the path with its unbounded segments (record IDs, file names and backup keys)
+/// replaced by placeholders.
+fn endpoint(path: &str) -> String {
+    let mut segments: Vec<&str> = path.split('/').collect();
+
+    match segments.as_slice() {
+        ["", "api", "collections", _, kind, _, ..]
+            if matches!(*kind, "records" | "impersonate") =>
+        {
+            segments[5] = ":id";
+        }
+        ["", "api", "files", _, _, _, ..] => {
+            segments[4] = ":id";
+            segments[5] = ":filename";
+        }
+        ["", "api", "backups", key, ..] if *key != "upload" => segments[3] = ":key",
+        _ => {}
+    }
+
+    segments.join("/")
+}
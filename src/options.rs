@@ -0,0 +1,289 @@
+use std::time::Duration;
+
+use reqwest::RequestBuilder;
+use tokio_util::sync::CancellationToken;
+
+use crate::PocketBase;
+use crate::auto_cancel::PendingRequest;
+
+/// Options overriding the client defaults for a single request.
+#[derive(Clone)]
+pub struct RequestOptions<'a> {
+    pub timeout: Option<Duration>,
+    pub headers: Vec<(&'a str, &'a str)>,
+    pub cancellation_token: Option<CancellationToken>,
+    pub cache: Option<Duration>,
+    pub request_key: RequestKey<'a>,
+}
+
+/// The key identifying duplicate requests for the auto-cancellation.
+#[derive(Clone, Copy)]
+pub enum RequestKey<'a> {
+    /// The method and URL of the request, if auto-cancellation is enabled on the client.
+    Default,
+    /// A key given with `.request_key()`.
+    Custom(&'a str),
+    /// The request is never auto-cancelled.
+    Disabled,
+}
+
+impl RequestOptions<'_> {
+    pub const fn new() -> Self {
+        Self {
+            timeout: None,
+            headers: Vec::new(),
+            cancellation_token: None,
+            cache: None,
+            request_key: RequestKey::Default,
+        }
+    }
+
+    /// Returns the token cancelling the request, and registers the request as pending if it
+    /// can be auto-cancelled, cancelling the older pending request with the same key.
+    ///
+    /// The request is only built to compute its default key. The pending request must be
+    /// kept until the request is complete.
+    pub fn cancellation(
+        &self,
+        client: &PocketBase,
+        request: impl FnOnce() -> RequestBuilder,
+    ) -> (Option<CancellationToken>, Option<PendingRequest>) {
+        let key = match self.request_key {
+            RequestKey::Custom(key) => Some(key.to_owned()),
+            RequestKey::Default if client.auto_cancellation() => request()
+                .build()
+                .ok()
+                .map(|request| format!("{} {}", request.method(), request.url())),
+            RequestKey::Default | RequestKey::Disabled => None,
+        };
+
+        let Some(key) = key else {
+            return (self.cancellation_token.clone(), None);
+        };
+
+        let pending = client
+            .pending_requests
+            .register(key, self.cancellation_token.as_ref());
+
+        (Some(pending.token()), Some(pending))
+    }
+
+    /// Applies the options on top of the given request.
+    pub fn apply(&self, mut request: RequestBuilder) -> RequestBuilder {
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        for (key, value) in &self.headers {
+            request = request.header(*key, *value);
+        }
+
+        request
+    }
+}
+
+/// Runs the request until completion, or until the given token is cancelled.
+///
+/// On cancellation, the request future is dropped, which aborts the in-flight request.
+pub async fn cancellable<T, E>(
+    cancellation_token: Option<CancellationToken>,
+    request: impl Future<Output = Result<T, E>>,
+    cancelled: impl FnOnce() -> E,
+) -> Result<T, E> {
+    // The request future is boxed to keep the futures of the builders small
+    let request = Box::pin(request);
+
+    match cancellation_token {
+        Some(cancellation_token) => cancellation_token
+            .run_until_cancelled(request)
+            .await
+            .unwrap_or_else(|| Err(cancelled())),
+        None => request.await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::{Duration, Instant};
+
+    use httpmock::Mock;
+    use httpmock::prelude::*;
+    use serde_json::{Value, json};
+    use tokio_util::sync::CancellationToken;
+
+    use super::cancellable;
+    use crate::PocketBase;
+    use crate::error::RequestError;
+
+    /// The delay of the slow endpoint, never waited for by the cancelled requests.
+    const DELAY: Duration = Duration::from_secs(5);
+
+    fn slow_endpoint(server: &MockServer) -> Mock<'_> {
+        server.mock(|when, then| {
+            when.method(GET).path("/api/collections/articles/records");
+            then.status(200).delay(DELAY).json_body(json!({
+                "page": 1,
+                "perPage": 30,
+                "totalItems": 0,
+                "totalPages": 0,
+                "items": [],
+            }));
+        })
+    }
+
+    /// Sets a flag when dropped, to check that a request future was dropped.
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Returns a request future that never completes, and the flag set when it is dropped.
+    fn pending_request() -> (
+        impl Future<Output = Result<(), RequestError>>,
+        Arc<AtomicBool>,
+    ) {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let flag = DropFlag(dropped.clone());
+
+        let request = async move {
+            let _flag = flag;
+            std::future::pending::<Result<(), RequestError>>().await
+        };
+
+        (request, dropped)
+    }
+
+    #[tokio::test]
+    async fn token_cancelled_before_the_call() {
+        let server = MockServer::start_async().await;
+        let endpoint = slow_endpoint(&server);
+        let pb = PocketBase::new(&server.base_url());
+
+        let cancellation_token = CancellationToken::new();
+        cancellation_token.cancel();
+
+        let result = pb
+            .collection("articles")
+            .get_list::<Value>()
+            .cancellation_token(cancellation_token)
+            .call()
+            .await;
+
+        // The request is never sent
+        assert!(matches!(result, Err(RequestError::Cancelled)));
+        assert_eq!(endpoint.hits(), 0);
+
+        let (request, dropped) = pending_request();
+        let cancellation_token = CancellationToken::new();
+        cancellation_token.cancel();
+
+        let result = cancellable(Some(cancellation_token), request, || {
+            RequestError::Cancelled
+        })
+        .await;
+
+        assert!(matches!(result, Err(RequestError::Cancelled)));
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn token_cancelled_mid_flight() {
+        let server = MockServer::start_async().await;
+        slow_endpoint(&server);
+        let pb = PocketBase::new(&server.base_url());
+
+        let cancellation_token = CancellationToken::new();
+        let start = Instant::now();
+
+        let (result, ()) = tokio::join!(
+            pb.collection("articles")
+                .get_list::<Value>()
+                .cancellation_token(cancellation_token.clone())
+                .call(),
+            async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                cancellation_token.cancel();
+            }
+        );
+
+        // The call returns without waiting for the response
+        assert!(matches!(result, Err(RequestError::Cancelled)));
+        assert!(start.elapsed() < DELAY);
+
+        // The in-flight request is dropped, which aborts it
+        let (request, dropped) = pending_request();
+        let cancellation_token = CancellationToken::new();
+
+        let (result, ()) = tokio::join!(
+            cancellable(Some(cancellation_token.clone()), request, || {
+                RequestError::Cancelled
+            }),
+            async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                assert!(!dropped.load(Ordering::SeqCst));
+                cancellation_token.cancel();
+            }
+        );
+
+        assert!(matches!(result, Err(RequestError::Cancelled)));
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn dropping_the_future() {
+        let server = MockServer::start_async().await;
+        slow_endpoint(&server);
+        let pb = PocketBase::new(&server.base_url());
+
+        let start = Instant::now();
+
+        let call = pb.collection("articles").get_list::<Value>().call();
+        let result = tokio::time::timeout(Duration::from_millis(100), call).await;
+
+        // The call future is dropped by the timeout, without waiting for the response
+        assert!(result.is_err());
+        assert!(start.elapsed() < DELAY);
+
+        // Dropping the call future drops the in-flight request, which aborts it
+        let (request, dropped) = pending_request();
+        let call = cancellable(Some(CancellationToken::new()), request, || {
+            RequestError::Cancelled
+        });
+
+        let result = tokio::time::timeout(Duration::from_millis(10), call).await;
+
+        assert!(result.is_err());
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn token_cancelled_with_request_key() {
+        let server = MockServer::start_async().await;
+        slow_endpoint(&server);
+        let pb = PocketBase::new(&server.base_url());
+
+        let cancellation_token = CancellationToken::new();
+        let start = Instant::now();
+
+        // The token given to the builder also cancels the auto-cancellable request
+        let (result, ()) = tokio::join!(
+            pb.collection("articles")
+                .get_list::<Value>()
+                .request_key("articles")
+                .cancellation_token(cancellation_token.clone())
+                .call(),
+            async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                cancellation_token.cancel();
+            }
+        );
+
+        assert!(matches!(result, Err(RequestError::Cancelled)));
+        assert!(start.elapsed() < DELAY);
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::PocketBase;
+use crate::error::ImpersonateError;
+
+/// A client of the pool, locked while it is being authenticated.
+type Slot = Arc<tokio::sync::Mutex<Option<PocketBase>>>;
+
+/// A pool of clients authenticated as the users of an auth collection, e.g. for a server
+/// sending requests on behalf of many users.
+///
+/// The clients are created on demand by impersonating the users with the superuser client
+/// given to [`PocketBasePool::new()`], and share its settings (HTTP client, retry policy,
+/// hooks, ...). As impersonation tokens can't be refreshed, a client is authenticated again
+/// when its token expires within the refresh threshold.
+///
+/// The clients unused for longer than the idle timeout are evicted, as well as the least
+/// recently used ones once the pool is full.
+///
+/// # Example
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use pocketbase_rs::{PocketBase, PocketBasePool};
+///
+/// let pool = PocketBasePool::new(superuser_client, "users")
+///     .with_token_duration(Duration::from_hours(1))
+///     .with_idle_timeout(Duration::from_mins(10));
+///
+/// // e.g. in a request handler
+/// let pb = pool.get(&user_id).await?;
+///
+/// let articles = pb
+///     .collection("articles")
+///     .get_list::<Article>()
+///     .call()
+///     .await?;
+/// ```
+pub struct PocketBasePool {
+    client: PocketBase,
+    collection: String,
+    token_duration: Option<Duration>,
+    refresh_threshold: Duration,
+    idle_timeout: Duration,
+    max_clients: usize,
+    entries: Mutex<HashMap<String, PoolEntry>>,
+}
+
+struct PoolEntry {
+    slot: Slot,
+    last_used: Instant,
+}
+
+impl fmt::Debug for PocketBasePool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PocketBasePool")
+            .field("collection", &self.collection)
+            .field("token_duration", &self.token_duration)
+            .field("refresh_threshold", &self.refresh_threshold)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("max_clients", &self.max_clients)
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PocketBasePool {
+    /// Creates a pool of clients impersonating the users of the given auth collection.
+    ///
+    /// `client` must be authenticated as a superuser. By default, the tokens have the
+    /// duration configured on the collection and are renewed 1 minute before they expire,
+    /// and the pool keeps up to 1000 clients, unused for at most 30 minutes.
+    #[must_use]
+    pub fn new(client: PocketBase, collection: impl Into<String>) -> Self {
+        Self {
+            client,
+            collection: collection.into(),
+            token_duration: None,
+            refresh_threshold: Duration::from_mins(1),
+            idle_timeout: Duration::from_mins(30),
+            max_clients: 1000,
+            entries: Mutex::default(),
+        }
+    }
+
+    /// Sets the duration of the impersonation tokens.
+    #[must_use]
+    pub const fn with_token_duration(mut self, duration: Duration) -> Self {
+        self.token_duration = Some(duration);
+        self
+    }
+
+    /// Authenticates a client again when its token expires within the given threshold.
+    #[must_use]
+    pub const fn with_refresh_threshold(mut self, threshold: Duration) -> Self {
+        self.refresh_threshold = threshold;
+        self
+    }
+
+    /// Evicts the clients unused for longer than the given timeout.
+    #[must_use]
+    pub const fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum amount of clients kept in the pool, the least recently used
+    /// ones being evicted first.
+    #[must_use]
+    pub const fn with_max_clients(mut self, max_clients: usize) -> Self {
+        self.max_clients = max_clients;
+        self
+    }
+
+    /// Returns a client authenticated as the given user, impersonating them if the pool
+    /// has no valid client for them yet.
+    ///
+    /// Concurrent calls for the same user wait for a single impersonation request.
+    pub async fn get(&self, user_id: &str) -> Result<PocketBase, ImpersonateError> {
+        let slot = self.slot(user_id);
+        let mut client = slot.lock().await;
+
+        if let Some(client) = client.as_ref().filter(|client| self.is_fresh(client)) {
+            return Ok(client.clone());
+        }
+
+        let mut request = self
+            .client
+            .collection(&self.collection)
+            .impersonate(user_id);
+
+        if let Some(duration) = self.token_duration {
+            request = request.duration(u128::from(duration.as_secs()));
+        }
+
+        let impersonated = request.call().await?;
+        *client = Some(impersonated.clone());
+        drop(client);
+
+        Ok(impersonated)
+    }
+
+    /// Removes the client of the given user from the pool, e.g. after their permissions changed.
+    pub fn evict(&self, user_id: &str) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(user_id);
+    }
+
+    /// Removes all the clients from the pool.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Returns the amount of clients in the pool.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns `true` if the pool has no clients.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the slot of the given user, creating it and evicting the stale ones if needed.
+    fn slot(&self, user_id: &str) -> Slot {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+
+        entries.retain(|_, entry| now.duration_since(entry.last_used) < self.idle_timeout);
+
+        if entries.len() >= self.max_clients
+            && !entries.contains_key(user_id)
+            && let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(user_id, _)| user_id.clone())
+        {
+            entries.remove(&oldest);
+        }
+
+        let entry = entries
+            .entry(user_id.to_owned())
+            .or_insert_with(|| PoolEntry {
+                slot: Slot::default(),
+                last_used: now,
+            });
+        entry.last_used = now;
+        let slot = entry.slot.clone();
+        drop(entries);
+
+        slot
+    }
+
+    /// Returns `true` if the token of the client doesn't expire within the refresh threshold.
+    fn is_fresh(&self, client: &PocketBase) -> bool {
+        client
+            .auth_store()
+            .and_then(|auth_store| auth_store.expires_at())
+            .and_then(|expires_at| expires_at.duration_since(SystemTime::now()).ok())
+            .is_some_and(|remaining| remaining > self.refresh_threshold)
+    }
+}
@@ -0,0 +1,665 @@
+use std::collections::{BTreeMap, HashMap};
+use std::future::IntoFuture;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::Stream;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use tokio::task::AbortHandle;
+
+use self::sse::EventStream;
+use crate::error::RequestError;
+use crate::{Collection, PocketBase};
+
+mod sse;
+
+/// How long a realtime connection is kept open before reconnecting.
+///
+/// `PocketBase` closes idle connections after 5 minutes, this is only a safety net.
+const CONNECTION_TIMEOUT: Duration = Duration::from_mins(10);
+
+/// The longest delay between two reconnection attempts.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// The state of the realtime connection, shared by the clones of a client.
+#[derive(Default)]
+pub struct RealtimeState {
+    /// The state of the connection, `None` when there is no connection.
+    ///
+    /// The lock is held while connecting and submitting the subscriptions, so that
+    /// concurrent subscriptions don't open several connections.
+    connection: tokio::sync::Mutex<Option<Connection>>,
+    subscribers: Mutex<HashMap<String, Vec<Subscriber>>>,
+    next_subscriber_id: AtomicU64,
+}
+
+struct Connection {
+    /// The id received in the `PB_CONNECT` event, `None` while reconnecting.
+    client_id: Option<String>,
+    listener: AbortHandle,
+}
+
+struct Subscriber {
+    id: u64,
+    sender: UnboundedSender<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConnectEvent {
+    client_id: String,
+}
+
+/// The options of a subscription, sent as part of its topic.
+#[derive(Serialize)]
+struct SubscriptionOptions<'a> {
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    query: BTreeMap<&'a str, &'a str>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    headers: BTreeMap<&'a str, &'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubscriptionsRequest<'a> {
+    client_id: &'a str,
+    subscriptions: Vec<String>,
+}
+
+/// The action that triggered a realtime event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordAction {
+    /// A record was created.
+    Create,
+    /// A record was updated.
+    Update,
+    /// A record was deleted.
+    Delete,
+}
+
+/// A change of a record, received through a realtime [`Subscription`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct RecordEvent<T> {
+    /// The action that triggered the event.
+    pub action: RecordAction,
+    /// The record, as it is after the action (or before, for a deletion).
+    pub record: T,
+}
+
+/// A realtime subscription to the records of a collection.
+///
+/// Created with [`Collection::subscribe()`] or [`Collection::subscribe_record()`].
+///
+/// The events are received with [`Subscription::recv()`], or by using the subscription as a
+/// [`Stream`]. The subscription is cancelled when dropped.
+pub struct Subscription<T> {
+    client: PocketBase,
+    topic: String,
+    subscriber_id: u64,
+    receiver: UnboundedReceiver<String>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+/// Builder for subscribing to the changes of the records of a collection.
+///
+/// The builder can be awaited directly, or executed through [`CollectionSubscribeBuilder::call()`].
+pub struct CollectionSubscribeBuilder<'a, T> {
+    client: &'a PocketBase,
+    topic: String,
+    query_params: Vec<(&'a str, &'a str)>,
+    headers: Vec<(&'a str, &'a str)>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+/// Manages the realtime subscriptions of a client.
+///
+/// Created with [`PocketBase::realtime()`].
+pub struct Realtime<'a> {
+    client: &'a PocketBase,
+}
+
+impl<'a> Collection<'a> {
+    /// Subscribe to the changes of the records of the collection.
+    ///
+    /// `topic` is either `*` to receive the changes of every record, or the id of a single record.
+    /// The events are only sent for the records the authenticated user can view.
+    ///
+    /// A single realtime connection is shared by all the subscriptions of a client. It is
+    /// automatically re-established if it drops.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use pocketbase_rs::RecordAction;
+    ///
+    /// let mut subscription = pb
+    ///     .collection("articles")
+    ///     .subscribe::<Article>("*")
+    ///     .filter("status = 'published'")
+    ///     .await?;
+    ///
+    /// while let Some(event) = subscription.recv().await {
+    ///     let event = event?;
+    ///
+    ///     match event.action {
+    ///         RecordAction::Create => println!("New article: {}", event.record.title),
+    ///         RecordAction::Update => println!("Updated article: {}", event.record.title),
+    ///         RecordAction::Delete => println!("Deleted article: {}", event.record.title),
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn subscribe<T: DeserializeOwned>(&self, topic: &str) -> CollectionSubscribeBuilder<'a, T> {
+        CollectionSubscribeBuilder {
+            client: self.client,
+            topic: format!("{}/{topic}", self.name),
+            query_params: Vec::new(),
+            headers: Vec::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Subscribe to the changes of a single record.
+    ///
+    /// See [`Collection::subscribe()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut subscription = pb
+    ///     .collection("articles")
+    ///     .subscribe_record::<Article>("record_id_123")
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn subscribe_record<T: DeserializeOwned>(
+        &self,
+        record_id: &str,
+    ) -> CollectionSubscribeBuilder<'a, T> {
+        self.subscribe(record_id)
+    }
+
+    /// Cancel the subscriptions to the given topic (`*` or a record id) of the collection,
+    /// whatever their options.
+    ///
+    /// To cancel all the subscriptions of the collection, see [`Realtime::unsubscribe_by_prefix()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.collection("articles").unsubscribe("*").await?;
+    /// ```
+    pub async fn unsubscribe(&self, topic: &str) -> Result<(), RequestError> {
+        self.client
+            .realtime()
+            .unsubscribe(&format!("{}/{topic}", self.name))
+            .await
+    }
+}
+
+impl<'a, T: DeserializeOwned> CollectionSubscribeBuilder<'a, T> {
+    /// Only receive the events of the records matching the given filter.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .filter("status = 'published'")
+    /// ```
+    #[must_use]
+    pub fn filter(self, filter: &'a str) -> Self {
+        self.query_param("filter", filter)
+    }
+
+    /// Auto expand relations of the records of the events (up to 6-levels deep).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .expand("author")
+    /// ```
+    #[must_use]
+    pub fn expand(self, expand: &'a str) -> Self {
+        self.query_param("expand", expand)
+    }
+
+    /// Comma separated string of the fields of the records of the events.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .fields("id,title")
+    /// ```
+    #[must_use]
+    pub fn fields(self, fields: &'a str) -> Self {
+        self.query_param("fields", fields)
+    }
+
+    /// Add a custom query parameter to the subscription.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query_param("tenant", "acme")
+    /// ```
+    #[must_use]
+    pub fn query_param(mut self, key: &'a str, value: &'a str) -> Self {
+        self.query_params.push((key, value));
+        self
+    }
+
+    /// Add a header to the subscription, as seen by the API rules and hooks of the server.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Tenant", "acme")
+    /// ```
+    #[must_use]
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.headers.push((key, value));
+        self
+    }
+
+    /// Subscribe, and return the subscription.
+    pub async fn call(self) -> Result<Subscription<T>, RequestError> {
+        let topic = self.topic_with_options();
+
+        self.client.subscribe_topic(topic).await
+    }
+
+    /// Returns the topic, with the options encoded as a query parameter, as expected by `PocketBase`.
+    fn topic_with_options(&self) -> String {
+        if self.query_params.is_empty() && self.headers.is_empty() {
+            return self.topic.clone();
+        }
+
+        let options = SubscriptionOptions {
+            query: self.query_params.iter().copied().collect(),
+            headers: self.headers.iter().copied().collect(),
+        };
+
+        let Ok(options) = serde_json::to_string(&options) else {
+            return self.topic.clone();
+        };
+
+        // The URL is only used to encode the options
+        let Ok(mut url) = reqwest::Url::parse("http://localhost") else {
+            return self.topic.clone();
+        };
+
+        url.query_pairs_mut().append_pair("options", &options);
+
+        let separator = if self.topic.contains('?') { '&' } else { '?' };
+
+        format!(
+            "{}{separator}{}",
+            self.topic,
+            url.query().unwrap_or_default()
+        )
+    }
+}
+
+impl<'a, T: DeserializeOwned + 'a> IntoFuture for CollectionSubscribeBuilder<'a, T> {
+    type Output = Result<Subscription<T>, RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
+
+impl PocketBase {
+    /// Returns the manager of the realtime subscriptions of the client.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// println!("Subscribed to: {:?}", pb.realtime().topics());
+    ///
+    /// pb.realtime().unsubscribe_all().await;
+    /// ```
+    #[must_use]
+    pub const fn realtime(&self) -> Realtime<'_> {
+        Realtime { client: self }
+    }
+}
+
+impl Realtime<'_> {
+    /// Returns the topics with at least one active subscription, including their options.
+    #[must_use]
+    pub fn topics(&self) -> Vec<String> {
+        self.client
+            .realtime
+            .subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Cancel the subscriptions to the given topic (e.g. `articles/*`), whatever their options.
+    ///
+    /// The cancelled subscriptions stop receiving events, and [`Subscription::recv()`]
+    /// returns `None`.
+    pub async fn unsubscribe(&self, topic: &str) -> Result<(), RequestError> {
+        let options_prefix = format!("{topic}?");
+
+        self.client
+            .remove_topics(|key| key == topic || key.starts_with(&options_prefix))
+            .await
+    }
+
+    /// Cancel the subscriptions to the topics starting with the given prefix
+    /// (e.g. `articles/` for all the subscriptions of the `articles` collection).
+    pub async fn unsubscribe_by_prefix(&self, prefix: &str) -> Result<(), RequestError> {
+        self.client
+            .remove_topics(|key| key.starts_with(prefix))
+            .await
+    }
+
+    /// Cancel all the subscriptions, and close the realtime connection.
+    pub async fn unsubscribe_all(&self) {
+        // Without subscriptions, the connection is closed without sending any request
+        let _ = self.client.remove_topics(|_| true).await;
+    }
+}
+
+impl PocketBase {
+    /// Registers a subscriber for the given topic, connecting to the realtime API if needed.
+    pub(crate) async fn subscribe_topic<T>(
+        &self,
+        topic: String,
+    ) -> Result<Subscription<T>, RequestError> {
+        let (sender, receiver) = unbounded_channel();
+        let subscriber_id = self
+            .realtime
+            .next_subscriber_id
+            .fetch_add(1, Ordering::Relaxed);
+
+        let mut connection = self.realtime.connection.lock().await;
+
+        if connection.is_none() {
+            let (client_id, events) = self.connect_realtime().await?;
+
+            *connection = Some(Connection {
+                client_id: Some(client_id),
+                listener: tokio::spawn(listen(self.clone(), events)).abort_handle(),
+            });
+        }
+
+        self.realtime
+            .subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(topic.clone())
+            .or_default()
+            .push(Subscriber {
+                id: subscriber_id,
+                sender,
+            });
+
+        let subscription = Subscription {
+            client: self.clone(),
+            topic,
+            subscriber_id,
+            receiver,
+            _marker: std::marker::PhantomData,
+        };
+
+        // While reconnecting, the subscriptions are submitted once connected
+        if let Some(client_id) = connection.as_ref().and_then(|c| c.client_id.as_deref()) {
+            self.submit_subscriptions(client_id).await?;
+        }
+
+        drop(connection);
+
+        Ok(subscription)
+    }
+
+    /// Returns the id of the realtime connection, `None` when disconnected or reconnecting.
+    pub(crate) async fn realtime_client_id(&self) -> Option<String> {
+        self.realtime
+            .connection
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|connection| connection.client_id.clone())
+    }
+
+    /// Opens the realtime event stream and waits for the id of the connection.
+    async fn connect_realtime(&self) -> Result<(String, EventStream), RequestError> {
+        let url = format!("{}/api/realtime", self.base_url);
+
+        let request = self
+            .reqwest_client
+            .get(&url)
+            .header("Accept", "text/event-stream")
+            .timeout(CONNECTION_TIMEOUT);
+
+        let response = match self.send_request(request).await {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => return Err(RequestError::from_response(response).await),
+            Err(_) => return Err(RequestError::Unreachable),
+        };
+
+        let mut events = EventStream::new(response);
+
+        while let Some(event) = events.next_event().await {
+            if event.name == "PB_CONNECT" {
+                let connect_event = serde_json::from_str::<ConnectEvent>(&event.data)
+                    .map_err(|error| RequestError::ParseError(error.to_string()))?;
+
+                return Ok((connect_event.client_id, events));
+            }
+        }
+
+        Err(RequestError::Unreachable)
+    }
+
+    /// Sends the list of the active subscriptions of the realtime connection.
+    async fn submit_subscriptions(&self, client_id: &str) -> Result<(), RequestError> {
+        let url = format!("{}/api/realtime", self.base_url);
+
+        let body = SubscriptionsRequest {
+            client_id,
+            subscriptions: self
+                .realtime
+                .subscribers
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .keys()
+                .cloned()
+                .collect(),
+        };
+
+        let request = self.with_authorization_token(self.reqwest_client.post(&url).json(&body));
+
+        match self.send_request(request).await {
+            Ok(response) if response.status().is_success() => Ok(()),
+            Ok(response) => Err(RequestError::from_response(response).await),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Removes a subscriber, and updates the subscriptions of the connection if it was the
+    /// last one of its topic.
+    fn remove_subscriber(&self, topic: &str, subscriber_id: u64) {
+        let topic_removed = {
+            let mut subscribers = self
+                .realtime
+                .subscribers
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            let Some(topic_subscribers) = subscribers.get_mut(topic) else {
+                return;
+            };
+
+            topic_subscribers.retain(|subscriber| subscriber.id != subscriber_id);
+
+            topic_subscribers.is_empty() && subscribers.remove(topic).is_some()
+        };
+
+        // The subscriptions can't be updated without a runtime, e.g. while shutting down
+        if let (true, Ok(runtime)) = (topic_removed, tokio::runtime::Handle::try_current()) {
+            let client = self.clone();
+
+            runtime.spawn(async move {
+                let _ = client.update_subscriptions().await;
+            });
+        }
+    }
+
+    /// Removes the topics matching the predicate, and updates the subscriptions of the connection.
+    async fn remove_topics(&self, predicate: impl Fn(&str) -> bool) -> Result<(), RequestError> {
+        let topic_removed = {
+            let mut subscribers = self
+                .realtime
+                .subscribers
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            let count = subscribers.len();
+            subscribers.retain(|topic, _| !predicate(topic));
+
+            subscribers.len() != count
+        };
+
+        if topic_removed {
+            self.update_subscriptions().await
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sends the active subscriptions, or closes the connection if there are none left.
+    async fn update_subscriptions(&self) -> Result<(), RequestError> {
+        let mut connection = self.realtime.connection.lock().await;
+
+        if !self.has_subscribers() {
+            if let Some(connection) = connection.take() {
+                connection.listener.abort();
+            }
+
+            return Ok(());
+        }
+
+        if let Some(client_id) = connection.as_ref().and_then(|c| c.client_id.as_deref()) {
+            self.submit_subscriptions(client_id).await?;
+        }
+
+        drop(connection);
+
+        Ok(())
+    }
+
+    fn has_subscribers(&self) -> bool {
+        !self
+            .realtime
+            .subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_empty()
+    }
+
+    /// Sends an event to the subscribers of its topic.
+    fn dispatch_event(&self, topic: &str, data: &str) {
+        let mut subscribers = self
+            .realtime
+            .subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(topic_subscribers) = subscribers.get_mut(topic) {
+            topic_subscribers.retain(|subscriber| subscriber.sender.send(data.to_owned()).is_ok());
+        }
+    }
+}
+
+/// Dispatches the events of the realtime connection, reconnecting when it drops, until
+/// there are no subscribers left.
+async fn listen(client: PocketBase, mut events: EventStream) {
+    loop {
+        while let Some(event) = events.next_event().await {
+            client.dispatch_event(&event.name, &event.data);
+        }
+
+        let mut delay = Duration::from_millis(200);
+
+        events = loop {
+            let mut connection = client.realtime.connection.lock().await;
+
+            if !client.has_subscribers() {
+                *connection = None;
+                return;
+            }
+
+            let Some(state) = connection.as_mut() else {
+                return;
+            };
+
+            state.client_id = None;
+
+            if let Ok((client_id, events)) = client.connect_realtime().await
+                && client.submit_subscriptions(&client_id).await.is_ok()
+            {
+                state.client_id = Some(client_id);
+                break events;
+            }
+
+            drop(connection);
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                delay_ms = delay.as_millis(),
+                "reconnecting to the PocketBase realtime API"
+            );
+
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+        };
+    }
+}
+
+impl<T: DeserializeOwned> Subscription<T> {
+    /// Returns the topic of the subscription (e.g. `articles/*`).
+    #[must_use]
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Waits for the next event of the subscription.
+    ///
+    /// Returns an error if the event couldn't be deserialized, and `None` once the
+    /// subscription was cancelled.
+    pub async fn recv(&mut self) -> Option<Result<RecordEvent<T>, RequestError>> {
+        self.receiver.recv().await.map(|data| parse_event(&data))
+    }
+}
+
+impl<T> Subscription<T> {
+    /// Waits for the raw data of the next event, for topics not sending record events.
+    pub(crate) async fn recv_raw(&mut self) -> Option<String> {
+        self.receiver.recv().await
+    }
+}
+
+impl<T: DeserializeOwned> Stream for Subscription<T> {
+    type Item = Result<RecordEvent<T>, RequestError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver
+            .poll_recv(cx)
+            .map(|data| data.map(|data| parse_event(&data)))
+    }
+}
+
+impl<T> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        self.client
+            .remove_subscriber(&self.topic, self.subscriber_id);
+    }
+}
+
+fn parse_event<T: DeserializeOwned>(data: &str) -> Result<RecordEvent<T>, RequestError> {
+    serde_json::from_str::<RecordEvent<T>>(data)
+        .map_err(|error| RequestError::ParseError(error.to_string()))
+}
@@ -0,0 +1,80 @@
+use std::pin::Pin;
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+
+/// A message received from a server-sent events stream.
+pub struct Event {
+    /// The name of the event (`message` if not given).
+    pub name: String,
+    /// The data of the event, with multiple `data` lines joined by a new line.
+    pub data: String,
+}
+
+/// Reads the events of a `text/event-stream` response body.
+pub struct EventStream {
+    body: Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>> + Send>>,
+    buffer: Vec<u8>,
+}
+
+impl EventStream {
+    pub fn new(response: reqwest::Response) -> Self {
+        Self {
+            body: Box::pin(response.bytes_stream()),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Returns the next event, or `None` once the stream is closed or failed.
+    pub async fn next_event(&mut self) -> Option<Event> {
+        loop {
+            if let Some(event) = self.parse_event() {
+                return Some(event);
+            }
+
+            let chunk = self.body.next().await?.ok()?;
+
+            // `PocketBase` ends the lines with `\n`, carriage returns are dropped for simplicity
+            self.buffer
+                .extend(chunk.iter().copied().filter(|byte| *byte != b'\r'));
+        }
+    }
+
+    /// Extracts the first complete event from the buffer, skipping the ones without data.
+    fn parse_event(&mut self) -> Option<Event> {
+        while let Some(end) = self.buffer.windows(2).position(|window| window == b"\n\n") {
+            let block: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            let block = String::from_utf8_lossy(&block);
+
+            let mut name = None;
+            let mut data: Option<String> = None;
+
+            for line in block.lines() {
+                let (field, value) = line.split_once(':').unwrap_or((line, ""));
+                let value = value.strip_prefix(' ').unwrap_or(value);
+
+                match field {
+                    "event" => name = Some(value.to_owned()),
+                    "data" => match &mut data {
+                        Some(data) => {
+                            data.push('\n');
+                            data.push_str(value);
+                        }
+                        None => data = Some(value.to_owned()),
+                    },
+                    // Comments, `id` and `retry` fields are not used
+                    _ => {}
+                }
+            }
+
+            if let Some(data) = data {
+                return Some(Event {
+                    name: name.unwrap_or_else(|| "message".to_owned()),
+                    data,
+                });
+            }
+        }
+
+        None
+    }
+}
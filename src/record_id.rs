@@ -0,0 +1,82 @@
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::error::RecordIdError;
+
+/// The characters of the record IDs generated by `PocketBase`.
+const ALPHABET: &[u8; 36] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// The length of the record IDs generated by `PocketBase`.
+const LENGTH: usize = 15;
+
+/// The maximum length of a record ID.
+const MAX_LENGTH: usize = 255;
+
+/// Generates a random record ID, in the format of the ones generated by `PocketBase`:
+/// 15 lowercase alphanumeric characters.
+///
+/// Sending the ID along with a new record makes its creation safe to retry, which
+/// `.idempotent()` does when creating a record with [`Collection::create()`](crate::Collection::create).
+///
+/// # Example
+/// ```rust,ignore
+/// let id = pocketbase_rs::generate_record_id();
+///
+/// pb.collection("articles")
+///     .create::<Value>(json!({ "id": id, "title": "Hello" }))
+///     .call()
+///     .await?;
+/// ```
+#[must_use]
+pub fn generate_record_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    // `RandomState` is randomly seeded, the counter keeps the IDs unique within the process
+    let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let mut bits = u128::from(RandomState::new().hash_one(counter)) << 64
+        | u128::from(RandomState::new().hash_one(counter));
+
+    // 36^15 is below 2^78, so the 128 random bits are enough for all the characters
+    (0..LENGTH)
+        .map(|_| {
+            let index = (bits % ALPHABET.len() as u128) as usize;
+            bits /= ALPHABET.len() as u128;
+            char::from(ALPHABET[index])
+        })
+        .collect()
+}
+
+/// Checks that the given record ID can be valid, without sending any request.
+///
+/// The IDs generated by `PocketBase` are 15 lowercase alphanumeric characters, but the `id`
+/// field of a collection can be configured to accept other formats. So only the IDs that
+/// can't be valid with any configuration are rejected: empty IDs, IDs longer than 255
+/// characters, and IDs with characters other than alphanumeric characters, `_` and `-`.
+///
+/// `get_one()`, `update()` and `delete()` run this check before sending their request.
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::validate_record_id;
+///
+/// // e.g. from a route parameter
+/// validate_record_id(&article_id)?;
+/// ```
+pub fn validate_record_id(id: &str) -> Result<(), RecordIdError> {
+    if id.is_empty() {
+        return Err(RecordIdError::Empty);
+    }
+
+    if id.len() > MAX_LENGTH {
+        return Err(RecordIdError::TooLong(id.len()));
+    }
+
+    if !id
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(RecordIdError::InvalidCharacters(id.to_owned()));
+    }
+
+    Ok(())
+}
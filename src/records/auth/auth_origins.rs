@@ -0,0 +1,57 @@
+use crate::error::RequestError;
+use crate::filter::escape_filter_value;
+use crate::records::crud::delete::DeleteError;
+use crate::records::system::AUTH_ORIGINS_COLLECTION;
+use crate::{AuthOrigin, Collection};
+
+impl Collection<'_> {
+    /// Lists the devices (auth origins) the given auth record logged in from, most recent first.
+    ///
+    /// Auth records can list their own origins, superusers can list the origins of any record.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let sessions = pb
+    ///     .collection("users")
+    ///     .list_auth_origins("RECORD_ID")
+    ///     .await?;
+    ///
+    /// for session in sessions {
+    ///     println!("{}: last login on {}", session.id, session.updated);
+    /// }
+    /// ```
+    pub async fn list_auth_origins(self, record_id: &str) -> Result<Vec<AuthOrigin>, RequestError> {
+        let filter = format!("recordRef='{}'", escape_filter_value(record_id));
+
+        Collection {
+            client: self.client,
+            name: AUTH_ORIGINS_COLLECTION,
+        }
+        .get_full_list::<AuthOrigin>()
+        .filter(&filter)
+        .sort("-updated")
+        .call()
+        .await
+    }
+
+    /// Deletes an auth origin, e.g. to let a user revoke a device.
+    ///
+    /// The next login from this device will be reported as a new one (and trigger the
+    /// "new login" alert email, if enabled). Existing auth tokens are not invalidated.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.collection("users")
+    ///     .delete_auth_origin("AUTH_ORIGIN_ID")
+    ///     .await?;
+    /// ```
+    pub async fn delete_auth_origin(self, auth_origin_id: &str) -> Result<(), DeleteError> {
+        Collection {
+            client: self.client,
+            name: AUTH_ORIGINS_COLLECTION,
+        }
+        .delete(auth_origin_id)
+        .call()
+        .await
+    }
+}
@@ -1,7 +1,25 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use serde::de::DeserializeOwned;
+use tokio_util::sync::CancellationToken;
+
+use super::parse_auth_response;
 use crate::error::RequestError;
-use crate::{AuthStore, Collection};
+use crate::options::{RequestOptions, cancellable};
+use crate::{AuthStore, AuthStoreRecord, Collection, PocketBase};
+
+/// Builder for refreshing the auth token of the authenticated record.
+///
+/// The builder can be awaited directly, or executed through [`CollectionAuthRefreshBuilder::call()`].
+pub struct CollectionAuthRefreshBuilder<'a, R = AuthStoreRecord> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    options: RequestOptions<'a>,
+    _marker: std::marker::PhantomData<fn() -> R>,
+}
 
-impl Collection<'_> {
+impl<'a> Collection<'a> {
     /// Returns a new auth response (token and record data) for an **already authenticated record**.
     ///
     /// This method is usually called by users on page/screen reload to ensure that the previously stored data in `pb.auth_store()` is still valid and up-to-date.
@@ -14,23 +32,111 @@ impl Collection<'_> {
     ///
     /// println!("New token: {}", auth_data.token);
     /// ```
-    pub async fn auth_refresh(&mut self) -> Result<AuthStore, RequestError> {
+    #[must_use]
+    pub const fn auth_refresh(self) -> CollectionAuthRefreshBuilder<'a> {
+        CollectionAuthRefreshBuilder {
+            client: self.client,
+            collection_name: self.name,
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, R: DeserializeOwned> CollectionAuthRefreshBuilder<'a, R> {
+    /// Deserialize the auth record into the given type, instead of an [`AuthStoreRecord`].
+    ///
+    /// The client still saves the token, along with the [`AuthStoreRecord`] fields of the record.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let auth_data = pb
+    ///     .collection("users")
+    ///     .auth_refresh()
+    ///     .record_type::<User>()
+    ///     .await?;
+    /// ```
+    pub fn record_type<T: DeserializeOwned>(self) -> CollectionAuthRefreshBuilder<'a, T> {
+        CollectionAuthRefreshBuilder {
+            client: self.client,
+            collection_name: self.collection_name,
+            options: self.options,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the auth data.
+    pub async fn call(self) -> Result<AuthStore<R>, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<AuthStore<R>, RequestError> {
         let url = format!(
             "{}/api/collections/{}/auth-refresh",
-            self.client.base_url(),
-            self.name
+            self.client.base_url, self.collection_name
         );
 
-        let request = self.client.request_post(&url).send().await;
+        let request = self
+            .client
+            .send_request(self.options.apply(self.client.request_post(&url)))
+            .await;
 
         match request {
             Ok(response) => match response.status() {
                 reqwest::StatusCode::OK => {
-                    let Ok(auth_store) = response.json::<AuthStore>().await else {
-                        return Err(RequestError::Unhandled);
-                    };
+                    let body = response.bytes().await?;
+
+                    let (auth_store, saved_auth_store) = parse_auth_response::<R>(&body)
+                        .map_err(|error| RequestError::ParseError(error.to_string()))?;
 
-                    self.client.update_auth_store(auth_store.clone());
+                    self.client.update_auth_store(saved_auth_store);
 
                     Ok(auth_store)
                 }
@@ -38,10 +144,20 @@ impl Collection<'_> {
                 reqwest::StatusCode::UNAUTHORIZED => Err(RequestError::Unauthorized),
                 reqwest::StatusCode::FORBIDDEN => Err(RequestError::Forbidden),
                 reqwest::StatusCode::NOT_FOUND => Err(RequestError::NotFound),
+                reqwest::StatusCode::TOO_MANY_REQUESTS => Err(RequestError::TooManyRequests),
 
-                _ => Err(RequestError::Unhandled),
+                _ => Err(RequestError::from_response(response).await),
             },
-            Err(_) => Err(RequestError::Unhandled),
+            Err(error) => Err(error.into()),
         }
     }
 }
+
+impl<'a, R: DeserializeOwned + Send + 'a> IntoFuture for CollectionAuthRefreshBuilder<'a, R> {
+    type Output = Result<AuthStore<R>, RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
@@ -1,5 +1,25 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use serde::de::DeserializeOwned;
+use tokio_util::sync::CancellationToken;
+
 use crate::error::RequestError;
-use crate::{AuthStore, Collection};
+use crate::options::{RequestOptions, cancellable};
+use crate::{AuthStore, AuthStoreRecord, Collection, PocketBase};
+
+/// Builder for refreshing the authentication token of a specific user.
+///
+/// The builder can be awaited directly, or executed through [`CollectionAuthRefreshForUserBuilder::call()`].
+pub struct CollectionAuthRefreshForUserBuilder<'a, R = AuthStoreRecord> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    user_token: &'a str,
+    expand: Option<&'a str>,
+    fields: Option<&'a str>,
+    options: RequestOptions<'a>,
+    _marker: std::marker::PhantomData<fn() -> R>,
+}
 
 impl<'a> Collection<'a> {
     /// Refresh the authentication token for a specific user.
@@ -15,16 +35,146 @@ impl<'a> Collection<'a> {
     ///
     /// println!("New token: {}", auth_data.token);
     /// ```
-    pub async fn auth_refresh_for_user(
-        &mut self,
+    #[must_use]
+    pub const fn auth_refresh_for_user(
+        self,
         user_token: &'a str,
-    ) -> Result<AuthStore, RequestError> {
+    ) -> CollectionAuthRefreshForUserBuilder<'a> {
+        CollectionAuthRefreshForUserBuilder {
+            client: self.client,
+            collection_name: self.name,
+            user_token,
+            expand: None,
+            fields: None,
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, R: DeserializeOwned> CollectionAuthRefreshForUserBuilder<'a, R> {
+    /// Auto expand relations of the auth record (up to 6-levels deep).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .expand("organization")
+    /// ```
+    pub const fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Comma separated string of the fields to return in the response.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .fields("token,record.id,record.name")
+    /// ```
+    pub const fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Deserialize the auth record into the given type, instead of an [`AuthStoreRecord`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct User {
+    ///     id: String,
+    ///     name: String,
+    ///     organization: String,
+    /// }
+    ///
+    /// let auth_data = pb
+    ///     .collection("users")
+    ///     .auth_refresh_for_user("USER_TOKEN")
+    ///     .record_type::<User>()
+    ///     .call()
+    ///     .await?;
+    ///
+    /// println!("Welcome back, {}", auth_data.record.name);
+    /// ```
+    pub fn record_type<T: DeserializeOwned>(self) -> CollectionAuthRefreshForUserBuilder<'a, T> {
+        CollectionAuthRefreshForUserBuilder {
+            client: self.client,
+            collection_name: self.collection_name,
+            user_token: self.user_token,
+            expand: self.expand,
+            fields: self.fields,
+            options: self.options,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the auth data.
+    pub async fn call(self) -> Result<AuthStore<R>, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<AuthStore<R>, RequestError> {
         let url = format!(
             "{}/api/collections/{}/auth-refresh",
-            self.client.base_url(),
-            self.name
+            self.client.base_url, self.collection_name
         );
 
+        let mut query_parameters: Vec<(&str, &str)> = vec![];
+
+        if let Some(expand) = self.expand {
+            query_parameters.push(("expand", expand));
+        }
+
+        if let Some(fields) = self.fields {
+            query_parameters.push(("fields", fields));
+        }
+
         // Usually we would do `let request = self.client.request_post(&url).bearer_auth(user_token).send().await;`,
         // but in our wrapper methods around `Reqwest`, we already use the `.bearer_auth()` method on our
         // `RequestBuilder` with the token of the currently logged in user.
@@ -32,29 +182,43 @@ impl<'a> Collection<'a> {
         // the user to re-authenticate, it seems to be ignored. We could probably rewrite our wrapper methods, but honestly, I'm too lazy.
         let request = self
             .client
-            .reqwest_client
-            .post(&url)
-            .bearer_auth(user_token)
-            .send()
+            .send_request(
+                self.options.apply(
+                    self.client
+                        .reqwest_client
+                        .post(&url)
+                        .query(&query_parameters)
+                        .bearer_auth(self.user_token),
+                ),
+            )
             .await;
 
         match request {
             Ok(response) => match response.status() {
-                reqwest::StatusCode::OK => {
-                    let Ok(auth_store) = response.json::<AuthStore>().await else {
-                        return Err(RequestError::Unhandled);
-                    };
-
-                    Ok(auth_store)
-                }
+                reqwest::StatusCode::OK => response
+                    .json::<AuthStore<R>>()
+                    .await
+                    .map_err(|error| RequestError::ParseError(error.to_string())),
 
                 reqwest::StatusCode::UNAUTHORIZED => Err(RequestError::Unauthorized),
                 reqwest::StatusCode::FORBIDDEN => Err(RequestError::Forbidden),
                 reqwest::StatusCode::NOT_FOUND => Err(RequestError::NotFound),
+                reqwest::StatusCode::TOO_MANY_REQUESTS => Err(RequestError::TooManyRequests),
 
-                _ => Err(RequestError::Unhandled),
+                _ => Err(RequestError::from_response(response).await),
             },
-            Err(_) => Err(RequestError::Unhandled),
+            Err(error) => Err(error.into()),
         }
     }
 }
+
+impl<'a, R: DeserializeOwned + Send + 'a> IntoFuture
+    for CollectionAuthRefreshForUserBuilder<'a, R>
+{
+    type Output = Result<AuthStore<R>, RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
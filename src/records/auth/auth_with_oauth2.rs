@@ -0,0 +1,191 @@
+use serde::Serialize;
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+
+use super::auth_with_password::mfa_processing;
+use crate::error::RawResponse;
+use crate::options::{RequestOptions, cancellable};
+use crate::{AuthStore, AuthenticationError, Collection, PocketBase, circuit_breaker};
+
+#[derive(Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OAuth2Credentials<'a> {
+    provider: &'a str,
+    code: &'a str,
+    code_verifier: &'a str,
+    #[serde(rename = "redirectURL")]
+    redirect_url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    create_data: Option<&'a Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mfa_id: Option<&'a str>,
+}
+
+/// Builder for authenticating with an `OAuth2` authorization code.
+pub struct CollectionAuthWithOAuth2CodeBuilder<'a> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    provider: &'a str,
+    code: &'a str,
+    code_verifier: &'a str,
+    redirect_url: &'a str,
+    create_data: Option<&'a Value>,
+    mfa_id: Option<&'a str>,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Collection<'a> {
+    /// Authenticate with an `OAuth2` authorization code.
+    ///
+    /// This is the manual flow: `code` and `code_verifier` are obtained after redirecting the
+    /// user to the provider's authorization page, and `redirect_url` must be the same one used
+    /// for the initial authorization request.
+    ///
+    /// On success, the auth token is automatically stored and used for subsequent requests.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let auth_data = pb.collection("users")
+    ///     .auth_with_oauth2_code("google", "CODE", "CODE_VERIFIER", "https://example.com/redirect")
+    ///     .call()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn auth_with_oauth2_code(
+        self,
+        provider: &'a str,
+        code: &'a str,
+        code_verifier: &'a str,
+        redirect_url: &'a str,
+    ) -> CollectionAuthWithOAuth2CodeBuilder<'a> {
+        CollectionAuthWithOAuth2CodeBuilder {
+            client: self.client,
+            collection_name: self.name,
+            provider,
+            code,
+            code_verifier,
+            redirect_url,
+            create_data: None,
+            mfa_id: None,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> CollectionAuthWithOAuth2CodeBuilder<'a> {
+    /// Optional data used when creating a new auth record on `OAuth2` sign-up.
+    pub const fn create_data(mut self, create_data: &'a Value) -> Self {
+        self.create_data = Some(create_data);
+        self
+    }
+
+    /// Complete a pending Multi-Factor Authentication session.
+    ///
+    /// `mfa_id` is the one returned by [`AuthenticationError::MfaRequired`] after a
+    /// first successful authentication with another method.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .mfa_id("MFA_ID")
+    /// ```
+    pub const fn mfa_id(mut self, mfa_id: &'a str) -> Self {
+        self.mfa_id = Some(mfa_id);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the auth data.
+    pub async fn call(self) -> Result<AuthStore, AuthenticationError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            AuthenticationError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<AuthStore, AuthenticationError> {
+        let uri = format!(
+            "{}/api/collections/{}/auth-with-oauth2",
+            self.client.base_url, self.collection_name
+        );
+
+        let credentials = OAuth2Credentials {
+            provider: self.provider,
+            code: self.code,
+            code_verifier: self.code_verifier,
+            redirect_url: self.redirect_url,
+            create_data: self.create_data,
+            mfa_id: self.mfa_id,
+        };
+
+        let response = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_post_json(&uri, &credentials)),
+            )
+            .await?;
+
+        match response.status() {
+            _ if circuit_breaker::is_open_response(&response) => {
+                Err(AuthenticationError::CircuitOpen)
+            }
+            reqwest::StatusCode::OK => {
+                let auth_store = response.json::<AuthStore>().await?;
+
+                self.client.update_auth_store(auth_store.clone());
+
+                Ok(auth_store)
+            }
+
+            reqwest::StatusCode::BAD_REQUEST => Err(AuthenticationError::InvalidCredentials),
+            reqwest::StatusCode::UNAUTHORIZED => Err(mfa_processing(response).await),
+
+            _ => Err(AuthenticationError::UnexpectedResponse(
+                RawResponse::from_response(response).await,
+            )),
+        }
+    }
+}
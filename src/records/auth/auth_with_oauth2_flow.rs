@@ -0,0 +1,318 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::Value;
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+
+use crate::error::RequestError;
+use crate::options::cancellable;
+use crate::{AuthStore, AuthenticationError, Collection, PocketBase};
+
+/// The realtime topic `PocketBase` sends the `OAuth2` redirect data to.
+const OAUTH2_TOPIC: &str = "@oauth2";
+
+/// Represents the various errors that can be obtained during the all-in-one `OAuth2` flow.
+#[derive(Error, Debug)]
+pub enum OAuth2FlowError {
+    /// The provider is not enabled for the collection.
+    #[error("The OAuth2 provider \"{0}\" is not enabled for this collection.")]
+    ProviderNotFound(String),
+    /// Fetching the auth methods or the realtime connection failed.
+    #[error("The OAuth2 flow failed: {0}")]
+    Request(#[from] RequestError),
+    /// The provider redirected with an error, e.g. when the user denied the access.
+    #[error("The OAuth2 provider returned an error: {0}")]
+    Provider(String),
+    /// The redirect data was not meant for this flow.
+    #[error("The OAuth2 redirect state doesn't match the realtime connection.")]
+    StateMismatch,
+    /// The user didn't complete the authorization in time.
+    #[error("The OAuth2 authorization was not completed in time.")]
+    TimedOut,
+    /// The flow was cancelled through its cancellation token.
+    #[error("The OAuth2 flow was cancelled.")]
+    Cancelled,
+    /// The authorization code was received, but the authentication failed.
+    #[error(transparent)]
+    Authentication(#[from] AuthenticationError),
+}
+
+#[derive(Deserialize)]
+struct AuthMethods {
+    oauth2: OAuth2Methods,
+}
+
+#[derive(Deserialize)]
+struct OAuth2Methods {
+    #[serde(default)]
+    providers: Vec<OAuth2Provider>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OAuth2Provider {
+    name: String,
+    #[serde(rename = "authURL")]
+    auth_url: String,
+    code_verifier: String,
+}
+
+#[derive(Deserialize)]
+struct OAuth2Redirect {
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    error: String,
+}
+
+/// Builder for the all-in-one `OAuth2` authentication flow.
+///
+/// The builder can be awaited directly, or executed through [`CollectionAuthWithOAuth2Builder::call()`].
+pub struct CollectionAuthWithOAuth2Builder<'a> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    provider: &'a str,
+    url_callback: Box<dyn FnOnce(&str) + Send + 'a>,
+    scopes: Vec<&'a str>,
+    create_data: Option<&'a Value>,
+    timeout: Option<Duration>,
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl<'a> Collection<'a> {
+    /// Authenticate with an `OAuth2` provider, handling the whole authorization flow.
+    ///
+    /// Like the JS SDK's `authWithOAuth2()`, the provider redirects to the
+    /// `/api/oauth2-redirect` route of `PocketBase`, which forwards the authorization code
+    /// through the realtime API. The URL to open (e.g. in the user's browser) is given to
+    /// `url_callback`, and the returned future completes once the user authorized the app.
+    ///
+    /// The redirect URL to register on the provider's side is
+    /// `https://your-pocketbase-domain/api/oauth2-redirect`.
+    ///
+    /// On success, the auth token is automatically stored and used for subsequent requests.
+    ///
+    /// Requires the `oauth2-flow` feature.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let auth_data = pb
+    ///     .collection("users")
+    ///     .auth_with_oauth2("google", |url| {
+    ///         println!("Open this URL to sign in: {url}");
+    ///     })
+    ///     .timeout(Duration::from_mins(5))
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn auth_with_oauth2(
+        self,
+        provider: &'a str,
+        url_callback: impl FnOnce(&str) + Send + 'a,
+    ) -> CollectionAuthWithOAuth2Builder<'a> {
+        CollectionAuthWithOAuth2Builder {
+            client: self.client,
+            collection_name: self.name,
+            provider,
+            url_callback: Box::new(url_callback),
+            scopes: Vec::new(),
+            create_data: None,
+            timeout: None,
+            cancellation_token: None,
+        }
+    }
+}
+
+impl<'a> CollectionAuthWithOAuth2Builder<'a> {
+    /// Request additional scopes, on top of the ones configured for the provider.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .scopes(&["https://www.googleapis.com/auth/calendar.readonly"])
+    /// ```
+    #[must_use]
+    pub fn scopes(mut self, scopes: &[&'a str]) -> Self {
+        self.scopes.extend_from_slice(scopes);
+        self
+    }
+
+    /// Data used to create the auth record, if the user signs in for the first time.
+    #[must_use]
+    pub const fn create_data(mut self, create_data: &'a Value) -> Self {
+        self.create_data = Some(create_data);
+        self
+    }
+
+    /// Give up if the user didn't complete the authorization within the given duration
+    /// (default: wait indefinitely).
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Cancel the flow when the given token is cancelled, making it return a `Cancelled` error,
+    /// e.g. when the user closes the sign-in dialog.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    #[must_use]
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Run the flow and return the auth store of the authenticated record.
+    pub async fn call(self) -> Result<AuthStore, OAuth2FlowError> {
+        let cancellation_token = self.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            OAuth2FlowError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<AuthStore, OAuth2FlowError> {
+        let provider = fetch_provider(self.client, self.collection_name, self.provider).await?;
+
+        let mut subscription = self
+            .client
+            .subscribe_topic::<()>(OAUTH2_TOPIC.to_owned())
+            .await?;
+
+        let client_id = self
+            .client
+            .realtime_client_id()
+            .await
+            .ok_or(RequestError::Unreachable)?;
+
+        let redirect_url = format!("{}/api/oauth2-redirect", self.client.base_url);
+
+        // The auth URL of `PocketBase` ends with an empty `redirect_uri` parameter
+        let mut url = reqwest::Url::parse(&format!("{}{redirect_url}", provider.auth_url))
+            .map_err(|error| RequestError::ParseError(error.to_string()))?;
+
+        let query: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(key, value)| {
+                let value = match key.as_ref() {
+                    "state" => client_id.clone(),
+                    "scope" if !self.scopes.is_empty() => {
+                        format!("{value} {}", self.scopes.join(" "))
+                    }
+                    _ => value.into_owned(),
+                };
+
+                (key.into_owned(), value)
+            })
+            .collect();
+
+        url.query_pairs_mut().clear().extend_pairs(query);
+
+        (self.url_callback)(url.as_str());
+
+        let data = match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, subscription.recv_raw())
+                .await
+                .map_err(|_| OAuth2FlowError::TimedOut)?,
+            None => subscription.recv_raw().await,
+        };
+
+        drop(subscription);
+
+        let redirect = data.ok_or(RequestError::Unreachable).and_then(|data| {
+            serde_json::from_str::<OAuth2Redirect>(&data)
+                .map_err(|error| RequestError::ParseError(error.to_string()))
+        })?;
+
+        if redirect.state != client_id {
+            return Err(OAuth2FlowError::StateMismatch);
+        }
+
+        if !redirect.error.is_empty() || redirect.code.is_empty() {
+            return Err(OAuth2FlowError::Provider(redirect.error));
+        }
+
+        let collection = Collection {
+            client: self.client,
+            name: self.collection_name,
+        };
+
+        let mut builder = collection.auth_with_oauth2_code(
+            &provider.name,
+            &redirect.code,
+            &provider.code_verifier,
+            &redirect_url,
+        );
+
+        if let Some(create_data) = self.create_data {
+            builder = builder.create_data(create_data);
+        }
+
+        Ok(builder.call().await?)
+    }
+}
+
+/// Fetches the authorization data of the provider.
+async fn fetch_provider(
+    client: &PocketBase,
+    collection_name: &str,
+    provider: &str,
+) -> Result<OAuth2Provider, OAuth2FlowError> {
+    let url = format!(
+        "{}/api/collections/{}/auth-methods",
+        client.base_url, collection_name
+    );
+
+    let response = match client.send_request(client.request_get(&url, None)).await {
+        Ok(response) if response.status().is_success() => response,
+        Ok(response) => return Err(RequestError::from_response(response).await.into()),
+        Err(_) => return Err(RequestError::Unreachable.into()),
+    };
+
+    let auth_methods = response
+        .json::<AuthMethods>()
+        .await
+        .map_err(|error| RequestError::ParseError(error.to_string()))?;
+
+    auth_methods
+        .oauth2
+        .providers
+        .into_iter()
+        .find(|candidate| candidate.name == provider)
+        .ok_or_else(|| OAuth2FlowError::ProviderNotFound(provider.to_owned()))
+}
+
+impl<'a> IntoFuture for CollectionAuthWithOAuth2Builder<'a> {
+    type Output = Result<AuthStore, OAuth2FlowError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OAuth2FlowError;
+    use crate::PocketBaseError;
+
+    #[test]
+    fn timeouts_are_not_cancellations() {
+        assert!(matches!(
+            PocketBaseError::from(OAuth2FlowError::TimedOut),
+            PocketBaseError::TimedOut
+        ));
+        assert!(matches!(
+            PocketBaseError::from(OAuth2FlowError::StateMismatch),
+            PocketBaseError::OAuth2StateMismatch
+        ));
+    }
+}
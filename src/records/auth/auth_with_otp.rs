@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+
+use super::auth_with_password::mfa_processing;
+use crate::error::{ApiErrorResponse, RawResponse, RequestError};
+use crate::options::{RequestOptions, cancellable};
+use crate::{AuthStore, AuthenticationError, Collection, PocketBase, circuit_breaker};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OtpResponse {
+    otp_id: String,
+}
+
+#[derive(Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OtpCredentials<'a> {
+    otp_id: &'a str,
+    password: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mfa_id: Option<&'a str>,
+}
+
+/// Builder for authenticating with a One-Time Password.
+pub struct CollectionAuthWithOtpBuilder<'a> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    otp_id: &'a str,
+    password: &'a str,
+    mfa_id: Option<&'a str>,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Collection<'a> {
+    /// Sends a One-Time Password to the auth record with the given email.
+    ///
+    /// Returns the `otpId` to use with [`Collection::auth_with_otp()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let otp_id = pb.collection("users")
+    ///     .request_otp("test@example.com")
+    ///     .await?;
+    /// ```
+    pub async fn request_otp(&self, email: &'a str) -> Result<String, RequestError> {
+        let url = format!(
+            "{}/api/collections/{}/request-otp",
+            self.client.base_url, self.name
+        );
+
+        let email: HashMap<String, String> = HashMap::from([("email".to_string(), email.into())]);
+
+        let request = self
+            .client
+            .send_request(self.client.request_post_json(&url, &email))
+            .await;
+
+        match request {
+            Ok(response) => match response.status() {
+                reqwest::StatusCode::OK => {
+                    let data = response.json::<OtpResponse>().await;
+
+                    match data {
+                        Ok(data) => Ok(data.otp_id),
+                        Err(error) => Err(RequestError::ParseError(error.to_string())),
+                    }
+                }
+                reqwest::StatusCode::BAD_REQUEST => Err(RequestError::BadRequest(
+                    ApiErrorResponse::from_response(response).await,
+                )),
+                reqwest::StatusCode::NOT_FOUND => Err(RequestError::NotFound),
+                reqwest::StatusCode::TOO_MANY_REQUESTS => Err(RequestError::TooManyRequests),
+                _ => Err(RequestError::from_response(response).await),
+            },
+            Err(_) => Err(RequestError::Unreachable),
+        }
+    }
+
+    /// Authenticate with a One-Time Password.
+    ///
+    /// `otp_id` is the one returned by [`Collection::request_otp()`] and `password`
+    /// is the code received by email.
+    ///
+    /// On success, the auth token is automatically stored and used for subsequent requests.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let auth_data = pb.collection("users")
+    ///     .auth_with_otp("OTP_ID", "123456")
+    ///     .call()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn auth_with_otp(
+        self,
+        otp_id: &'a str,
+        password: &'a str,
+    ) -> CollectionAuthWithOtpBuilder<'a> {
+        CollectionAuthWithOtpBuilder {
+            client: self.client,
+            collection_name: self.name,
+            otp_id,
+            password,
+            mfa_id: None,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> CollectionAuthWithOtpBuilder<'a> {
+    /// Complete a pending Multi-Factor Authentication session.
+    ///
+    /// `mfa_id` is the one returned by [`AuthenticationError::MfaRequired`] after a
+    /// first successful authentication with another method.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .mfa_id("MFA_ID")
+    /// ```
+    pub const fn mfa_id(mut self, mfa_id: &'a str) -> Self {
+        self.mfa_id = Some(mfa_id);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the auth data.
+    pub async fn call(self) -> Result<AuthStore, AuthenticationError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            AuthenticationError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<AuthStore, AuthenticationError> {
+        let uri = format!(
+            "{}/api/collections/{}/auth-with-otp",
+            self.client.base_url, self.collection_name
+        );
+
+        let credentials = OtpCredentials {
+            otp_id: self.otp_id,
+            password: self.password,
+            mfa_id: self.mfa_id,
+        };
+
+        let response = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_post_json(&uri, &credentials)),
+            )
+            .await?;
+
+        match response.status() {
+            _ if circuit_breaker::is_open_response(&response) => {
+                Err(AuthenticationError::CircuitOpen)
+            }
+            reqwest::StatusCode::OK => {
+                let auth_store = response.json::<AuthStore>().await?;
+
+                self.client.update_auth_store(auth_store.clone());
+
+                Ok(auth_store)
+            }
+
+            // {
+            //     "status": 400,
+            //     "message": "Failed to authenticate.",
+            //     "data": {}
+            // }
+            reqwest::StatusCode::BAD_REQUEST => Err(AuthenticationError::InvalidCredentials),
+            reqwest::StatusCode::UNAUTHORIZED => Err(mfa_processing(response).await),
+
+            _ => Err(AuthenticationError::UnexpectedResponse(
+                RawResponse::from_response(response).await,
+            )),
+        }
+    }
+}
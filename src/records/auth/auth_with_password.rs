@@ -1,13 +1,28 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
 use serde::Serialize;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use thiserror::Error;
+use tokio_util::sync::CancellationToken;
 
-use crate::{AuthStore, Collection, ErrorResponse};
+use super::parse_auth_response;
+use crate::error::RawResponse;
+use crate::options::{RequestOptions, cancellable};
+use crate::{
+    AuthStore, AuthStoreRecord, Collection, ErrorResponse, PocketBase, TransportError,
+    circuit_breaker,
+};
 
 #[derive(Clone, Default, Serialize)]
 struct Credentials<'a> {
     pub(crate) identity: &'a str,
     pub(crate) password: &'a str,
+    #[serde(rename = "identityField", skip_serializing_if = "Option::is_none")]
+    pub(crate) identity_field: Option<&'a str>,
+    #[serde(rename = "mfaId", skip_serializing_if = "Option::is_none")]
+    pub(crate) mfa_id: Option<&'a str>,
 }
 
 /// Represents errors that can occur during the authentication process with the `PocketBase` API.
@@ -45,35 +60,84 @@ pub enum AuthenticationError {
     IdentityMustBeEmail,
     /// An HTTP error occurred while communicating with the `PocketBase` API.
     ///
-    /// This variant wraps a [`reqwest::Error`] and indicates that the request could not be completed
+    /// This variant wraps a [`TransportError`] and indicates that the request could not be completed
     /// due to network issues, invalid URL, timeouts, etc.
     #[error("Authentication failed. Couldn't reach the PocketBase API: {0}")]
-    HttpError(reqwest::Error),
+    HttpError(TransportError),
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    ///
+    /// See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("Authentication failed. The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
+    /// The request was cancelled through its cancellation token.
+    #[error("Authentication cancelled: the request was cancelled.")]
+    Cancelled,
     /// When something unexpected was returned by the `PocketBase` REST API.
     ///
     /// Would usually mean that there is an error somewhere in this API wrapper.
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
     #[error(
-        "Authentication failed due to an unexpected response. Usually means a problem in the PocketBase API's wrapper."
+        "Authentication failed due to an unexpected response ({0}). Usually means a problem in the PocketBase API's wrapper."
     )]
-    UnexpectedResponse,
+    UnexpectedResponse(RawResponse),
     /// Occurs when you try to authenticate a `PocketBase` client without providing the collection name.
     #[error(
         "Authentication failed due to missing collection name. [Example: PocketBaseClientBuilder::new(\"\")"
     )]
     MissingCollection,
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [401 Unauthorized]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/401") HTTP error response
+    /// containing an `mfaId`.
+    ///
+    /// The collection has Multi-Factor Authentication enabled and this first authentication
+    /// step succeeded. Authenticate again with another method (e.g. [`Collection::auth_with_otp()`])
+    /// passing the given `mfa_id` to complete the login.
+    #[error(
+        "Authentication requires another factor. Complete the MFA challenge using the given mfaId."
+    )]
+    MfaRequired {
+        /// The id of the pending MFA session.
+        mfa_id: String,
+    },
+}
+
+impl From<TransportError> for AuthenticationError {
+    fn from(error: TransportError) -> Self {
+        Self::HttpError(error)
+    }
 }
 
 impl From<reqwest::Error> for AuthenticationError {
     fn from(error: reqwest::Error) -> Self {
-        Self::HttpError(error)
+        Self::HttpError(error.into())
     }
 }
 
-impl Collection<'_> {
+/// Builder for authenticating with a combination of identity and password.
+///
+/// The builder can be awaited directly, or executed through [`CollectionAuthWithPasswordBuilder::call()`].
+pub struct CollectionAuthWithPasswordBuilder<'a, R = AuthStoreRecord> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    identity: &'a str,
+    password: &'a str,
+    identity_field: Option<&'a str>,
+    expand: Option<&'a str>,
+    fields: Option<&'a str>,
+    mfa_id: Option<&'a str>,
+    options: RequestOptions<'a>,
+    _marker: std::marker::PhantomData<fn() -> R>,
+}
+
+impl<'a> Collection<'a> {
     /// Authenticate with combination of **email**/**username** and **password**.
     ///
     /// On success, the auth token is automatically stored and used for subsequent requests.
     ///
+    /// The builder can also set the identity field, and the `expand` and `fields` query
+    /// parameters of the request.
+    ///
     /// # Example
     /// ```rust,ignore
     /// let auth_data = pb.collection("users")
@@ -82,28 +146,218 @@ impl Collection<'_> {
     ///
     /// println!("Token: {}", auth_data.token);
     /// ```
-    pub async fn auth_with_password(
-        &mut self,
-        identity: &str,
-        password: &str,
-    ) -> Result<AuthStore, AuthenticationError> {
+    #[must_use]
+    pub const fn auth_with_password(
+        self,
+        identity: &'a str,
+        password: &'a str,
+    ) -> CollectionAuthWithPasswordBuilder<'a> {
+        CollectionAuthWithPasswordBuilder {
+            client: self.client,
+            collection_name: self.name,
+            identity,
+            password,
+            identity_field: None,
+            expand: None,
+            fields: None,
+            mfa_id: None,
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, R: DeserializeOwned> CollectionAuthWithPasswordBuilder<'a, R> {
+    /// Set the field the identity is checked against, for collections with several
+    /// identity fields (e.g. `username` and `email`).
+    ///
+    /// If not set, the identity is checked against all the identity fields of the collection.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .identity_field("username")
+    /// ```
+    pub const fn identity_field(mut self, identity_field: &'a str) -> Self {
+        self.identity_field = Some(identity_field);
+        self
+    }
+
+    /// Auto expand relations of the auth record (up to 6-levels deep).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .expand("company")
+    /// ```
+    pub const fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Comma separated string of the fields to return in the response.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .fields("token,record.id,record.email")
+    /// ```
+    pub const fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Complete a pending Multi-Factor Authentication session.
+    ///
+    /// `mfa_id` is the one returned by [`AuthenticationError::MfaRequired`] after a
+    /// first successful authentication with another method.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .mfa_id("MFA_ID")
+    /// ```
+    pub const fn mfa_id(mut self, mfa_id: &'a str) -> Self {
+        self.mfa_id = Some(mfa_id);
+        self
+    }
+
+    /// Deserialize the auth record into the given type, instead of an [`AuthStoreRecord`].
+    ///
+    /// The client still saves the token, along with the [`AuthStoreRecord`] fields of the record.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct User {
+    ///     id: String,
+    ///     name: String,
+    ///     organization: String,
+    /// }
+    ///
+    /// let auth_data = pb
+    ///     .collection("users")
+    ///     .auth_with_password("YOUR_EMAIL_OR_USERNAME", "YOUR_PASSWORD")
+    ///     .record_type::<User>()
+    ///     .await?;
+    ///
+    /// println!("Welcome, {}", auth_data.record.name);
+    /// ```
+    pub fn record_type<T: DeserializeOwned>(self) -> CollectionAuthWithPasswordBuilder<'a, T> {
+        CollectionAuthWithPasswordBuilder {
+            client: self.client,
+            collection_name: self.collection_name,
+            identity: self.identity,
+            password: self.password,
+            identity_field: self.identity_field,
+            expand: self.expand,
+            fields: self.fields,
+            mfa_id: self.mfa_id,
+            options: self.options,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the auth data.
+    pub async fn call(self) -> Result<AuthStore<R>, AuthenticationError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            AuthenticationError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<AuthStore<R>, AuthenticationError> {
         let uri = format!(
             "{}/api/collections/{}/auth-with-password",
-            self.client.base_url, self.name
+            self.client.base_url, self.collection_name
         );
 
-        let credentials = Credentials { identity, password };
+        let credentials = Credentials {
+            identity: self.identity,
+            password: self.password,
+            identity_field: self.identity_field,
+            mfa_id: self.mfa_id,
+        };
+
+        let mut query_parameters: Vec<(&str, &str)> = vec![];
+
+        if let Some(expand) = self.expand {
+            query_parameters.push(("expand", expand));
+        }
+
+        if let Some(fields) = self.fields {
+            query_parameters.push(("fields", fields));
+        }
 
         let response = self
             .client
-            .request_post_json(&uri, &credentials)
-            .send()
+            .send_request(
+                self.options.apply(
+                    self.client
+                        .request_post_json(&uri, &credentials)
+                        .query(&query_parameters),
+                ),
+            )
             .await?;
 
+        if circuit_breaker::is_open_response(&response) {
+            return Err(AuthenticationError::CircuitOpen);
+        }
+
         if response.status().is_success() {
-            let auth_store = response.json::<AuthStore>().await?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?;
 
-            self.client.update_auth_store(auth_store.clone());
+            let Ok((auth_store, saved_auth_store)) = parse_auth_response::<R>(&body) else {
+                return Err(AuthenticationError::UnexpectedResponse(RawResponse::new(
+                    status,
+                    headers,
+                    String::from_utf8_lossy(&body).into_owned(),
+                )));
+            };
+
+            self.client.update_auth_store(saved_auth_store);
 
             return Ok(auth_store);
         }
@@ -185,6 +439,87 @@ impl Collection<'_> {
             return Err(AuthenticationError::InvalidCredentials);
         }
 
-        Err(AuthenticationError::UnexpectedResponse)
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            return Err(mfa_processing(response).await);
+        }
+
+        Err(AuthenticationError::UnexpectedResponse(
+            RawResponse::from_response(response).await,
+        ))
+    }
+}
+
+impl<'a, R: DeserializeOwned + Send + 'a> IntoFuture for CollectionAuthWithPasswordBuilder<'a, R> {
+    type Output = Result<AuthStore<R>, AuthenticationError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
+
+/// Turns a [401 Unauthorized]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/401") auth response
+/// into an [`AuthenticationError`].
+///
+/// When the collection has MFA enabled, `PocketBase` answers the first successful auth
+/// attempt with a 401 containing the `mfaId` of the pending MFA session.
+pub async fn mfa_processing(response: reqwest::Response) -> AuthenticationError {
+    let response = RawResponse::from_response(response).await;
+
+    let Ok(body) = serde_json::from_str::<Value>(&response.body) else {
+        return AuthenticationError::UnexpectedResponse(response);
+    };
+
+    // {
+    //     "mfaId": "ysdx9ko8ciwkqzg"
+    // }
+    let mfa_id = body
+        .get("mfaId")
+        .or_else(|| body.get("data").and_then(|data| data.get("mfaId")))
+        .and_then(Value::as_str);
+
+    mfa_id.map_or_else(
+        || AuthenticationError::UnexpectedResponse(response),
+        |mfa_id| AuthenticationError::MfaRequired {
+            mfa_id: mfa_id.to_string(),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::{AuthenticationError, PocketBase, PocketBaseError};
+
+    #[tokio::test]
+    async fn unexpected_response_keeps_the_body() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/collections/users/auth-with-password");
+            then.status(502).body("Bad Gateway");
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let error = pb
+            .collection("users")
+            .auth_with_password("test@example.com", "password")
+            .await
+            .unwrap_err();
+
+        let AuthenticationError::UnexpectedResponse(response) = error else {
+            panic!("unexpected error: {error:?}");
+        };
+
+        assert_eq!(response.status, 502);
+        assert_eq!(response.body, "Bad Gateway");
+
+        assert!(matches!(
+            PocketBaseError::from(AuthenticationError::UnexpectedResponse(response)),
+            PocketBaseError::UnexpectedResponse(response) if response.status == 502
+        ));
     }
 }
@@ -0,0 +1,240 @@
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::{AUTHORIZATION, HeaderValue};
+use reqwest::{Request, RequestBuilder, Response, StatusCode};
+use serde::Serialize;
+
+use crate::{AuthStore, PocketBase, TransportError};
+
+/// Configuration of the automatic refresh of the auth token.
+///
+/// When enabled, the client transparently calls `auth-refresh` before sending a request
+/// if the token expires within `threshold`, and retries a request once if it was rejected
+/// with a `401 Unauthorized` after refreshing the token.
+///
+/// Superuser tokens can't be refreshed once expired: credentials can be given to
+/// re-authenticate instead.
+///
+/// # Example
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use pocketbase_rs::{AutoRefresh, PocketBase};
+///
+/// let pb = PocketBase::builder("http://localhost:8090")
+///     .auto_refresh(
+///         AutoRefresh::new(Duration::from_secs(30 * 60))
+///             .with_credentials("_superusers", "admin@example.com", "YOUR_PASSWORD"),
+///     )
+///     .build()?;
+/// ```
+#[derive(Clone)]
+pub struct AutoRefresh {
+    threshold: Duration,
+    credentials: Option<Credentials>,
+}
+
+#[derive(Clone)]
+struct Credentials {
+    collection: String,
+    identity: String,
+    password: String,
+}
+
+#[derive(Serialize)]
+struct PasswordAuthRequest<'a> {
+    identity: &'a str,
+    password: &'a str,
+}
+
+impl fmt::Debug for AutoRefresh {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AutoRefresh")
+            .field("threshold", &self.threshold)
+            .field(
+                "credentials",
+                &self.credentials.as_ref().map(|_| "***REDACTED***"),
+            )
+            .finish()
+    }
+}
+
+impl AutoRefresh {
+    /// Refresh the token when it expires within the given threshold.
+    #[must_use]
+    pub const fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            credentials: None,
+        }
+    }
+
+    /// Re-authenticate with the given credentials when the token can't be refreshed.
+    #[must_use]
+    pub fn with_credentials(
+        mut self,
+        collection: impl Into<String>,
+        identity: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some(Credentials {
+            collection: collection.into(),
+            identity: identity.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    fn needs_refresh(&self, auth_store: Option<&AuthStore>) -> bool {
+        auth_store
+            .and_then(AuthStore::expires_at)
+            .and_then(|expires_at| expires_at.duration_since(SystemTime::now()).ok())
+            .is_none_or(|remaining| remaining < self.threshold)
+    }
+}
+
+/// Sends the request, refreshing the auth token beforehand if it is about to expire,
+/// and once more if the request is rejected with a `401 Unauthorized`.
+pub async fn send_with_auto_refresh(
+    client: &PocketBase,
+    auto_refresh: &AutoRefresh,
+    request: RequestBuilder,
+) -> Result<Response, TransportError> {
+    let (http_client, request) = request.build_split();
+    let mut request = request?;
+
+    // Authentication requests are sent as-is, as they are the ones refreshing the token
+    let is_auth_request = request
+        .url()
+        .path_segments()
+        .and_then(Iterator::last)
+        .is_some_and(|segment| segment.starts_with("auth-"));
+
+    let Some(used_token) = bearer_token(&request).filter(|_| !is_auth_request) else {
+        return client
+            .execute(RequestBuilder::from_parts(http_client, request))
+            .await;
+    };
+
+    if auto_refresh.needs_refresh(client.auth_store().as_ref())
+        && refresh(client, auto_refresh, &used_token).await
+    {
+        set_bearer_token(client, &mut request);
+    }
+
+    let retry_request = request.try_clone();
+
+    let response = client
+        .execute(RequestBuilder::from_parts(http_client.clone(), request))
+        .await?;
+
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return Ok(response);
+    }
+
+    let Some(mut retry_request) = retry_request else {
+        return Ok(response);
+    };
+
+    let used_token = bearer_token(&retry_request).unwrap_or_default();
+
+    if !refresh(client, auto_refresh, &used_token).await {
+        return Ok(response);
+    }
+
+    set_bearer_token(client, &mut retry_request);
+
+    client
+        .execute(RequestBuilder::from_parts(http_client, retry_request))
+        .await
+}
+
+/// Refreshes the auth store, returning `true` if a new token is available.
+///
+/// Concurrent refreshes are serialized: if the token was already replaced while
+/// waiting, it is not refreshed again.
+async fn refresh(client: &PocketBase, auto_refresh: &AutoRefresh, used_token: &str) -> bool {
+    let _guard = client.auth_refresh_lock.lock().await;
+
+    let auth_store = client.auth_store();
+
+    if auth_store
+        .as_ref()
+        .is_some_and(|auth_store| auth_store.token != used_token)
+    {
+        return true;
+    }
+
+    if let Some(auth_store) = auth_store.filter(AuthStore::is_valid) {
+        let url = format!(
+            "{}/api/collections/{}/auth-refresh",
+            client.base_url, auth_store.record.collection_name
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(collection = %auth_store.record.collection_name, "refreshing PocketBase auth token");
+
+        if let Some(auth_store) = authenticate(client, client.request_post(&url)).await {
+            client.update_auth_store(auth_store);
+            return true;
+        }
+    }
+
+    if let Some(credentials) = &auto_refresh.credentials {
+        let url = format!(
+            "{}/api/collections/{}/auth-with-password",
+            client.base_url, credentials.collection
+        );
+
+        let body = PasswordAuthRequest {
+            identity: &credentials.identity,
+            password: &credentials.password,
+        };
+
+        let request = client.reqwest_client.post(&url).json(&body);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(collection = %credentials.collection, "re-authenticating to PocketBase with credentials");
+
+        if let Some(auth_store) = authenticate(client, request).await {
+            client.update_auth_store(auth_store);
+            return true;
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!("PocketBase auth token could not be refreshed");
+
+    false
+}
+
+async fn authenticate(client: &PocketBase, request: RequestBuilder) -> Option<AuthStore> {
+    let response = client.execute(request).await.ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    response.json::<AuthStore>().await.ok()
+}
+
+fn bearer_token(request: &Request) -> Option<String> {
+    request
+        .headers()
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::to_owned)
+}
+
+fn set_bearer_token(client: &PocketBase, request: &mut Request) {
+    let Some(token) = client.token() else {
+        return;
+    };
+
+    if let Ok(mut value) = HeaderValue::from_str(&format!("Bearer {token}")) {
+        value.set_sensitive(true);
+        request.headers_mut().insert(AUTHORIZATION, value);
+    }
+}
@@ -0,0 +1,125 @@
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::error::{BadRequestError, BadRequestResponse, RawResponse};
+use crate::{Collection, circuit_breaker};
+
+/// Represents the various errors that can be obtained after a `confirm_verification` request.
+#[derive(Error, Debug)]
+pub enum ConfirmVerificationError {
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response.
+    ///
+    /// The given verification token is invalid or has expired.
+    #[error("Invalid or expired verification token.")]
+    InvalidToken,
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response.
+    ///
+    /// One or more fields were not validated `PocketBase`.
+    #[error("One or more fields were not validated : {0:?}")]
+    BadRequest(Vec<BadRequestError>),
+    /// Communication with the `PocketBase` API failed.
+    ///
+    /// This could be caused by an internet outage, an error in the link given to the `PocketBase` SDK
+    /// and similar errors.
+    #[error("The communication with the PocketBase API failed: {0}")]
+    Unreachable(String),
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    ///
+    /// See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
+    /// The response from the `PocketBase` instance API was unexpected.
+    /// If you think its an error, please [open an issue on GitHub]("https://github.com/fromhorizons/pocketbase-rs/issues").
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
+    #[error("An unexpected response was returned by the PocketBase API: {0}")]
+    UnexpectedResponse(RawResponse),
+}
+
+#[derive(Clone, Default, Serialize)]
+struct VerificationConfirmation<'a> {
+    token: &'a str,
+}
+
+impl<'a> Collection<'a> {
+    /// Confirms a user account verification request.
+    ///
+    /// `token` is the one received in the verification email sent by
+    /// [`Collection::request_verification()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.collection("users")
+    ///     .confirm_verification("VERIFICATION_TOKEN")
+    ///     .await?;
+    /// ```
+    pub async fn confirm_verification(
+        &self,
+        token: &'a str,
+    ) -> Result<(), ConfirmVerificationError> {
+        let url = format!(
+            "{}/api/collections/{}/confirm-verification",
+            self.client.base_url, self.name
+        );
+
+        let body = VerificationConfirmation { token };
+
+        let request = self
+            .client
+            .send_request(self.client.request_post_json(&url, &body))
+            .await;
+
+        match request {
+            Ok(response) => match response.status() {
+                _ if circuit_breaker::is_open_response(&response) => {
+                    Err(ConfirmVerificationError::CircuitOpen)
+                }
+                reqwest::StatusCode::NO_CONTENT | reqwest::StatusCode::OK => Ok(()),
+
+                reqwest::StatusCode::BAD_REQUEST => {
+                    let response = RawResponse::from_response(response).await;
+
+                    let Ok(bad_response) =
+                        serde_json::from_str::<BadRequestResponse>(&response.body)
+                    else {
+                        return Err(ConfirmVerificationError::UnexpectedResponse(response));
+                    };
+
+                    // {
+                    //     "status": 400,
+                    //     "message": "An error occurred while validating the submitted data.",
+                    //     "data": {
+                    //       "token": {
+                    //         "code": "validation_invalid_token",
+                    //         "message": "Invalid or expired token."
+                    //       }
+                    //     }
+                    // }
+                    if bad_response.data.contains_key("token") {
+                        return Err(ConfirmVerificationError::InvalidToken);
+                    }
+
+                    let mut errors: Vec<BadRequestError> = vec![];
+
+                    for (error_name, error_data) in bad_response.data {
+                        errors.push(BadRequestError {
+                            name: error_name,
+                            code: error_data.code,
+                            message: error_data.message,
+                        });
+                    }
+
+                    Err(ConfirmVerificationError::BadRequest(errors))
+                }
+
+                _ => Err(ConfirmVerificationError::UnexpectedResponse(
+                    RawResponse::from_response(response).await,
+                )),
+            },
+
+            Err(error) => Err(ConfirmVerificationError::Unreachable(error.to_string())),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::error::{BadRequestError, BadRequestResponse, RawResponse};
+use crate::{Collection, circuit_breaker};
+
+/// Represents the various errors that can be obtained after a `request_email_change`
+/// or `confirm_email_change` request.
+#[derive(Error, Debug)]
+pub enum EmailChangeError {
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response.
+    ///
+    /// The given email change token is invalid or has expired.
+    #[error("Invalid or expired email change token.")]
+    InvalidToken,
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response.
+    ///
+    /// The given password doesn't match the auth record's current password.
+    #[error("Missing or invalid auth record password.")]
+    WrongPassword,
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response.
+    ///
+    /// One or more fields were not validated (e.g. the new email is already in use).
+    #[error("One or more fields were not validated : {0:?}")]
+    BadRequest(Vec<BadRequestError>),
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [401 Unauthorized]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/401") HTTP error response.
+    ///
+    /// Requesting an email change requires the client to be authenticated.
+    #[error("The request requires valid record authorization token.")]
+    Unauthorized,
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [403 Forbidden]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/403") HTTP error response.
+    ///
+    /// The authorized record is not allowed to perform this action.
+    #[error("The authorized record is not allowed to perform this action.")]
+    Forbidden,
+    /// Communication with the `PocketBase` API failed.
+    ///
+    /// This could be caused by an internet outage, an error in the link given to the `PocketBase` SDK
+    /// and similar errors.
+    #[error("The communication with the PocketBase API failed: {0}")]
+    Unreachable(String),
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    ///
+    /// See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
+    /// The response from the `PocketBase` instance API was unexpected.
+    /// If you think its an error, please [open an issue on GitHub]("https://github.com/fromhorizons/pocketbase-rs/issues").
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
+    #[error("An unexpected response was returned by the PocketBase API: {0}")]
+    UnexpectedResponse(RawResponse),
+}
+
+#[derive(Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EmailChangeRequest<'a> {
+    new_email: &'a str,
+}
+
+#[derive(Clone, Default, Serialize)]
+struct EmailChangeConfirmation<'a> {
+    token: &'a str,
+    password: &'a str,
+}
+
+impl Collection<'_> {
+    /// Sends an email change request to the currently authenticated record.
+    ///
+    /// `PocketBase` sends a confirmation link to the new email address.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.collection("users")
+    ///     .request_email_change("new@example.com")
+    ///     .await?;
+    /// ```
+    pub async fn request_email_change(&self, new_email: &str) -> Result<(), EmailChangeError> {
+        let url = format!(
+            "{}/api/collections/{}/request-email-change",
+            self.client.base_url, self.name
+        );
+
+        let body = EmailChangeRequest { new_email };
+
+        let request = self
+            .client
+            .send_request(self.client.request_post_json(&url, &body))
+            .await;
+
+        email_change_processing(request).await
+    }
+
+    /// Confirms an email change request.
+    ///
+    /// `token` is the one received in the confirmation link, `password` is the
+    /// auth record's current password.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.collection("users")
+    ///     .confirm_email_change("EMAIL_CHANGE_TOKEN", "YOUR_PASSWORD")
+    ///     .await?;
+    /// ```
+    pub async fn confirm_email_change(
+        &self,
+        token: &str,
+        password: &str,
+    ) -> Result<(), EmailChangeError> {
+        let url = format!(
+            "{}/api/collections/{}/confirm-email-change",
+            self.client.base_url, self.name
+        );
+
+        let body = EmailChangeConfirmation { token, password };
+
+        let request = self
+            .client
+            .send_request(self.client.request_post_json(&url, &body))
+            .await;
+
+        email_change_processing(request).await
+    }
+}
+
+async fn email_change_processing(
+    request: Result<reqwest::Response, crate::TransportError>,
+) -> Result<(), EmailChangeError> {
+    match request {
+        Ok(response) => match response.status() {
+            _ if circuit_breaker::is_open_response(&response) => Err(EmailChangeError::CircuitOpen),
+            reqwest::StatusCode::NO_CONTENT | reqwest::StatusCode::OK => Ok(()),
+
+            reqwest::StatusCode::BAD_REQUEST => {
+                let response = RawResponse::from_response(response).await;
+
+                let Ok(bad_response) = serde_json::from_str::<BadRequestResponse>(&response.body)
+                else {
+                    return Err(EmailChangeError::UnexpectedResponse(response));
+                };
+
+                let mut errors: Vec<BadRequestError> = vec![];
+
+                for (error_name, error_data) in bad_response.data {
+                    match (error_name.as_str(), error_data.code.as_str()) {
+                        // {
+                        //     "token": {
+                        //       "code": "validation_invalid_token",
+                        //       "message": "Invalid or expired token."
+                        //     }
+                        // }
+                        ("token", _) => return Err(EmailChangeError::InvalidToken),
+                        // {
+                        //     "password": {
+                        //       "code": "validation_invalid_password",
+                        //       "message": "Missing or invalid auth record password."
+                        //     }
+                        // }
+                        ("password", "validation_invalid_password") => {
+                            return Err(EmailChangeError::WrongPassword);
+                        }
+                        _ => errors.push(BadRequestError {
+                            name: error_name,
+                            code: error_data.code,
+                            message: error_data.message,
+                        }),
+                    }
+                }
+
+                Err(EmailChangeError::BadRequest(errors))
+            }
+
+            reqwest::StatusCode::UNAUTHORIZED => Err(EmailChangeError::Unauthorized),
+            reqwest::StatusCode::FORBIDDEN => Err(EmailChangeError::Forbidden),
+
+            _ => Err(EmailChangeError::UnexpectedResponse(
+                RawResponse::from_response(response).await,
+            )),
+        },
+
+        Err(error) => Err(EmailChangeError::Unreachable(error.to_string())),
+    }
+}
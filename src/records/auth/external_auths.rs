@@ -0,0 +1,110 @@
+use serde::Deserialize;
+
+use crate::error::RequestError;
+use crate::filter::escape_filter_value;
+use crate::{Collection, Timestamp};
+
+/// Name of the system collection storing the `OAuth2` providers linked to auth records.
+pub const EXTERNAL_AUTHS_COLLECTION: &str = "_externalAuths";
+
+/// Represents an `OAuth2` provider linked to an auth record.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ExternalAuth {
+    /// The external auth unique ID.
+    pub id: String,
+    /// The ID of the collection the linked auth record belongs to.
+    pub collection_ref: String,
+    /// The ID of the linked auth record.
+    pub record_ref: String,
+    /// The name of the `OAuth2` provider *(example: `google`)*.
+    pub provider: String,
+    /// The ID of the user on the provider's side.
+    pub provider_id: String,
+    /// The timestamp when the provider was linked.
+    #[serde(default)]
+    pub created: Timestamp,
+    /// The timestamp when the external auth was last updated.
+    #[serde(default)]
+    pub updated: Timestamp,
+}
+
+impl Collection<'_> {
+    /// Lists all `OAuth2` providers linked to the given auth record.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let external_auths = pb
+    ///     .collection("users")
+    ///     .list_external_auths("RECORD_ID")
+    ///     .await?;
+    ///
+    /// for external_auth in external_auths {
+    ///     println!("Linked with {}", external_auth.provider);
+    /// }
+    /// ```
+    pub async fn list_external_auths(
+        self,
+        record_id: &str,
+    ) -> Result<Vec<ExternalAuth>, RequestError> {
+        let filter = format!("recordRef='{}'", escape_filter_value(record_id));
+
+        Collection {
+            client: self.client,
+            name: EXTERNAL_AUTHS_COLLECTION,
+        }
+        .get_full_list::<ExternalAuth>()
+        .filter(&filter)
+        .call()
+        .await
+    }
+
+    /// Unlinks a single `OAuth2` provider from the given auth record.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.collection("users")
+    ///     .unlink_external_auth("RECORD_ID", "google")
+    ///     .await?;
+    /// ```
+    pub async fn unlink_external_auth(
+        self,
+        record_id: &str,
+        provider: &str,
+    ) -> Result<(), RequestError> {
+        let filter = format!(
+            "recordRef='{}' && provider='{}'",
+            escape_filter_value(record_id),
+            escape_filter_value(provider)
+        );
+
+        let client = self.client;
+
+        let external_auth = Collection {
+            client,
+            name: EXTERNAL_AUTHS_COLLECTION,
+        }
+        .get_first_list_item::<ExternalAuth>()
+        .filter(&filter)
+        .call()
+        .await?;
+
+        let endpoint = format!(
+            "{}/api/collections/{}/records/{}",
+            client.base_url, EXTERNAL_AUTHS_COLLECTION, external_auth.id
+        );
+
+        let Ok(response) = client.send_request(client.request_delete(&endpoint)).await else {
+            return Err(RequestError::Unreachable);
+        };
+
+        match response.status() {
+            reqwest::StatusCode::NO_CONTENT | reqwest::StatusCode::OK => Ok(()),
+            reqwest::StatusCode::UNAUTHORIZED => Err(RequestError::Unauthorized),
+            reqwest::StatusCode::FORBIDDEN => Err(RequestError::Forbidden),
+            reqwest::StatusCode::NOT_FOUND => Err(RequestError::NotFound),
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(RequestError::TooManyRequests),
+            _ => Err(RequestError::from_response(response).await),
+        }
+    }
+}
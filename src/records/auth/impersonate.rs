@@ -1,8 +1,14 @@
-use serde::Deserialize;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use serde::de::DeserializeOwned;
 use thiserror::Error;
+use tokio_util::sync::CancellationToken;
 
-use super::AuthStore;
-use crate::{Collection, PocketBase};
+use super::{AuthStore, parse_auth_response};
+use crate::error::RawResponse;
+use crate::options::{RequestOptions, cancellable};
+use crate::{AuthStoreRecord, Collection, PocketBase, circuit_breaker};
 
 /// Represents the various errors that can be obtained after a `impersonate` request.
 #[derive(Error, Debug)]
@@ -41,36 +47,30 @@ pub enum ImpersonateError {
     /// and similar errors.
     #[error("The communication with the PocketBase API failed: {0}")]
     Unreachable(String),
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    ///
+    /// See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
+    /// The request was cancelled through its cancellation token.
+    #[error("The request was cancelled.")]
+    Cancelled,
     /// The response from the `PocketBase` instance API was unexpected.
     /// If you think its an error, please [open an issue on GitHub]("https://github.com/fromhorizons/pocketbase-rs/issues").
-    #[error("An unhandled status code was returned by the PocketBase API: {0}")]
-    UnexpectedResponse(String),
-}
-
-#[derive(Deserialize)]
-struct AuthData {
-    record: AuthDataRecord,
-    token: String,
-}
-
-#[derive(Default, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct AuthDataRecord {
-    collection_id: String,
-    collection_name: String,
-    id: String,
-    email: String,
-    email_visibility: bool,
-    verified: bool,
-    created: String,
-    updated: String,
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
+    #[error("An unexpected response was returned by the PocketBase API: {0}")]
+    UnexpectedResponse(RawResponse),
 }
 
-pub struct CollectionImpersonateBuilder<'a> {
+pub struct CollectionImpersonateBuilder<'a, R = AuthStoreRecord> {
     client: &'a PocketBase,
     collection_name: &'a str,
     user_id: &'a str,
     duration: Option<String>,
+    reqwest_client: Option<reqwest::Client>,
+    options: RequestOptions<'a>,
+    _marker: std::marker::PhantomData<fn() -> R>,
 }
 
 impl<'a> Collection<'a> {
@@ -79,6 +79,10 @@ impl<'a> Collection<'a> {
     /// Only superusers can perform this action. Returns a new `PocketBase` client
     /// with the impersonated user's auth token.
     ///
+    /// The new client shares the HTTP client (timeouts, proxies, TLS configuration, ...),
+    /// the retry policy and the request hooks of the current client. As the token can't be
+    /// refreshed, the automatic refresh is not inherited.
+    ///
     /// # Example
     /// ```rust,ignore
     /// let impersonate_client = pb
@@ -97,11 +101,14 @@ impl<'a> Collection<'a> {
             collection_name: self.name,
             user_id,
             duration: None,
+            reqwest_client: None,
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl CollectionImpersonateBuilder<'_> {
+impl<'a, R: DeserializeOwned> CollectionImpersonateBuilder<'a, R> {
     /// Set custom JWT duration in seconds (optional).
     ///
     /// If not set, uses the default collection auth token duration.
@@ -110,40 +117,172 @@ impl CollectionImpersonateBuilder<'_> {
         self
     }
 
+    /// Use the given reqwest client for the impersonated client, instead of the one of the
+    /// current client.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let reqwest_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(5))
+    ///     .build()?;
+    ///
+    /// .reqwest_client(reqwest_client)
+    /// ```
+    pub fn reqwest_client(mut self, reqwest_client: reqwest::Client) -> Self {
+        self.reqwest_client = Some(reqwest_client);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Deserialize the auth record returned by [`Self::call_auth_store()`] into the given
+    /// type, instead of an [`AuthStoreRecord`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let auth_store = pb
+    ///     .collection("users")
+    ///     .impersonate("USER_RECORD_ID")
+    ///     .record_type::<User>()
+    ///     .call_auth_store()
+    ///     .await?;
+    /// ```
+    pub fn record_type<T: DeserializeOwned>(self) -> CollectionImpersonateBuilder<'a, T> {
+        CollectionImpersonateBuilder {
+            client: self.client,
+            collection_name: self.collection_name,
+            user_id: self.user_id,
+            duration: self.duration,
+            reqwest_client: self.reqwest_client,
+            options: self.options,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
     /// Execute the request and return a new `PocketBase` client with the impersonated user's token.
     pub async fn call(self) -> Result<PocketBase, ImpersonateError> {
+        let client = self.client;
+        let reqwest_client = self
+            .reqwest_client
+            .clone()
+            .unwrap_or_else(|| client.reqwest_client.clone());
+
+        let (_, auth_store) = self.execute().await?;
+
+        let impersonate_client = inherit_client(client, reqwest_client);
+        impersonate_client.update_auth_store(auth_store);
+
+        Ok(impersonate_client)
+    }
+
+    /// Execute the request and return the impersonated user's auth store (token and record),
+    /// without creating a new client.
+    ///
+    /// Useful when the token is only forwarded to another service.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let auth_store = pb
+    ///     .collection("users")
+    ///     .impersonate("USER_RECORD_ID")
+    ///     .call_auth_store()
+    ///     .await?;
+    ///
+    /// println!("Token: {}", auth_store.token);
+    /// ```
+    pub async fn call_auth_store(self) -> Result<AuthStore<R>, ImpersonateError> {
+        self.execute().await.map(|(auth_store, _)| auth_store)
+    }
+
+    /// Sends the request until completion or cancellation, returning both the typed auth
+    /// store and the one saved by clients.
+    async fn execute(self) -> Result<(AuthStore<R>, AuthStore), ImpersonateError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.send(), || {
+            ImpersonateError::Cancelled
+        })
+        .await
+    }
+
+    async fn send(self) -> Result<(AuthStore<R>, AuthStore), ImpersonateError> {
         let url = format!(
             "{}/api/collections/{}/impersonate/{}",
             self.client.base_url, self.collection_name, self.user_id
         );
 
         let request = {
-            if let Some(duration) = self.duration {
-                self.client
-                    .request_post_form(
-                        &url,
-                        reqwest::multipart::Form::new().text("duration", duration),
-                    )
-                    .send()
-                    .await
+            let request = if let Some(duration) = self.duration {
+                self.client.request_post_form(
+                    &url,
+                    reqwest::multipart::Form::new().text("duration", duration),
+                )
             } else {
-                self.client.request_post(&url).send().await
-            }
+                self.client.request_post(&url)
+            };
+
+            self.client.send_request(self.options.apply(request)).await
         };
 
         match request {
             Ok(response) => match response.status() {
+                _ if circuit_breaker::is_open_response(&response) => {
+                    Err(ImpersonateError::CircuitOpen)
+                }
                 reqwest::StatusCode::OK => {
-                    let Ok(auth_store) = response.json::<AuthStore>().await else {
-                        return Err(ImpersonateError::UnexpectedResponse(
-                            "Couldn't parse API response into Auth Data".to_string(),
-                        ));
-                    };
+                    let headers = response.headers().clone();
+                    let body = response
+                        .bytes()
+                        .await
+                        .map_err(|error| ImpersonateError::Unreachable(error.to_string()))?;
 
-                    let mut impersonate_client = PocketBase::new(&self.client.base_url());
-                    impersonate_client.update_auth_store(auth_store);
-
-                    Ok(impersonate_client)
+                    parse_auth_response::<R>(&body).map_err(|_| {
+                        ImpersonateError::UnexpectedResponse(RawResponse::new(
+                            200,
+                            headers,
+                            String::from_utf8_lossy(&body).into_owned(),
+                        ))
+                    })
                 }
 
                 reqwest::StatusCode::BAD_REQUEST => Err(ImpersonateError::BadRequest),
@@ -152,10 +291,56 @@ impl CollectionImpersonateBuilder<'_> {
                 reqwest::StatusCode::NOT_FOUND => Err(ImpersonateError::NotFound),
 
                 _ => Err(ImpersonateError::UnexpectedResponse(
-                    response.status().to_string(),
+                    RawResponse::from_response(response).await,
                 )),
             },
             Err(error) => Err(ImpersonateError::Unreachable(error.to_string())),
         }
     }
 }
+
+/// Creates an unauthenticated client with the settings of the given one.
+fn inherit_client(client: &PocketBase, reqwest_client: reqwest::Client) -> PocketBase {
+    let before_send_hooks = client
+        .before_send_hooks
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    let after_send_hooks = client
+        .after_send_hooks
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    #[cfg(feature = "signing")]
+    let request_signer = client
+        .request_signer
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    PocketBase {
+        base_url: client.base_url.clone(),
+        read_base_url: client.read_base_url.clone(),
+        auth_store: Arc::default(),
+        auth_change_listeners: Arc::default(),
+        before_send_hooks: Arc::new(RwLock::new(before_send_hooks)),
+        after_send_hooks: Arc::new(RwLock::new(after_send_hooks)),
+        #[cfg(feature = "signing")]
+        request_signer: Arc::new(RwLock::new(request_signer)),
+        reqwest_client,
+        transport: client.transport.clone(),
+        metrics: Arc::new(RwLock::new(client.metrics())),
+        retry_policy: Arc::new(RwLock::new(client.retry_policy())),
+        circuit_breaker: Arc::new(RwLock::new(client.circuit_breaker())),
+        failover: client.failover.clone(),
+        request_id_header: client.request_id_header.clone(),
+        auto_refresh: Arc::default(),
+        auth_refresh_lock: Arc::default(),
+        auto_cancellation: Arc::new(AtomicBool::new(client.auto_cancellation())),
+        pending_requests: Arc::default(),
+        response_cache: Arc::default(),
+        realtime: Arc::default(),
+    }
+}
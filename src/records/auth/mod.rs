@@ -1,30 +1,93 @@
-use serde::Deserialize;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::Timestamp;
+
+pub mod auth_origins;
 pub mod auth_refresh;
 pub mod auth_refresh_for_user;
+pub mod auth_with_oauth2;
+#[cfg(feature = "oauth2-flow")]
+pub mod auth_with_oauth2_flow;
+pub mod auth_with_otp;
 pub mod auth_with_password;
+pub mod auto_refresh;
+pub mod confirm_verification;
+pub mod email_change;
+pub mod external_auths;
 pub mod impersonate;
 pub mod request_verification;
+pub mod superusers;
 
 /// Stores authentication details for a `PocketBase` user.
 ///
 /// The `AuthStore` struct holds the authenticated user's record and a token
 /// used for making authenticated requests to the `PocketBase` API.
-#[derive(Clone, Debug, Deserialize)]
-pub struct AuthStore {
+///
+/// It can be serialized to persist a session (e.g. to disk) and restored later
+/// with [`PocketBase::load_auth_store()`](crate::PocketBase::load_auth_store).
+///
+/// The record is an [`AuthStoreRecord`] by default, and can be any type holding the
+/// fields of a custom auth collection.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AuthStore<R = AuthStoreRecord> {
     /// The authenticated user's record.
-    pub record: AuthStoreRecord,
+    pub record: R,
     /// The authentication token.
     pub token: String,
 }
 
+/// The claims of a `PocketBase` auth token used by the SDK.
+#[derive(Deserialize)]
+struct TokenClaims {
+    exp: u64,
+}
+
+impl<R> AuthStore<R> {
+    /// Returns the expiration date of the token, decoded locally from its `exp` claim.
+    ///
+    /// Returns `None` if the token is not a valid JWT.
+    #[must_use]
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        let payload = self.token.split('.').nth(1)?;
+        let payload = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+        let claims = serde_json::from_slice::<TokenClaims>(&payload).ok()?;
+
+        UNIX_EPOCH.checked_add(Duration::from_secs(claims.exp))
+    }
+
+    /// Returns `true` if the token is a valid JWT that has not expired yet.
+    ///
+    /// The signature is not verified, and the token could have been invalidated server-side
+    /// (e.g. after a password change): this is only meant to decide when to refresh it.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// if !pb.auth_store().is_some_and(|auth_store| auth_store.is_valid()) {
+    ///     pb.collection("users").auth_refresh().await?;
+    /// }
+    /// ```
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| expires_at > SystemTime::now())
+    }
+}
+
 /// Represents the details of an authenticated user's record.
 ///
 /// The `AuthStoreRecord` struct contains information about the user,
 /// such as their ID, email, etc. and other metadata related to the
 /// collection they belong to.
-#[derive(Clone, Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
+///
+/// Missing fields (e.g. a hidden email) are left empty. To access the custom fields of
+/// an auth collection, authenticate with a custom record type instead.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct AuthStoreRecord {
     /// The user's unique ID.
     pub id: String,
@@ -33,9 +96,11 @@ pub struct AuthStoreRecord {
     /// The name of the collection the user belongs to.
     pub collection_name: String,
     /// The timestamp when the record was created.
-    pub created: String,
+    #[serde(default)]
+    pub created: Timestamp,
     /// The timestamp when the record was last updated.
-    pub updated: String,
+    #[serde(default)]
+    pub updated: Timestamp,
     /// The user's email address.
     pub email: String,
     /// Indicates whether the user's email is publicly visible.
@@ -43,3 +108,14 @@ pub struct AuthStoreRecord {
     /// Indicates whether the user's email has been verified.
     pub verified: bool,
 }
+
+/// Parses an auth response body into an [`AuthStore`] holding a record of type `R`,
+/// along with the default [`AuthStore`] saved in the client.
+pub fn parse_auth_response<R: DeserializeOwned>(
+    body: &[u8],
+) -> Result<(AuthStore<R>, AuthStore), serde_json::Error> {
+    let auth_store = serde_json::from_slice::<AuthStore<R>>(body)?;
+    let saved_auth_store = serde_json::from_slice::<AuthStore>(body)?;
+
+    Ok((auth_store, saved_auth_store))
+}
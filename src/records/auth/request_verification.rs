@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use crate::Collection;
-use crate::error::RequestError;
+use crate::error::{ApiErrorResponse, RequestError};
 
 impl<'a> Collection<'a> {
     /// Sends users account verification request.
@@ -20,33 +20,24 @@ impl<'a> Collection<'a> {
 
         let email: HashMap<String, String> = HashMap::from([("email".to_string(), email.into())]);
 
-        let request = (self.client.request_post_json(&url, &email)).send().await;
+        let request = self
+            .client
+            .send_request(self.client.request_post_json(&url, &email))
+            .await;
 
         match request {
             Ok(response) => match response.status() {
                 reqwest::StatusCode::NO_CONTENT => Ok(()),
-                reqwest::StatusCode::BAD_REQUEST => Err(RequestError::BadRequest(String::new())),
+                reqwest::StatusCode::BAD_REQUEST => Err(RequestError::BadRequest(
+                    ApiErrorResponse::from_response(response).await,
+                )),
+                reqwest::StatusCode::UNAUTHORIZED => Err(RequestError::Unauthorized),
+                reqwest::StatusCode::FORBIDDEN => Err(RequestError::Forbidden),
                 reqwest::StatusCode::NOT_FOUND => Err(RequestError::NotFound),
-                _ => Err(RequestError::Unhandled),
+                reqwest::StatusCode::TOO_MANY_REQUESTS => Err(RequestError::TooManyRequests),
+                _ => Err(RequestError::from_response(response).await),
             },
-            Err(error) => {
-                if let Some(error_status) = error.status() {
-                    match error_status {
-                        reqwest::StatusCode::UNAUTHORIZED => {
-                            return Err(RequestError::Unauthorized);
-                        }
-                        reqwest::StatusCode::FORBIDDEN => {
-                            return Err(RequestError::Forbidden);
-                        }
-                        reqwest::StatusCode::NOT_FOUND => {
-                            return Err(RequestError::NotFound);
-                        }
-                        _ => return Err(RequestError::Unhandled),
-                    }
-                }
-
-                Err(RequestError::Unhandled)
-            }
+            Err(error) => Err(error.into()),
         }
     }
 }
@@ -0,0 +1,66 @@
+use crate::{AuthStore, AuthenticationError, Collection, PocketBase};
+
+/// Name of the system collection holding `PocketBase` superusers.
+pub const SUPERUSERS_COLLECTION: &str = "_superusers";
+
+impl PocketBase {
+    /// Creates a [`Collection`] instance targeting the `_superusers` system collection.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.superusers()
+    ///     .auth_with_password("admin@example.com", "YOUR_PASSWORD")
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn superusers(&self) -> Collection<'_> {
+        Collection {
+            client: self,
+            name: SUPERUSERS_COLLECTION,
+        }
+    }
+
+    /// Authenticate as a superuser with the given **email** and **password**.
+    ///
+    /// Shorthand for `pb.superusers().auth_with_password(email, password)`.
+    /// On success, the auth token is automatically stored and gives access to the
+    /// superuser-only APIs (settings, logs, backups, ...).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.admin_auth_with_password("admin@example.com", "YOUR_PASSWORD")
+    ///     .await?;
+    ///
+    /// assert!(pb.is_superuser());
+    /// ```
+    pub async fn admin_auth_with_password(
+        &self,
+        email: &str,
+        password: &str,
+    ) -> Result<AuthStore, AuthenticationError> {
+        self.superusers()
+            .auth_with_password(email, password)
+            .call()
+            .await
+    }
+
+    /// Returns `true` if the client is currently authenticated as a superuser.
+    #[must_use]
+    pub fn is_superuser(&self) -> bool {
+        self.auth_store()
+            .as_ref()
+            .is_some_and(AuthStore::is_superuser)
+    }
+}
+
+impl AuthStore {
+    /// Returns `true` if the authenticated record belongs to the `_superusers` collection.
+    #[must_use]
+    pub fn is_superuser(&self) -> bool {
+        self.record.collection_name == SUPERUSERS_COLLECTION
+    }
+}
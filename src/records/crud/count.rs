@@ -0,0 +1,142 @@
+use serde::de::IgnoredAny;
+use tokio_util::sync::CancellationToken;
+
+use crate::Collection;
+use crate::error::RequestError;
+use crate::records::crud::get_list::CollectionGetListBuilder;
+
+/// Builder for counting the records of a collection.
+pub struct CollectionCountBuilder<'a> {
+    list: CollectionGetListBuilder<'a, IgnoredAny>,
+}
+
+impl<'a> Collection<'a> {
+    /// Count the records of the given collection, optionally matching a filter.
+    ///
+    /// The records are never deserialized: a single record page is requested and only
+    /// its total is returned.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let published = pb
+    ///     .collection("articles")
+    ///     .count()
+    ///     .filter("status='published'")
+    ///     .call()
+    ///     .await?;
+    ///
+    /// println!("{published} published articles");
+    /// ```
+    #[must_use]
+    pub fn count(self) -> CollectionCountBuilder<'a> {
+        CollectionCountBuilder {
+            list: self
+                .get_list::<IgnoredAny>()
+                .per_page(1)
+                .query_param("fields", "id"),
+        }
+    }
+}
+
+impl<'a> CollectionCountBuilder<'a> {
+    /// Only count the records matching the filter.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .filter("language='en' && created>'1970-01-01'")
+    /// ```
+    #[must_use]
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.list = self.list.filter(filter);
+        self
+    }
+
+    /// Append a custom query parameter to the request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query_param("tenant", "acme")
+    /// ```
+    #[must_use]
+    pub fn query_param(mut self, key: &'a str, value: &'a str) -> Self {
+        self.list = self.list.query_param(key, value);
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    #[must_use]
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.list = self.list.timeout(timeout);
+        self
+    }
+
+    /// Serve the count from the client cache if it was stored less than `ttl` ago.
+    ///
+    /// See [`CollectionGetListBuilder::cache()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .cache(Duration::from_secs(60))
+    /// ```
+    #[must_use]
+    pub fn cache(mut self, ttl: std::time::Duration) -> Self {
+        self.list = self.list.cache(ttl);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    #[must_use]
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.list = self.list.header(key, value);
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    #[must_use]
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.list = self.list.cancellation_token(cancellation_token);
+        self
+    }
+
+    /// Let a newer request with the same key cancel this one, see the `request_key()`
+    /// method of [`Collection::get_list()`](crate::Collection::get_list).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .request_key("articles-count")
+    /// ```
+    #[must_use]
+    pub fn request_key(mut self, key: &'a str) -> Self {
+        self.list = self.list.request_key(key);
+        self
+    }
+
+    /// Never auto-cancel this request, even if auto-cancellation is enabled on the client.
+    #[must_use]
+    pub fn no_auto_cancel(mut self) -> Self {
+        self.list = self.list.no_auto_cancel();
+        self
+    }
+
+    /// Execute the request and return the number of matching records.
+    pub async fn call(self) -> Result<u64, RequestError> {
+        let records = self.list.call().await?;
+
+        Ok(u64::try_from(records.total_items).unwrap_or_default())
+    }
+}
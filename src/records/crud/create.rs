@@ -1,8 +1,15 @@
+use reqwest::RequestBuilder;
+use reqwest::header::HeaderValue;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use thiserror::Error;
+use tokio_util::sync::CancellationToken;
 
-use crate::Collection;
-use crate::error::{BadRequestError, BadRequestResponse};
+use crate::error::{BadRequestError, BadRequestResponse, RawResponse};
+use crate::options::{RequestOptions, cancellable};
+use crate::retry::IDEMPOTENCY_KEY;
+use crate::{Collection, PocketBase, Timestamp, circuit_breaker, generate_record_id};
 
 /// Represents the various errors that can be obtained after a `create` request.
 #[derive(Error, Debug)]
@@ -14,6 +21,23 @@ pub enum CreateError {
     #[error("Failed to create record: {0:?}")]
     BadRequest(Vec<BadRequestError>),
     /// Communication with the `PocketBase` API was successful,
+    /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response,
+    /// as the value of a field with a unique index is already used by another record.
+    ///
+    /// Only returned when no other field was rejected, [`BadRequest`](Self::BadRequest) is
+    /// returned otherwise. Holds the name of the field, e.g. to fetch the existing record instead.
+    #[error("The value of the `{field}` field is already used by another record.")]
+    NotUnique {
+        /// The name of the field whose value must be unique.
+        field: String,
+    },
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [401 Unauthorized]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/401") HTTP error response.
+    ///
+    /// The request requires a valid Authorization Token.
+    #[error("The request requires a valid Authorization Token.")]
+    Unauthorized,
+    /// Communication with the `PocketBase` API was successful,
     /// but returned a [403 Forbidden]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/403") HTTP error response.
     ///
     /// You are not allowed to perform this request.
@@ -25,12 +49,29 @@ pub enum CreateError {
     /// The requested resource wasn't found. Missing collection context.
     #[error("The requested resource wasn't found. Missing collection context.")]
     NotFound,
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [429 Too Many Requests]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/429") HTTP error response.
+    ///
+    /// The server is rate limiting requests. Wait before retrying.
+    #[error("The server is rate limiting requests. Please wait before retrying.")]
+    TooManyRequests,
+    /// The request was cancelled through its cancellation token.
+    #[error("The request was cancelled.")]
+    Cancelled,
+    /// The request could not be built, e.g. because of an invalid header value.
+    #[error("The request could not be built: {0}")]
+    InvalidRequest(String),
     /// Communication with the `PocketBase` API failed.
     ///
     /// This could be caused by an internet outage, an error in the link given to the `PocketBase` SDK
     /// and similar errors.
     #[error("The communication with the PocketBase API failed: {0}")]
     Unreachable(String),
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    ///
+    /// See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
     /// The response could not be parsed into the expected data structure.
     #[error(
         "Could not parse response into the expected data structure. It usually means that there is a mismatch between the provided Generic Type Parameter and your Collection definition: {0}"
@@ -39,41 +80,63 @@ pub enum CreateError {
     /// An unexpected error occurred.
     /// The response from the `PocketBase` instance API was unexpected.
     /// If you think its an error, please [open an issue on GitHub]("https://github.com/fromhorizons/pocketbase-rs/issues").
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
     #[error("An unhandled status code was returned by the PocketBase API: {0}")]
-    UnexpectedResponse(String),
+    UnexpectedResponse(RawResponse),
 }
 
-// TODO: Include the actual record data based on Generic type parameter.
-//
-// pub struct CreateResponse<T> {
-//     pub collection_name: String,
-//     pub collection_id: String,
-//     pub id: String,
-//     pub updated: String,
-//     pub created: String,
-//     #[serde(flatten)]
-//     pub record: T, // The actual record data
-// }
-
-/// Contains information about the successfully created Record
+/// Contains information about the successfully created Record.
+///
+/// The record data returned by `PocketBase` (including server-computed fields such as
+/// defaults and autodates) is deserialized into `record`.
+///
+/// Note that `collectionName`, `collectionId`, `id`, `created` and `updated` are captured
+/// by this envelope and are therefore not forwarded to `T`. They are left empty when
+/// they are not part of the fields selected with `.fields()`.
 #[derive(Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct CreateResponse {
+pub struct CreateResponse<T> {
+    /// The name of the collection the record belongs to.
+    #[serde(default)]
     pub collection_name: String,
+    /// The ID of the collection the record belongs to.
+    #[serde(default)]
     pub collection_id: String,
+    /// The record's unique ID.
+    #[serde(default)]
     pub id: String,
-    pub updated: String,
-    pub created: String,
+    /// The timestamp when the record was last updated.
+    #[serde(default)]
+    pub updated: Timestamp,
+    /// The timestamp when the record was created.
+    #[serde(default)]
+    pub created: Timestamp,
+    /// The actual record data.
+    #[serde(flatten)]
+    pub record: T,
 }
 
-impl Collection<'_> {
+/// Builder for creating a new record.
+pub struct CollectionCreateBuilder<'a, T> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    request: reqwest::RequestBuilder,
+    idempotent: bool,
+    expand: Option<&'a str>,
+    fields: Option<&'a str>,
+    _marker: std::marker::PhantomData<T>,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Collection<'a> {
     /// Create a new record.
     ///
     /// For file uploads, use [`Collection::create_multipart()`].
     ///
     /// # Example
     /// ```rust,ignore
-    /// #[derive(Default, Serialize, Clone, Debug)]
+    /// #[derive(Serialize, Deserialize, Debug)]
     /// struct Article {
     ///     name: String,
     ///     content: String,
@@ -85,24 +148,32 @@ impl Collection<'_> {
     ///         name: "test".to_string(),
     ///         content: "an interesting article content.".to_string(),
     ///     })
+    ///     .call()
     ///     .await?;
+    ///
+    /// println!("Created article {} with name {}", article.id, article.record.name);
     /// ```
-    pub async fn create<T: Default + Serialize + Clone + Send>(
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn create<T: Serialize + DeserializeOwned + Send>(
         self,
         record: T,
-    ) -> Result<CreateResponse, CreateError> {
+    ) -> CollectionCreateBuilder<'a, T> {
         let endpoint = format!(
             "{}/api/collections/{}/records",
             self.client.base_url, self.name
         );
 
-        let request = self
-            .client
-            .request_post_json(&endpoint, &record)
-            .send()
-            .await;
-
-        create_processing(request).await
+        CollectionCreateBuilder {
+            client: self.client,
+            collection_name: self.name,
+            request: self.client.request_post_json(&endpoint, &record),
+            idempotent: false,
+            expand: None,
+            fields: None,
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
+        }
     }
 
     /// Create a new record with multipart form data (e.g., for file uploads).
@@ -126,33 +197,261 @@ impl Collection<'_> {
     ///
     /// let record = pb
     ///     .collection("foxes")
-    ///     .create_multipart(form)
+    ///     .create_multipart::<Fox>(form)
+    ///     .call()
     ///     .await?;
     /// ```
-    pub async fn create_multipart(
+    #[must_use]
+    pub fn create_multipart<T: DeserializeOwned>(
         self,
         form: reqwest::multipart::Form,
-    ) -> Result<CreateResponse, CreateError> {
-        let collection_name = self.name;
-
+    ) -> CollectionCreateBuilder<'a, T> {
         let endpoint = format!(
             "{}/api/collections/{}/records",
-            self.client.base_url, collection_name
+            self.client.base_url, self.name
         );
 
-        let request = self.client.request_post_form(&endpoint, form).send().await;
+        CollectionCreateBuilder {
+            client: self.client,
+            collection_name: self.name,
+            request: self.client.request_post_form(&endpoint, form),
+            idempotent: false,
+            expand: None,
+            fields: None,
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T: DeserializeOwned> CollectionCreateBuilder<'a, T> {
+    /// Auto expand relations of the created record (up to 6-levels deep).
+    ///
+    /// Expanded relations are appended under the `expand` property.
+    /// Only relations the user has view permissions for will be expanded.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .expand("author")
+    /// ```
+    pub const fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Comma separated string of the fields to return in the response.
+    ///
+    /// The envelope fields of the [`CreateResponse`] that are not returned are left empty.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .fields("id,title,expand.author.name")
+    /// ```
+    pub const fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Make the creation safe to retry, so that a create retried after a timeout doesn't
+    /// produce a duplicate record.
+    ///
+    /// A random `id` is generated with [`generate_record_id()`](crate::generate_record_id) and
+    /// added to the record, unless it already has one, and is sent as the `Idempotency-Key`
+    /// header, which lets the client's [`RetryPolicy`](crate::RetryPolicy) retry the request.
+    /// If a record with the generated `id` already exists, e.g. because a previous attempt was
+    /// created before timing out, that record is fetched and returned instead of a `NotUnique`
+    /// error. An `id` given with the record may belong to an unrelated record, so the
+    /// `NotUnique` error is returned as-is in that case.
+    ///
+    /// Only the records sent as JSON get an `id`, the multipart forms must include it themselves.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let article = pb
+    ///     .collection("articles")
+    ///     .create::<Article>(article)
+    ///     .idempotent()
+    ///     .call()
+    ///     .await?;
+    /// ```
+    pub const fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    /// Send the given `Idempotency-Key` header, which lets the client's
+    /// [`RetryPolicy`](crate::RetryPolicy) retry the request.
+    ///
+    /// `PocketBase` doesn't deduplicate the requests by itself: the key is meant for a hook
+    /// or a proxy in front of it. See [`idempotent()`](Self::idempotent) to rely on the record `id` instead.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .idempotency_key(&order.reference)
+    /// ```
+    pub fn idempotency_key(mut self, key: &str) -> Self {
+        self.request = self.request.header(IDEMPOTENCY_KEY, key);
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the created record.
+    pub async fn call(self) -> Result<CreateResponse<T>, CreateError> {
+        let cancellation_token = self.options.cancellation_token.clone();
 
-        create_processing(request).await
+        cancellable(cancellation_token, self.execute(), || {
+            CreateError::Cancelled
+        })
+        .await
     }
+
+    async fn execute(self) -> Result<CreateResponse<T>, CreateError> {
+        let mut query_parameters: Vec<(&str, &str)> = vec![];
+
+        if let Some(expand) = self.expand {
+            query_parameters.push(("expand", expand));
+        }
+
+        if let Some(fields) = self.fields {
+            query_parameters.push(("fields", fields));
+        }
+
+        let request = self.options.apply(self.request).query(&query_parameters);
+
+        if !self.idempotent {
+            return create_processing(self.client.send_request(request).await).await;
+        }
+
+        let (request, generated_id) = with_record_id(request)
+            .map_err(|error| CreateError::InvalidRequest(error.to_string()))?;
+
+        let result = create_processing(self.client.send_request(request).await).await;
+
+        // Only a generated `id` proves that the existing record was created by a previous
+        // attempt: a given `id` may belong to an unrelated record.
+        let Some(id) = generated_id else {
+            return result;
+        };
+
+        match result {
+            Err(CreateError::NotUnique { field }) if field == "id" => {
+                // A previous attempt already created the record
+                let endpoint = format!(
+                    "{}/api/collections/{}/records/{id}",
+                    self.client.base_url, self.collection_name
+                );
+
+                let request = self
+                    .options
+                    .apply(self.client.request_get(&endpoint, Some(query_parameters)));
+
+                create_processing(self.client.send_request(request).await).await
+            }
+            result => result,
+        }
+    }
+}
+
+/// Adds a generated `id` to the JSON record of the request, unless it already has one, and
+/// sends the `id` of the record as the idempotency key.
+///
+/// Returns the request along with the generated `id`, if any.
+fn with_record_id(
+    request: reqwest::RequestBuilder,
+) -> Result<(reqwest::RequestBuilder, Option<String>), reqwest::Error> {
+    let (http_client, request) = request.build_split();
+    let mut request = request?;
+
+    let mut record = request
+        .body()
+        .and_then(reqwest::Body::as_bytes)
+        .and_then(|body| serde_json::from_slice::<Map<String, Value>>(body).ok());
+
+    let given_id = record
+        .as_ref()
+        .and_then(|record| record.get("id"))
+        .and_then(Value::as_str)
+        .filter(|id| !id.is_empty())
+        .map(str::to_owned);
+
+    let generated_id = match (&mut record, &given_id) {
+        (Some(record), None) => {
+            let id = generate_record_id();
+            record.insert("id".to_owned(), Value::String(id.clone()));
+
+            if let Ok(body) = serde_json::to_vec(&record) {
+                *request.body_mut() = Some(body.into());
+            }
+
+            Some(id)
+        }
+        _ => None,
+    };
+
+    // Multipart forms are sent with a random key, as their `id` is unknown
+    let key = given_id
+        .or_else(|| generated_id.clone())
+        .unwrap_or_else(generate_record_id);
+
+    if let Ok(key) = HeaderValue::from_str(&key) {
+        request.headers_mut().insert(IDEMPOTENCY_KEY, key);
+    }
+
+    Ok((
+        RequestBuilder::from_parts(http_client, request),
+        generated_id,
+    ))
 }
 
-async fn create_processing(
-    request: Result<reqwest::Response, reqwest::Error>,
-) -> Result<CreateResponse, CreateError> {
+async fn create_processing<T: DeserializeOwned>(
+    request: Result<reqwest::Response, crate::TransportError>,
+) -> Result<CreateResponse<T>, CreateError> {
     match request {
         Ok(response) => match response.status() {
+            _ if circuit_breaker::is_open_response(&response) => Err(CreateError::CircuitOpen),
             reqwest::StatusCode::OK => {
-                let data = response.json::<CreateResponse>().await;
+                let data = response.json::<CreateResponse<T>>().await;
 
                 match data {
                     Ok(data) => Ok(data),
@@ -175,20 +474,167 @@ async fn create_processing(
                             });
                         }
 
-                        Err(CreateError::BadRequest(errors))
+                        Err(BadRequestError::not_unique_field(&errors)
+                            .map_or(CreateError::BadRequest(errors), |field| {
+                                CreateError::NotUnique { field }
+                            }))
                     }
                     Err(error) => Err(CreateError::ParseError(error.to_string())),
                 }
             }
 
+            reqwest::StatusCode::UNAUTHORIZED => Err(CreateError::Unauthorized),
             reqwest::StatusCode::FORBIDDEN => Err(CreateError::Forbidden),
             reqwest::StatusCode::NOT_FOUND => Err(CreateError::NotFound),
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(CreateError::TooManyRequests),
 
             _ => Err(CreateError::UnexpectedResponse(
-                response.status().to_string(),
+                RawResponse::from_response(response).await,
             )),
         },
 
         Err(error) => Err(CreateError::Unreachable(error.to_string())),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::{Value, json};
+
+    use super::CreateError;
+    use crate::PocketBase;
+    use crate::test_utils::assert_error_statuses;
+
+    #[tokio::test]
+    async fn error_statuses() {
+        assert_error_statuses!(
+            POST,
+            "/api/collections/articles/records",
+            |pb| pb.collection("articles").create::<Value>(json!({ "title": "Hello" })).call(),
+            {
+                401 => CreateError::Unauthorized,
+                429 => CreateError::TooManyRequests,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn fields_subset() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.query_param("fields", "title");
+            then.status(200).json_body(json!({ "title": "Hello" }));
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let response = pb
+            .collection("articles")
+            .create::<Value>(json!({ "title": "Hello" }))
+            .fields("title")
+            .call()
+            .await
+            .unwrap();
+
+        assert!(response.id.is_empty());
+        assert_eq!(response.record, json!({ "title": "Hello" }));
+    }
+
+    fn not_unique_id(server: &MockServer) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(POST).path("/api/collections/articles/records");
+            then.status(400).json_body(json!({
+                "status": 400,
+                "message": "Failed to create record.",
+                "data": {
+                    "id": { "code": "validation_not_unique", "message": "Value must be unique." },
+                },
+            }));
+        });
+
+        server.mock(|when, then| {
+            when.method(GET)
+                .path_matches(Regex::new("^/api/collections/articles/records/[a-z0-9]+$").unwrap());
+            then.status(200).json_body(json!({
+                "collectionName": "articles",
+                "collectionId": "pbc_123",
+                "id": "existing",
+                "title": "Hello",
+            }));
+        })
+    }
+
+    #[tokio::test]
+    async fn idempotent_fetches_the_record_created_by_a_previous_attempt() {
+        let server = MockServer::start_async().await;
+        let fetch = not_unique_id(&server);
+        let pb = PocketBase::new(&server.base_url());
+
+        let response = pb
+            .collection("articles")
+            .create::<Value>(json!({ "title": "Hello" }))
+            .idempotent()
+            .call()
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, "existing");
+        fetch.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn idempotent_keeps_the_conflict_of_a_given_id() {
+        let server = MockServer::start_async().await;
+        let fetch = not_unique_id(&server);
+        let pb = PocketBase::new(&server.base_url());
+
+        let result = pb
+            .collection("articles")
+            .create::<Value>(json!({ "id": "abc123", "title": "Hello" }))
+            .idempotent()
+            .call()
+            .await;
+
+        assert!(matches!(result, Err(CreateError::NotUnique { field }) if field == "id"));
+        fetch.assert_hits(0);
+    }
+
+    #[tokio::test]
+    async fn idempotent_keeps_the_other_errors() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(POST).path("/api/collections/articles/records");
+            then.status(400).json_body(json!({
+                "status": 400,
+                "message": "Failed to create record.",
+                "data": {
+                    "id": { "code": "validation_not_unique", "message": "Value must be unique." },
+                    "title": { "code": "validation_required", "message": "Cannot be blank." },
+                },
+            }));
+        });
+
+        let fetch = server.mock(|when, then| {
+            when.method(GET);
+            then.status(200);
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let result = pb
+            .collection("articles")
+            .create::<Value>(json!({ "title": "" }))
+            .idempotent()
+            .call()
+            .await;
+
+        let Err(CreateError::BadRequest(errors)) = result else {
+            panic!("expected a bad request error, got {result:?}");
+        };
+        assert_eq!(errors.len(), 2);
+        fetch.assert_hits(0);
+    }
+}
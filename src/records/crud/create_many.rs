@@ -0,0 +1,73 @@
+use futures_util::{StreamExt, stream};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::records::crud::create::{CreateError, CreateResponse};
+use crate::{Collection, PocketBase};
+
+/// Builder for creating several records concurrently.
+pub struct CollectionCreateManyBuilder<'a, T> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    records: Vec<T>,
+    concurrency: usize,
+}
+
+impl<'a> Collection<'a> {
+    /// Create several records, sending up to `concurrency` requests in parallel (default: 8).
+    ///
+    /// A failed creation doesn't stop the other ones: the result of each creation is
+    /// returned in the order of the given records.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let results = pb
+    ///     .collection("articles")
+    ///     .create_many::<Article>(articles)
+    ///     .concurrency(16)
+    ///     .call()
+    ///     .await;
+    ///
+    /// let failures = results.iter().filter(|result| result.is_err()).count();
+    /// println!("Imported {} articles, {failures} failed", results.len() - failures);
+    /// ```
+    #[must_use]
+    pub fn create_many<T: Serialize + DeserializeOwned + Send>(
+        &self,
+        records: impl IntoIterator<Item = T>,
+    ) -> CollectionCreateManyBuilder<'a, T> {
+        CollectionCreateManyBuilder {
+            client: self.client,
+            collection_name: self.name,
+            records: records.into_iter().collect(),
+            concurrency: 8,
+        }
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Send> CollectionCreateManyBuilder<'_, T> {
+    /// Send up to `concurrency` requests in parallel (default: 8).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .concurrency(16)
+    /// ```
+    pub const fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = if concurrency == 0 { 1 } else { concurrency };
+        self
+    }
+
+    /// Execute the requests and return the result of each creation, in the order of the records.
+    pub async fn call(self) -> Vec<Result<CreateResponse<T>, CreateError>> {
+        let collection = Collection {
+            client: self.client,
+            name: self.collection_name,
+        };
+
+        stream::iter(self.records)
+            .map(|record| collection.create(record).call())
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
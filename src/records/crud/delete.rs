@@ -1,16 +1,30 @@
-use crate::Collection;
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::error::{ApiErrorResponse, RawResponse, RecordIdError};
+use crate::options::{RequestOptions, cancellable};
+use crate::{Collection, PocketBase, circuit_breaker, validate_record_id};
 use thiserror::Error;
 
+/// Represents the various errors that can be obtained after a `delete` request.
 #[derive(Error, Debug)]
 pub enum DeleteError {
     /// Communication with the `PocketBase` API was successful,
     /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response.
     ///
-    /// Failed to delete record. Make sure that the record is not part of a required relation reference. `PocketBase`.
-    #[error(
-        "Failed to delete record. Make sure that the record is not part of a required relation reference."
-    )]
-    BadRequest,
+    /// Failed to delete record. Make sure that the record is not part of a required relation reference.
+    ///
+    /// The payload returned by `PocketBase` is kept, as hooks can reject the deletion with their own message.
+    #[error("Failed to delete record: {0}")]
+    BadRequest(ApiErrorResponse),
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [401 Unauthorized]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/401") HTTP error response.
+    ///
+    /// The request requires a valid Authorization Token.
+    #[error("The request requires a valid Authorization Token.")]
+    Unauthorized,
     /// Communication with the `PocketBase` API was successful,
     /// but returned a [403 Forbidden]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/403") HTTP error response.
     ///
@@ -23,17 +37,48 @@ pub enum DeleteError {
     /// The requested resource wasn't found.
     #[error("The requested resource wasn't found.")]
     NotFound,
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [429 Too Many Requests]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/429") HTTP error response.
+    ///
+    /// The server is rate limiting requests. Wait before retrying.
+    #[error("The server is rate limiting requests. Please wait before retrying.")]
+    TooManyRequests,
+    /// The given record id can't be valid, the request was not sent.
+    #[error("Invalid record id: {0}")]
+    InvalidRecordId(RecordIdError),
     /// Communication with the `PocketBase` API failed.
     ///
     /// This could be caused by an internet outage, an error in the link given to the `PocketBase` SDK
     /// and similar errors.
     #[error("The communication with the PocketBase API failed: {0}")]
     Unreachable(String),
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    ///
+    /// See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
+    /// The request was cancelled through its cancellation token.
+    #[error("The request was cancelled.")]
+    Cancelled,
     /// An unexpected error occurred.
     /// The response from the `PocketBase` instance API was unexpected.
     /// If you think its an error, please [open an issue on GitHub]("https://github.com/fromhorizons/pocketbase-rs/issues").
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
     #[error("An unhandled status code was returned by the PocketBase API: {0}")]
-    UnexpectedResponse(String),
+    UnexpectedResponse(RawResponse),
+}
+
+/// Builder for deleting a single record.
+///
+/// The builder can be awaited directly, or executed through [`CollectionDeleteBuilder::call()`].
+pub struct CollectionDeleteBuilder<'a> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    record_id: &'a str,
+    missing_ok: bool,
+    query_params: Vec<(&'a str, &'a str)>,
+    options: RequestOptions<'a>,
 }
 
 impl<'a> Collection<'a> {
@@ -45,30 +90,131 @@ impl<'a> Collection<'a> {
     ///     .delete("RECORD_ID")
     ///     .await?;
     /// ```
-    pub async fn delete(&self, record_id: &'a str) -> Result<(), DeleteError> {
-        // Validate record_id
-        if record_id.is_empty() {
-            return Err(DeleteError::BadRequest);
+    #[must_use]
+    pub const fn delete(&self, record_id: &'a str) -> CollectionDeleteBuilder<'a> {
+        CollectionDeleteBuilder {
+            client: self.client,
+            collection_name: self.name,
+            record_id,
+            missing_ok: false,
+            query_params: Vec::new(),
+            options: RequestOptions::new(),
         }
+    }
+}
+
+impl<'a> CollectionDeleteBuilder<'a> {
+    /// Treat a missing record (`404 Not Found`) as a successful deletion.
+    ///
+    /// Useful for idempotent cleanup jobs.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .missing_ok(true)
+    /// ```
+    pub const fn missing_ok(mut self, missing_ok: bool) -> Self {
+        self.missing_ok = missing_ok;
+        self
+    }
+
+    /// Append a custom query parameter to the request.
+    ///
+    /// Useful for passing parameters read by `PocketBase` hooks.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query_param("cascade", "true")
+    /// ```
+    pub fn query_param(mut self, key: &'a str, value: &'a str) -> Self {
+        self.query_params.push((key, value));
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<(), DeleteError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            DeleteError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<(), DeleteError> {
+        validate_record_id(self.record_id).map_err(DeleteError::InvalidRecordId)?;
 
         let endpoint = format!(
             "{}/api/collections/{}/records/{}",
-            self.client.base_url, self.name, record_id
+            self.client.base_url, self.collection_name, self.record_id
         );
-        let request = self.client.request_delete(&endpoint).send().await;
+        let request = self
+            .client
+            .send_request(
+                self.options.apply(
+                    self.client
+                        .request_delete(&endpoint)
+                        .query(&self.query_params),
+                ),
+            )
+            .await;
 
         match request {
             Ok(response) => match response.status() {
+                _ if circuit_breaker::is_open_response(&response) => Err(DeleteError::CircuitOpen),
                 reqwest::StatusCode::NO_CONTENT | reqwest::StatusCode::OK => Ok(()),
-                reqwest::StatusCode::BAD_REQUEST => Err(DeleteError::BadRequest),
+                reqwest::StatusCode::NOT_FOUND if self.missing_ok => Ok(()),
+                reqwest::StatusCode::BAD_REQUEST => Err(DeleteError::BadRequest(
+                    ApiErrorResponse::from_response(response).await,
+                )),
+                reqwest::StatusCode::UNAUTHORIZED => Err(DeleteError::Unauthorized),
                 reqwest::StatusCode::FORBIDDEN => Err(DeleteError::Forbidden),
                 reqwest::StatusCode::NOT_FOUND => Err(DeleteError::NotFound),
-                _ => Err(DeleteError::UnexpectedResponse(format!(
-                    "Status: {}, Collection: {}, Record: {}",
-                    response.status(),
-                    self.name,
-                    record_id
-                ))),
+                reqwest::StatusCode::TOO_MANY_REQUESTS => Err(DeleteError::TooManyRequests),
+                _ => Err(DeleteError::UnexpectedResponse(
+                    RawResponse::from_response(response).await,
+                )),
             },
             Err(e) => {
                 if e.is_timeout() {
@@ -84,3 +230,60 @@ impl<'a> Collection<'a> {
         }
     }
 }
+
+impl<'a> IntoFuture for CollectionDeleteBuilder<'a> {
+    type Output = Result<(), DeleteError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use super::DeleteError;
+    use crate::test_utils::assert_error_statuses;
+    use crate::{CancellationToken, PocketBase};
+
+    #[tokio::test]
+    async fn error_statuses() {
+        assert_error_statuses!(
+            DELETE,
+            "/api/collections/articles/records/abc123",
+            |pb| pb.collection("articles").delete("abc123").call(),
+            {
+                401 => DeleteError::Unauthorized,
+                429 => DeleteError::TooManyRequests,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelled() {
+        let server = MockServer::start_async().await;
+
+        let endpoint = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/api/collections/articles/records/abc123");
+            then.status(204);
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let cancellation_token = CancellationToken::new();
+        cancellation_token.cancel();
+
+        let result = pb
+            .collection("articles")
+            .delete("abc123")
+            .cancellation_token(cancellation_token)
+            .call()
+            .await;
+
+        assert!(matches!(result, Err(DeleteError::Cancelled)));
+        assert_eq!(endpoint.hits(), 0);
+    }
+}
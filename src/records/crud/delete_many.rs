@@ -0,0 +1,93 @@
+use futures_util::{StreamExt, stream};
+
+use crate::error::DeleteError;
+use crate::{Collection, PocketBase};
+
+/// Builder for deleting several records concurrently.
+pub struct CollectionDeleteManyBuilder<'a> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    record_ids: Vec<&'a str>,
+    concurrency: usize,
+    missing_ok: bool,
+}
+
+impl<'a> Collection<'a> {
+    /// Delete several records, sending up to `concurrency` requests in parallel (default: 8).
+    ///
+    /// A failed deletion doesn't stop the other ones: the result of each deletion is
+    /// returned along with its record id, in the order of the given ids.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let results = pb
+    ///     .collection("articles")
+    ///     .delete_many(["RECORD_ID_1", "RECORD_ID_2", "RECORD_ID_3"])
+    ///     .concurrency(16)
+    ///     .missing_ok(true)
+    ///     .call()
+    ///     .await;
+    ///
+    /// for (record_id, result) in results {
+    ///     if let Err(error) = result {
+    ///         eprintln!("Failed to delete {record_id}: {error}");
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn delete_many(
+        &self,
+        record_ids: impl IntoIterator<Item = &'a str>,
+    ) -> CollectionDeleteManyBuilder<'a> {
+        CollectionDeleteManyBuilder {
+            client: self.client,
+            collection_name: self.name,
+            record_ids: record_ids.into_iter().collect(),
+            concurrency: 8,
+            missing_ok: false,
+        }
+    }
+}
+
+impl<'a> CollectionDeleteManyBuilder<'a> {
+    /// Send up to `concurrency` requests in parallel (default: 8).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .concurrency(16)
+    /// ```
+    pub const fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = if concurrency == 0 { 1 } else { concurrency };
+        self
+    }
+
+    /// Treat missing records (`404 Not Found`) as successful deletions.
+    ///
+    /// Useful for idempotent cleanup jobs.
+    pub const fn missing_ok(mut self, missing_ok: bool) -> Self {
+        self.missing_ok = missing_ok;
+        self
+    }
+
+    /// Execute the requests and return the result of each deletion, in the order of the ids.
+    pub async fn call(self) -> Vec<(&'a str, Result<(), DeleteError>)> {
+        let collection = Collection {
+            client: self.client,
+            name: self.collection_name,
+        };
+
+        stream::iter(self.record_ids)
+            .map(|record_id| async move {
+                let result = collection
+                    .delete(record_id)
+                    .missing_ok(self.missing_ok)
+                    .call()
+                    .await;
+
+                (record_id, result)
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
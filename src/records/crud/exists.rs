@@ -0,0 +1,139 @@
+use serde::de::IgnoredAny;
+use tokio_util::sync::CancellationToken;
+
+use crate::Collection;
+use crate::error::RequestError;
+use crate::records::crud::get_list::CollectionGetListBuilder;
+use crate::records::crud::get_one::CollectionGetOneBuilder;
+
+/// Builder for checking whether a record exists.
+pub struct CollectionExistsBuilder<'a> {
+    lookup: Lookup<'a>,
+}
+
+enum Lookup<'a> {
+    Id(CollectionGetOneBuilder<'a, IgnoredAny>),
+    Filter(CollectionGetListBuilder<'a, IgnoredAny>),
+}
+
+impl<'a> Collection<'a> {
+    /// Check whether a record with the given id exists.
+    ///
+    /// A `404 Not Found` response is returned as `false` rather than an error. Note that
+    /// `PocketBase` also answers `404` for records hidden by the view rule of the collection.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// if !pb.collection("articles").exists("record_id_123").call().await? {
+    ///     println!("The article has been deleted");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn exists(self, record_id: &'a str) -> CollectionExistsBuilder<'a> {
+        CollectionExistsBuilder {
+            lookup: Lookup::Id(
+                self.get_one::<IgnoredAny>(record_id)
+                    .query_param("fields", "id"),
+            ),
+        }
+    }
+
+    /// Check whether at least one record matches the given filter.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let already_imported = pb
+    ///     .collection("articles")
+    ///     .exists_where("slug='hello-world'")
+    ///     .call()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn exists_where(self, filter: &'a str) -> CollectionExistsBuilder<'a> {
+        CollectionExistsBuilder {
+            lookup: Lookup::Filter(
+                self.get_list::<IgnoredAny>()
+                    .filter(filter)
+                    .per_page(1)
+                    .skip_total(true)
+                    .query_param("fields", "id"),
+            ),
+        }
+    }
+}
+
+impl<'a> CollectionExistsBuilder<'a> {
+    /// Append a custom query parameter to the request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query_param("tenant", "acme")
+    /// ```
+    #[must_use]
+    pub fn query_param(mut self, key: &'a str, value: &'a str) -> Self {
+        self.lookup = match self.lookup {
+            Lookup::Id(builder) => Lookup::Id(builder.query_param(key, value)),
+            Lookup::Filter(builder) => Lookup::Filter(builder.query_param(key, value)),
+        };
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    #[must_use]
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.lookup = match self.lookup {
+            Lookup::Id(builder) => Lookup::Id(builder.timeout(timeout)),
+            Lookup::Filter(builder) => Lookup::Filter(builder.timeout(timeout)),
+        };
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    #[must_use]
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.lookup = match self.lookup {
+            Lookup::Id(builder) => Lookup::Id(builder.header(key, value)),
+            Lookup::Filter(builder) => Lookup::Filter(builder.header(key, value)),
+        };
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    #[must_use]
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.lookup = match self.lookup {
+            Lookup::Id(builder) => Lookup::Id(builder.cancellation_token(cancellation_token)),
+            Lookup::Filter(builder) => {
+                Lookup::Filter(builder.cancellation_token(cancellation_token))
+            }
+        };
+        self
+    }
+
+    /// Execute the request and return whether the record exists.
+    pub async fn call(self) -> Result<bool, RequestError> {
+        match self.lookup {
+            Lookup::Id(builder) => match builder.call().await {
+                Ok(_) => Ok(true),
+                Err(RequestError::NotFound) => Ok(false),
+                Err(error) => Err(error),
+            },
+            Lookup::Filter(builder) => Ok(!builder.call().await?.items.is_empty()),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use futures_util::TryStreamExt;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use thiserror::Error;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::error::RequestError;
+use crate::records::crud::get_full_list::CollectionGetFullListBuilder;
+
+/// Represents the various errors that can be obtained while exporting records.
+#[derive(Error, Debug)]
+pub enum ExportError {
+    /// A page of records could not be fetched.
+    ///
+    /// The records of the previous pages were already written.
+    #[error("Failed to fetch the records to export: {0}")]
+    Request(#[from] RequestError),
+    /// The records could not be written to the given writer.
+    #[error("Failed to write the exported records: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl<T: DeserializeOwned + Send> CollectionGetFullListBuilder<'_, T> {
+    /// Write the matching records to `writer` as newline-delimited JSON, one record per line.
+    ///
+    /// Pages are fetched one at a time and written as soon as they are received, so at most
+    /// `batch_size` records are held in memory. The records are written as returned by
+    /// `PocketBase`, regardless of `T`.
+    ///
+    /// Returns the amount of records written. The writer is flushed, but not shut down.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut file = tokio::fs::File::create("articles.ndjson").await?;
+    ///
+    /// let count = pb
+    ///     .collection("articles")
+    ///     .get_full_list::<IgnoredAny>()
+    ///     .sort("created")
+    ///     .export_ndjson(&mut file)
+    ///     .await?;
+    ///
+    /// println!("Exported {count} articles");
+    /// ```
+    pub async fn export_ndjson<W: AsyncWrite + Unpin + Send>(
+        self,
+        writer: &mut W,
+    ) -> Result<usize, ExportError> {
+        let mut pages = std::pin::pin!(self.pages::<Value>());
+        let mut count = 0;
+
+        while let Some(records) = pages.try_next().await? {
+            let mut buffer = Vec::new();
+
+            for record in &records {
+                serde_json::to_writer(&mut buffer, record).map_err(std::io::Error::from)?;
+                buffer.push(b'\n');
+            }
+
+            writer.write_all(&buffer).await?;
+            count += records.len();
+        }
+
+        writer.flush().await?;
+
+        Ok(count)
+    }
+
+    /// Write the matching records to `writer` as CSV, with a header row.
+    ///
+    /// The columns are the fields of the first record, in alphabetical order, so restricting them with
+    /// `.query_param("fields", ...)` keeps the export stable. Text values are written as-is,
+    /// `null` values as empty cells, and the other values (e.g. multiple relations or `json`
+    /// fields) as JSON. Like [`export_ndjson()`](Self::export_ndjson), pages are written as
+    /// soon as they are received.
+    ///
+    /// Returns the amount of records written. The writer is flushed, but not shut down.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut file = tokio::fs::File::create("articles.csv").await?;
+    ///
+    /// pb.collection("articles")
+    ///     .get_full_list::<IgnoredAny>()
+    ///     .query_param("fields", "id,title,created")
+    ///     .export_csv(&mut file)
+    ///     .await?;
+    /// ```
+    pub async fn export_csv<W: AsyncWrite + Unpin + Send>(
+        self,
+        writer: &mut W,
+    ) -> Result<usize, ExportError> {
+        let mut pages = std::pin::pin!(self.pages::<Map<String, Value>>());
+        let mut columns: Option<Vec<String>> = None;
+        let mut count = 0;
+
+        while let Some(records) = pages.try_next().await? {
+            let mut buffer = String::new();
+
+            for record in &records {
+                let columns = columns.get_or_insert_with(|| {
+                    let columns: Vec<String> = record.keys().cloned().collect();
+                    write_csv_row(&mut buffer, columns.iter());
+                    columns
+                });
+
+                write_csv_row(
+                    &mut buffer,
+                    columns.iter().map(|column| csv_cell(record.get(column))),
+                );
+            }
+
+            writer.write_all(buffer.as_bytes()).await?;
+            count += records.len();
+        }
+
+        writer.flush().await?;
+
+        Ok(count)
+    }
+}
+
+/// Returns the text of a CSV cell.
+fn csv_cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(text)) => text.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+/// Appends a CSV row, quoting the cells as described by RFC 4180.
+fn write_csv_row<S: AsRef<str>>(buffer: &mut String, cells: impl Iterator<Item = S>) {
+    for (index, cell) in cells.enumerate() {
+        let cell = cell.as_ref();
+
+        if index > 0 {
+            buffer.push(',');
+        }
+
+        if cell.contains([',', '"', '\r', '\n']) {
+            buffer.push('"');
+            buffer.push_str(&cell.replace('"', "\"\""));
+            buffer.push('"');
+        } else {
+            buffer.push_str(cell);
+        }
+    }
+
+    buffer.push_str("\r\n");
+}
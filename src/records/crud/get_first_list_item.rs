@@ -1,8 +1,11 @@
 use serde::{Deserialize, de::DeserializeOwned};
+use tokio_util::sync::CancellationToken;
 
 use crate::PocketBase;
 use crate::error::RequestError;
-use crate::{Collection, RecordList};
+use crate::options::{RequestKey, RequestOptions, cancellable};
+use crate::records::crud::list_options::ListOptions;
+use crate::{ApiResponse, Collection, RecordList};
 
 pub struct CollectionGetFirstListItemBuilder<'a, T: Send + Deserialize<'a>> {
     client: &'a PocketBase,
@@ -10,7 +13,9 @@ pub struct CollectionGetFirstListItemBuilder<'a, T: Send + Deserialize<'a>> {
     sort: Option<&'a str>,
     expand: Option<&'a str>,
     filter: Option<&'a str>,
+    query_params: Vec<(&'a str, &'a str)>,
     _marker: std::marker::PhantomData<T>,
+    options: RequestOptions<'a>,
 }
 
 impl<'a> Collection<'a> {
@@ -18,7 +23,7 @@ impl<'a> Collection<'a> {
     ///
     /// # Example
     /// ```rust,ignore
-    /// #[derive(Default, Deserialize, Clone)]
+    /// #[derive(Deserialize)]
     /// struct Article {
     ///     id: String,
     ///     title: String,
@@ -34,7 +39,7 @@ impl<'a> Collection<'a> {
     ///     .await?;
     /// ```
     #[must_use]
-    pub const fn get_first_list_item<T: Default + DeserializeOwned + Clone + Send>(
+    pub const fn get_first_list_item<T: DeserializeOwned + Send>(
         self,
     ) -> CollectionGetFirstListItemBuilder<'a, T> {
         CollectionGetFirstListItemBuilder {
@@ -43,12 +48,14 @@ impl<'a> Collection<'a> {
             sort: None,
             expand: None,
             filter: None,
+            query_params: Vec::new(),
+            options: RequestOptions::new(),
             _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetFirstListItemBuilder<'a, T> {
+impl<'a, T: DeserializeOwned + Send> CollectionGetFirstListItemBuilder<'a, T> {
     /// Set the sort order. Prefix with `-` for DESC or `+` for ASC (default).
     ///
     /// # Example
@@ -89,8 +96,160 @@ impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetFirstListIte
         self
     }
 
+    /// Append a custom query parameter to the request.
+    ///
+    /// Useful for passing parameters read by `PocketBase` hooks, or route
+    /// parameters not yet covered by this builder.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query_param("tenant", "acme")
+    /// ```
+    pub fn query_param(mut self, key: &'a str, value: &'a str) -> Self {
+        self.query_params.push((key, value));
+        self
+    }
+
+    /// Apply the given [`ListOptions`], on top of the options already set.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .list_options(&options)
+    /// ```
+    pub fn list_options(mut self, options: &'a ListOptions) -> Self {
+        self.sort = options.sort.as_deref().or(self.sort);
+        self.filter = options.filter.as_deref().or(self.filter);
+        self.expand = options.expand.as_deref().or(self.expand);
+        self.query_params.extend(options.query_params());
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Serve the response from the client cache if it was stored less than `ttl` ago.
+    ///
+    /// Outdated responses are revalidated with a conditional request when `PocketBase`
+    /// returned an `ETag`. The cache is shared by the clones of the client, see
+    /// [`PocketBase::clear_cache()`](crate::PocketBase::clear_cache).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .cache(Duration::from_secs(60))
+    /// ```
+    pub const fn cache(mut self, ttl: std::time::Duration) -> Self {
+        self.options.cache = Some(ttl);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Cancel the pending request with the same key when sending this request, and let a
+    /// newer request with the same key cancel this one, making it return a `Cancelled` error.
+    ///
+    /// Without a key, the method and URL of the request are used when auto-cancellation is
+    /// enabled on the client, see [`PocketBase::set_auto_cancellation()`](crate::PocketBase::set_auto_cancellation).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .request_key("articles-search")
+    /// ```
+    pub const fn request_key(mut self, key: &'a str) -> Self {
+        self.options.request_key = RequestKey::Custom(key);
+        self
+    }
+
+    /// Never auto-cancel this request, even if auto-cancellation is enabled on the client.
+    pub const fn no_auto_cancel(mut self) -> Self {
+        self.options.request_key = RequestKey::Disabled;
+        self
+    }
+
     /// Execute the request and return the first matching record.
+    ///
+    /// Returns [`RequestError::NotFound`] when no record matches.
     pub async fn call(self) -> Result<T, RequestError> {
+        self.call_with_response()
+            .await
+            .map(|response| response.data)
+    }
+
+    /// Execute the request and return the first matching record, or `None` when no record matches.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let article = pb
+    ///     .collection("articles")
+    ///     .get_first_list_item::<Article>()
+    ///     .filter("slug='hello-world'")
+    ///     .call_opt()
+    ///     .await?;
+    ///
+    /// if article.is_none() {
+    ///     println!("No such article");
+    /// }
+    /// ```
+    pub async fn call_opt(self) -> Result<Option<T>, RequestError> {
+        match self.call().await {
+            Ok(record) => Ok(Some(record)),
+            Err(RequestError::NotFound) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Execute the request and return the first matching record, along with the response status and headers.
+    ///
+    /// The headers of an unsuccessful response are kept in the error when available, see
+    /// [`RequestError::headers()`].
+    pub async fn call_with_response(self) -> Result<ApiResponse<T>, RequestError> {
+        let (cancellation_token, _pending) =
+            self.options.cancellation(self.client, || self.request());
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    /// Builds the request.
+    fn request(&self) -> reqwest::RequestBuilder {
         let url = format!(
             "{}/api/collections/{}/records",
             self.client.base_url, self.collection_name
@@ -111,38 +270,70 @@ impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetFirstListIte
             query_parameters.push(("expand", expand));
         }
 
+        query_parameters.extend(self.query_params.iter().copied());
+
+        self.options
+            .apply(self.client.request_get(&url, Some(query_parameters)))
+    }
+
+    async fn execute(self) -> Result<ApiResponse<T>, RequestError> {
         let request = self
             .client
-            .request_get(&url, Some(query_parameters))
-            .send()
+            .send_cached(self.options.cache, self.request())
             .await;
 
         let response = match request {
-            Ok(response) => response
-                .error_for_status()
-                .map_err(|err| match err.status() {
-                    Some(reqwest::StatusCode::FORBIDDEN) => RequestError::Forbidden,
-                    Some(reqwest::StatusCode::NOT_FOUND) => RequestError::NotFound,
-                    _ => RequestError::Unhandled,
-                })?,
-            Err(error) => {
-                return Err(match error.status() {
-                    Some(reqwest::StatusCode::FORBIDDEN) => RequestError::Forbidden,
-                    Some(reqwest::StatusCode::NOT_FOUND) => RequestError::NotFound,
-                    _ => RequestError::Unhandled,
-                });
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                return Err(RequestError::from_response(response)
+                    .await
+                    .with_filter(self.filter));
             }
+            Err(error) => return Err(error.into()),
         };
 
+        let status = response.status();
+        let headers = response.headers().clone();
+
         // Parse JSON response
         let records = response
             .json::<RecordList<T>>()
             .await
             .map_err(|error| RequestError::ParseError(error.to_string()))?;
 
-        records.items.first().map_or_else(
-            || Err(RequestError::ParseError("No record found.".to_owned())),
-            |record| Ok(record.clone()),
-        )
+        // Like the JS SDK, an empty page is reported as a 404 error
+        records
+            .items
+            .into_iter()
+            .next()
+            .map_or(Err(RequestError::NotFound), |record| {
+                Ok(ApiResponse {
+                    status,
+                    headers,
+                    data: record,
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::Value;
+
+    use crate::error::RequestError;
+    use crate::test_utils::assert_error_statuses;
+
+    #[tokio::test]
+    async fn error_statuses() {
+        assert_error_statuses!(
+            GET,
+            "/api/collections/articles/records",
+            |pb| pb.collection("articles").get_first_list_item::<Value>().filter("slug='hello'").call(),
+            {
+                401 => RequestError::Unauthorized,
+                429 => RequestError::TooManyRequests,
+            }
+        );
     }
 }
@@ -1,17 +1,36 @@
+use futures_util::{Stream, StreamExt, TryStreamExt, stream};
 use serde::de::DeserializeOwned;
+use tokio_util::sync::CancellationToken;
 
 use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+use crate::records::crud::list_options::ListOptions;
 use crate::{Collection, RecordList};
 
+/// The records fetched by [`CollectionGetFullListBuilder::call_partial()`] before a page failed.
+#[derive(Debug)]
+pub struct PartialList<T> {
+    /// The records of the pages fetched before the failed one, in order.
+    pub records: Vec<T>,
+    /// The page that failed, to resume from with [`CollectionGetFullListBuilder::start_page()`].
+    pub failed_page: u32,
+    /// The error returned for the failed page.
+    pub error: RequestError,
+}
+
 /// Builder for fetching all records from a collection.
 pub struct CollectionGetFullListBuilder<'a, T: Send> {
     client: &'a crate::PocketBase,
     collection_name: &'a str,
     batch_size: u16,
+    start_page: u32,
+    concurrency: usize,
     sort: Option<&'a str>,
     expand: Option<&'a str>,
     filter: Option<&'a str>,
-    _marker: std::marker::PhantomData<T>,
+    query_params: Vec<(&'a str, &'a str)>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+    options: RequestOptions<'a>,
 }
 
 impl<'a> Collection<'a> {
@@ -22,7 +41,7 @@ impl<'a> Collection<'a> {
     ///
     /// # Example
     /// ```rust,ignore
-    /// #[derive(Default, Deserialize, Clone)]
+    /// #[derive(Deserialize)]
     /// struct Article {
     ///     id: String,
     ///     title: String,
@@ -39,27 +58,58 @@ impl<'a> Collection<'a> {
     /// println!("Total articles: {}", all_articles.len());
     /// ```
     #[must_use]
-    pub const fn get_full_list<T: Default + DeserializeOwned + Clone + Send>(
+    pub const fn get_full_list<T: DeserializeOwned + Send>(
         self,
     ) -> CollectionGetFullListBuilder<'a, T> {
         CollectionGetFullListBuilder {
             client: self.client,
             collection_name: self.name,
             batch_size: 500, // Maximum allowed by PocketBase
+            start_page: 1,
+            concurrency: 1,
             sort: None,
             expand: None,
             filter: None,
+            query_params: Vec::new(),
+            options: RequestOptions::new(),
             _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetFullListBuilder<'a, T> {
-    /// Set the batch size for pagination (default: 500, max: 500).
+impl<'a, T: DeserializeOwned + Send> CollectionGetFullListBuilder<'a, T> {
+    /// Set the batch size for pagination (default: 500, between 1 and 500).
     ///
     /// Lower values reduce memory usage but increase request count.
     pub fn batch_size(mut self, size: u16) -> Self {
-        self.batch_size = size.min(500); // Ensure we don't exceed PocketBase's limit
+        self.batch_size = size.clamp(1, 500); // Ensure we stay within PocketBase's limits
+        self
+    }
+
+    /// Start fetching from the given page (default: 1), e.g. to resume from the
+    /// [`PartialList::failed_page`] of a previous call, with the same batch size.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .start_page(partial.failed_page)
+    /// ```
+    pub const fn start_page(mut self, start_page: u32) -> Self {
+        self.start_page = if start_page == 0 { 1 } else { start_page };
+        self
+    }
+
+    /// Fetch up to `concurrency` pages in parallel (default: 1).
+    ///
+    /// When greater than 1, the first page is fetched with the total count so that
+    /// the remaining pages can be requested concurrently. Records are still returned
+    /// in order. Only applies to [`call()`](Self::call) and [`call_partial()`](Self::call_partial).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .concurrency(4)
+    /// ```
+    pub const fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = if concurrency == 0 { 1 } else { concurrency };
         self
     }
 
@@ -103,74 +153,180 @@ impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetFullListBuil
         self
     }
 
+    /// Append a custom query parameter to the request.
+    ///
+    /// Useful for passing parameters read by `PocketBase` hooks, or route
+    /// parameters not yet covered by this builder.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query_param("tenant", "acme")
+    /// ```
+    pub fn query_param(mut self, key: &'a str, value: &'a str) -> Self {
+        self.query_params.push((key, value));
+        self
+    }
+
+    /// Apply the given [`ListOptions`], on top of the options already set.
+    ///
+    /// `page` is the first page to fetch and `per_page` the batch size (clamped between 1 and 500).
+    /// `skip_total` is ignored, as the total count is only queried when needed (see
+    /// [`concurrency()`](Self::concurrency)).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .list_options(&options)
+    /// ```
+    pub fn list_options(mut self, options: &'a ListOptions) -> Self {
+        if let Some(page) = options.page {
+            self = self.start_page(u32::from(page));
+        }
+
+        if let Some(per_page) = options.per_page {
+            self = self.batch_size(per_page);
+        }
+
+        self.sort = options.sort.as_deref().or(self.sort);
+        self.filter = options.filter.as_deref().or(self.filter);
+        self.expand = options.expand.as_deref().or(self.expand);
+        self.query_params.extend(options.query_params());
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Serve the response from the client cache if it was stored less than `ttl` ago.
+    ///
+    /// Outdated responses are revalidated with a conditional request when `PocketBase`
+    /// returned an `ETag`. The cache is shared by the clones of the client, see
+    /// [`PocketBase::clear_cache()`](crate::PocketBase::clear_cache).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .cache(Duration::from_secs(60))
+    /// ```
+    pub const fn cache(mut self, ttl: std::time::Duration) -> Self {
+        self.options.cache = Some(ttl);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
     /// Execute the request and return all matching records.
     ///
     /// Automatically handles pagination by making multiple requests if needed.
     pub async fn call(self) -> Result<Vec<T>, RequestError> {
-        let mut all_records = Vec::new();
-        let mut page = 1u32;
-        let batch_size_str = self.batch_size.to_string();
+        let cancellation_token = self.options.cancellation_token.clone();
 
-        loop {
-            let url = format!(
-                "{}/api/collections/{}/records",
-                self.client.base_url, self.collection_name
-            );
-
-            let page_str = page.to_string();
-            let mut query_parameters: Vec<(&str, &str)> = vec![
-                ("page", &page_str),
-                ("perPage", &batch_size_str),
-                ("skipTotal", "true"),
-            ];
-
-            if let Some(sort) = self.sort {
-                query_parameters.push(("sort", sort));
-            }
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
 
-            if let Some(filter) = self.filter {
-                query_parameters.push(("filter", filter));
-            }
+    /// Execute the request and return all matching records, or the records fetched
+    /// before the first failed page along with the error.
+    ///
+    /// Useful for long exports, which can resume from the failed page instead of starting over.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let articles = match pb
+    ///     .collection("articles")
+    ///     .get_full_list::<Article>()
+    ///     .call_partial()
+    ///     .await
+    /// {
+    ///     Ok(articles) => articles,
+    ///     Err(partial) => {
+    ///         eprintln!("Page {} failed: {}", partial.failed_page, partial.error);
+    ///
+    ///         let mut articles = partial.records;
+    ///         articles.extend(
+    ///             pb.collection("articles")
+    ///                 .get_full_list::<Article>()
+    ///                 .start_page(partial.failed_page)
+    ///                 .call()
+    ///                 .await?,
+    ///         );
+    ///         articles
+    ///     }
+    /// };
+    /// ```
+    pub async fn call_partial(self) -> Result<Vec<T>, PartialList<T>> {
+        let cancellation_token = self.options.cancellation_token.clone();
+        let start_page = self.start_page;
 
-            if let Some(expand) = self.expand {
-                query_parameters.push(("expand", expand));
-            }
+        cancellable(cancellation_token, self.fetch_all(), || PartialList {
+            records: Vec::new(),
+            failed_page: start_page,
+            error: RequestError::Cancelled,
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<Vec<T>, RequestError> {
+        self.fetch_all().await.map_err(|partial| partial.error)
+    }
 
-            let request = self
-                .client
-                .request_get(&url, Some(query_parameters))
-                .send()
-                .await;
-
-            let response = match request {
-                Ok(response) => response
-                    .error_for_status()
-                    .map_err(|err| match err.status() {
-                        Some(reqwest::StatusCode::FORBIDDEN) => RequestError::Forbidden,
-                        Some(reqwest::StatusCode::NOT_FOUND) => RequestError::NotFound,
-                        Some(reqwest::StatusCode::UNAUTHORIZED) => RequestError::Unauthorized,
-                        _ => RequestError::Unhandled,
-                    })?,
+    async fn fetch_all(&self) -> Result<Vec<T>, PartialList<T>> {
+        if self.concurrency > 1 {
+            return self.fetch_all_concurrently().await;
+        }
+
+        let mut all_records = Vec::new();
+        let mut page = self.start_page;
+
+        loop {
+            let records_page = match self.fetch_page(page, true).await {
+                Ok(records_page) => records_page,
                 Err(error) => {
-                    return Err(if error.is_timeout() || error.is_connect() {
-                        RequestError::Unreachable
-                    } else {
-                        match error.status() {
-                            Some(reqwest::StatusCode::FORBIDDEN) => RequestError::Forbidden,
-                            Some(reqwest::StatusCode::NOT_FOUND) => RequestError::NotFound,
-                            Some(reqwest::StatusCode::UNAUTHORIZED) => RequestError::Unauthorized,
-                            _ => RequestError::Unhandled,
-                        }
+                    return Err(PartialList {
+                        records: all_records,
+                        failed_page: page,
+                        error,
                     });
                 }
             };
 
-            // Parse JSON response
-            let records_page = response
-                .json::<RecordList<T>>()
-                .await
-                .map_err(|error| RequestError::ParseError(error.to_string()))?;
-
             let items_count = records_page.items.len();
             all_records.extend(records_page.items);
 
@@ -186,4 +342,219 @@ impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetFullListBuil
 
         Ok(all_records)
     }
+
+    /// Execute the request and return the matching records as a stream.
+    ///
+    /// Pages are fetched lazily, one at a time, as the stream is consumed: at most
+    /// `batch_size` records are held in memory. The stream ends after the first error.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut articles = pb
+    ///     .collection("articles")
+    ///     .get_full_list::<Article>()
+    ///     .stream();
+    ///
+    /// while let Some(article) = articles.try_next().await? {
+    ///     println!("{article:?}");
+    /// }
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = Result<T, RequestError>> + Send + 'a
+    where
+        T: 'a,
+    {
+        let start_page = self.start_page;
+
+        stream::try_unfold((self, Some(start_page)), |(builder, page)| async move {
+            let Some(page) = page else {
+                return Ok::<_, RequestError>(None);
+            };
+
+            let records_page = builder.fetch_page(page, true).await?;
+
+            let next_page = if records_page.items.len() < builder.batch_size as usize {
+                None
+            } else {
+                Some(page + 1)
+            };
+
+            let items = stream::iter(records_page.items.into_iter().map(Ok));
+
+            Ok(Some((items, (builder, next_page))))
+        })
+        .try_flatten()
+    }
+
+    /// Returns the pages of matching records as a stream, fetching them one at a time.
+    ///
+    /// The stream ends after the first error.
+    pub(crate) fn pages<R: DeserializeOwned>(
+        &self,
+    ) -> impl Stream<Item = Result<Vec<R>, RequestError>> + Send + '_ {
+        stream::try_unfold(Some(self.start_page), move |page| async move {
+            let Some(page) = page else {
+                return Ok(None);
+            };
+
+            let records_page = self.fetch_page::<R>(page, true).await?;
+
+            let next_page = if records_page.items.len() < self.batch_size as usize {
+                None
+            } else {
+                Some(page + 1)
+            };
+
+            Ok(Some((records_page.items, next_page)))
+        })
+    }
+
+    async fn fetch_all_concurrently(&self) -> Result<Vec<T>, PartialList<T>> {
+        // The first page gives us the total amount of pages to fetch
+        let first_page = match self.fetch_page(self.start_page, false).await {
+            Ok(first_page) => first_page,
+            Err(error) => {
+                return Err(PartialList {
+                    records: Vec::new(),
+                    failed_page: self.start_page,
+                    error,
+                });
+            }
+        };
+
+        let total_pages = u32::try_from(first_page.total_pages).unwrap_or(0);
+
+        let mut all_records = first_page.items;
+
+        // Pages are yielded in order, so the records before a failed page are complete
+        let mut pages = stream::iter(self.start_page + 1..=total_pages)
+            .map(|page| async move { (page, self.fetch_page(page, true).await) })
+            .buffered(self.concurrency);
+
+        while let Some((page, records_page)) = pages.next().await {
+            match records_page {
+                Ok(records_page) => all_records.extend(records_page.items),
+                Err(error) => {
+                    return Err(PartialList {
+                        records: all_records,
+                        failed_page: page,
+                        error,
+                    });
+                }
+            }
+        }
+
+        Ok(all_records)
+    }
+
+    async fn fetch_page<R: DeserializeOwned>(
+        &self,
+        page: u32,
+        skip_total: bool,
+    ) -> Result<RecordList<R>, RequestError> {
+        let url = format!(
+            "{}/api/collections/{}/records",
+            self.client.base_url, self.collection_name
+        );
+
+        let page_str = page.to_string();
+        let batch_size_str = self.batch_size.to_string();
+        let mut query_parameters: Vec<(&str, &str)> =
+            vec![("page", &page_str), ("perPage", &batch_size_str)];
+
+        if skip_total {
+            query_parameters.push(("skipTotal", "true"));
+        }
+
+        if let Some(sort) = self.sort {
+            query_parameters.push(("sort", sort));
+        }
+
+        if let Some(filter) = self.filter {
+            query_parameters.push(("filter", filter));
+        }
+
+        if let Some(expand) = self.expand {
+            query_parameters.push(("expand", expand));
+        }
+
+        query_parameters.extend(self.query_params.iter().copied());
+
+        let request = self
+            .client
+            .send_cached(
+                self.options.cache,
+                self.options
+                    .apply(self.client.request_get(&url, Some(query_parameters))),
+            )
+            .await;
+
+        let response = match request {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                return Err(RequestError::from_response(response)
+                    .await
+                    .with_filter(self.filter));
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        // Parse JSON response
+        response
+            .json::<RecordList<R>>()
+            .await
+            .map_err(|error| RequestError::ParseError(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::{Value, json};
+
+    use crate::{ListOptions, PocketBase};
+
+    fn page(page: u32, items: &[Value]) -> Value {
+        json!({ "page": page, "perPage": 1, "totalItems": -1, "totalPages": -1, "items": items })
+    }
+
+    #[tokio::test]
+    async fn zero_per_page_is_clamped() {
+        let server = MockServer::start_async().await;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/collections/articles/records")
+                .query_param("page", "1")
+                .query_param("perPage", "1");
+            then.status(200).json_body(page(1, &[json!({ "id": "a" })]));
+        });
+
+        let last = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/collections/articles/records")
+                .query_param("page", "2")
+                .query_param("perPage", "1");
+            then.status(200).json_body(page(2, &[]));
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+        let options = ListOptions {
+            per_page: Some(0),
+            ..Default::default()
+        };
+
+        let records = pb
+            .collection("articles")
+            .get_full_list::<Value>()
+            .list_options(&options)
+            .call()
+            .await
+            .unwrap();
+
+        first.assert_hits(1);
+        last.assert_hits(1);
+        assert_eq!(records.len(), 1);
+    }
 }
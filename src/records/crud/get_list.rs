@@ -1,8 +1,12 @@
 use serde::{Deserialize, de::DeserializeOwned};
+use tokio_util::sync::CancellationToken;
 
 use crate::PocketBase;
 use crate::error::RequestError;
-use crate::{Collection, RecordList};
+use crate::options::{RequestKey, RequestOptions, cancellable};
+use crate::records::crud::list_options::ListOptions;
+use crate::records::raw::RawBody;
+use crate::{ApiResponse, Collection, RecordList};
 
 pub struct CollectionGetListBuilder<'a, T: Send + Deserialize<'a>> {
     client: &'a PocketBase,
@@ -13,7 +17,28 @@ pub struct CollectionGetListBuilder<'a, T: Send + Deserialize<'a>> {
     expand: Option<&'a str>,
     filter: Option<&'a str>,
     skip_total: bool,
+    query_params: Vec<(&'a str, &'a str)>,
     _marker: std::marker::PhantomData<T>,
+    options: RequestOptions<'a>,
+}
+
+// Not derived, as it would require `T: Clone`
+impl<'a, T: Send + Deserialize<'a>> Clone for CollectionGetListBuilder<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            collection_name: self.collection_name,
+            page: self.page.clone(),
+            per_page: self.per_page.clone(),
+            sort: self.sort,
+            expand: self.expand,
+            filter: self.filter,
+            skip_total: self.skip_total,
+            query_params: self.query_params.clone(),
+            _marker: std::marker::PhantomData,
+            options: self.options.clone(),
+        }
+    }
 }
 
 impl<'a> Collection<'a> {
@@ -21,7 +46,7 @@ impl<'a> Collection<'a> {
     ///
     /// # Example
     /// ```rust,ignore
-    /// #[derive(Default, Deserialize, Clone)]
+    /// #[derive(Deserialize)]
     /// struct Article {
     ///     id: String,
     ///     title: String,
@@ -40,9 +65,7 @@ impl<'a> Collection<'a> {
     /// }
     /// ```
     #[must_use]
-    pub const fn get_list<T: Default + DeserializeOwned + Clone + Send>(
-        self,
-    ) -> CollectionGetListBuilder<'a, T> {
+    pub const fn get_list<T: DeserializeOwned + Send>(self) -> CollectionGetListBuilder<'a, T> {
         CollectionGetListBuilder {
             client: self.client,
             collection_name: self.name,
@@ -52,12 +75,14 @@ impl<'a> Collection<'a> {
             expand: None,
             filter: None,
             skip_total: false,
+            query_params: Vec::new(),
+            options: RequestOptions::new(),
             _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetListBuilder<'a, T> {
+impl<'a, T: DeserializeOwned + Send> CollectionGetListBuilder<'a, T> {
     /// The page (aka. offset) of the paginated list (default to 1).
     pub fn page(mut self, page: u16) -> Self {
         self.page = Some(page.to_string());
@@ -120,8 +145,229 @@ impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetListBuilder<
         self
     }
 
+    /// Append a custom query parameter to the request.
+    ///
+    /// Useful for passing parameters read by `PocketBase` hooks, or route
+    /// parameters not yet covered by this builder.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query_param("tenant", "acme")
+    /// ```
+    pub fn query_param(mut self, key: &'a str, value: &'a str) -> Self {
+        self.query_params.push((key, value));
+        self
+    }
+
+    /// Apply the given [`ListOptions`], on top of the options already set.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .list_options(&options)
+    /// ```
+    pub fn list_options(mut self, options: &'a ListOptions) -> Self {
+        if let Some(page) = options.page {
+            self = self.page(page);
+        }
+
+        if let Some(per_page) = options.per_page {
+            self = self.per_page(per_page);
+        }
+
+        if let Some(skip_total) = options.skip_total {
+            self.skip_total = skip_total;
+        }
+
+        self.sort = options.sort.as_deref().or(self.sort);
+        self.filter = options.filter.as_deref().or(self.filter);
+        self.expand = options.expand.as_deref().or(self.expand);
+        self.query_params.extend(options.query_params());
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Serve the response from the client cache if it was stored less than `ttl` ago.
+    ///
+    /// Outdated responses are revalidated with a conditional request when `PocketBase`
+    /// returned an `ETag`. The cache is shared by the clones of the client, see
+    /// [`PocketBase::clear_cache()`](crate::PocketBase::clear_cache).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .cache(Duration::from_secs(60))
+    /// ```
+    pub const fn cache(mut self, ttl: std::time::Duration) -> Self {
+        self.options.cache = Some(ttl);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Cancel the pending request with the same key when sending this request, and let a
+    /// newer request with the same key cancel this one, making it return a `Cancelled` error.
+    ///
+    /// Without a key, the method and URL of the request are used when auto-cancellation is
+    /// enabled on the client, see [`PocketBase::set_auto_cancellation()`](crate::PocketBase::set_auto_cancellation).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .request_key("articles-search")
+    /// ```
+    pub const fn request_key(mut self, key: &'a str) -> Self {
+        self.options.request_key = RequestKey::Custom(key);
+        self
+    }
+
+    /// Never auto-cancel this request, even if auto-cancellation is enabled on the client.
+    pub const fn no_auto_cancel(mut self) -> Self {
+        self.options.request_key = RequestKey::Disabled;
+        self
+    }
+
+    /// Iterate over the pages of the list, starting from the configured page (default to 1).
+    ///
+    /// Unlike [`Collection::get_full_list()`], every page is returned with its metadata.
+    /// The iteration stops after the last page, known from `total_pages` or from a page
+    /// shorter than `per_page` (e.g. with [`Self::skip_total()`]), or after an error.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut pages = pb
+    ///     .collection("articles")
+    ///     .get_list::<Article>()
+    ///     .per_page(100)
+    ///     .paginate();
+    ///
+    /// while let Some(page) = pages.next_page().await {
+    ///     let page = page?;
+    ///
+    ///     println!("Page {}/{}: {} articles", page.page, page.total_pages, page.items.len());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn paginate(self) -> Pages<'a, T> {
+        let page = self
+            .page
+            .as_deref()
+            .and_then(|page| page.parse().ok())
+            .unwrap_or(1);
+
+        Pages {
+            builder: self,
+            page: Some(page),
+        }
+    }
+
     /// Execute the request and return the paginated results.
     pub async fn call(self) -> Result<RecordList<T>, RequestError> {
+        self.call_with_response()
+            .await
+            .map(|response| response.data)
+    }
+
+    /// Execute the request and return the paginated results, along with the response status and headers.
+    ///
+    /// The headers of an unsuccessful response are kept in the error when available, see
+    /// [`RequestError::headers()`].
+    pub async fn call_with_response(self) -> Result<ApiResponse<RecordList<T>>, RequestError> {
+        let (cancellation_token, _pending) =
+            self.options.cancellation(self.client, || self.request());
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    /// Execute the request and return the raw response body, without deserializing it.
+    ///
+    /// See [`RawBody`] to deserialize the records lazily, or into types borrowing from the body.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let raw = pb
+    ///     .collection("articles")
+    ///     .get_list::<IgnoredAny>()
+    ///     .per_page(500)
+    ///     .call_raw()
+    ///     .await?;
+    ///
+    /// for article in raw.records()?.parse_items::<ArticleRef<'_>>() {
+    ///     println!("{}", article?.title);
+    /// }
+    /// ```
+    pub async fn call_raw(self) -> Result<RawBody, RequestError> {
+        let (cancellation_token, _pending) =
+            self.options.cancellation(self.client, || self.request());
+
+        cancellable(
+            cancellation_token,
+            async move { RawBody::from_response(self.send().await?).await },
+            || RequestError::Cancelled,
+        )
+        .await
+    }
+
+    async fn execute(self) -> Result<ApiResponse<RecordList<T>>, RequestError> {
+        let response = self.send().await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        // Parse JSON response
+        let records = response
+            .json::<RecordList<T>>()
+            .await
+            .map_err(|error| RequestError::ParseError(error.to_string()))?;
+
+        Ok(ApiResponse {
+            status,
+            headers,
+            data: records,
+        })
+    }
+
+    /// Builds the request.
+    fn request(&self) -> reqwest::RequestBuilder {
         let url = format!(
             "{}/api/collections/{}/records",
             self.client.base_url, self.collection_name
@@ -149,37 +395,165 @@ impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetListBuilder<
             query_parameters.push(("expand", expand));
         }
 
+        if self.skip_total {
+            query_parameters.push(("skipTotal", "true"));
+        }
+
+        query_parameters.extend(self.query_params.iter().copied());
+
+        self.options
+            .apply(self.client.request_get(&url, Some(query_parameters)))
+    }
+
+    /// Sends the request and returns the successful response.
+    async fn send(self) -> Result<reqwest::Response, RequestError> {
         let request = self
             .client
-            .request_get(&url, Some(query_parameters))
-            .send()
+            .send_cached(self.options.cache, self.request())
             .await;
 
-        let response = match request {
-            Ok(response) => response
-                .error_for_status()
-                .map_err(|err| match err.status() {
-                    Some(reqwest::StatusCode::FORBIDDEN) => RequestError::Forbidden,
-                    Some(reqwest::StatusCode::NOT_FOUND) => RequestError::NotFound,
-                    Some(reqwest::StatusCode::TOO_MANY_REQUESTS) => RequestError::TooManyRequests,
-                    _ => RequestError::Unhandled,
-                })?,
+        match request {
+            Ok(response) if response.status().is_success() => Ok(response),
+            Ok(response) => Err(RequestError::from_response(response)
+                .await
+                .with_filter(self.filter)),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+/// An iterator over the pages of a records list, created with [`CollectionGetListBuilder::paginate()`].
+pub struct Pages<'a, T: Send + Deserialize<'a>> {
+    builder: CollectionGetListBuilder<'a, T>,
+    /// The next page to fetch, `None` once the iteration is over.
+    page: Option<u16>,
+}
+
+impl<T: DeserializeOwned + Send> Pages<'_, T> {
+    /// Fetch the next page, or return `None` once all the pages have been returned.
+    pub async fn next_page(&mut self) -> Option<Result<RecordList<T>, RequestError>> {
+        let page = self.page?;
+
+        let records = match self.builder.clone().page(page).call().await {
+            Ok(records) => records,
             Err(error) => {
-                return Err(match error.status() {
-                    Some(reqwest::StatusCode::FORBIDDEN) => RequestError::Forbidden,
-                    Some(reqwest::StatusCode::NOT_FOUND) => RequestError::NotFound,
-                    Some(reqwest::StatusCode::TOO_MANY_REQUESTS) => RequestError::TooManyRequests,
-                    _ => RequestError::Unhandled,
-                });
+                self.page = None;
+                return Some(Err(error));
             }
         };
 
-        // Parse JSON response
-        let records = response
-            .json::<RecordList<T>>()
+        // `total_pages` is negative when the total is skipped
+        let is_last_page = records.items.is_empty()
+            || records.items.len() < usize::try_from(records.per_page).unwrap_or(0)
+            || (records.total_pages >= 0 && i32::from(page) >= records.total_pages);
+
+        self.page = if is_last_page {
+            None
+        } else {
+            page.checked_add(1)
+        };
+
+        Some(Ok(records))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::{Value, json};
+
+    use crate::PocketBase;
+    use crate::error::RequestError;
+    use crate::test_utils::assert_error_statuses;
+
+    #[tokio::test]
+    async fn error_statuses() {
+        assert_error_statuses!(
+            GET,
+            "/api/collections/articles/records",
+            |pb| pb.collection("articles").get_list::<Value>().call(),
+            {
+                401 => RequestError::Unauthorized,
+                429 => RequestError::TooManyRequests,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn error_headers() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/api/collections/articles/records");
+            then.status(400)
+                .header("X-Request-Id", "4f1c2a")
+                .json_body(json!({ "status": 400, "message": "Invalid filter.", "data": {} }));
+        });
+
+        server.mock(|when, then| {
+            when.method(GET).path("/api/collections/missing/records");
+            then.status(500)
+                .header("X-Request-Id", "8b3d1e")
+                .body("oops");
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        for (collection, request_id) in [("articles", "4f1c2a"), ("missing", "8b3d1e")] {
+            let error = pb
+                .collection(collection)
+                .get_list::<Value>()
+                .call_with_response()
+                .await
+                .unwrap_err();
+
+            let headers = error.headers().unwrap();
+            assert_eq!(headers.get("X-Request-Id").unwrap(), request_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_filter() {
+        let server = MockServer::start_async().await;
+
+        // The payload returned by PocketBase for an invalid filter, which doesn't mention it.
+        server.mock(|when, then| {
+            when.method(GET).path("/api/collections/articles/records");
+            then.status(400).json_body(json!({
+                "data": {},
+                "message": "Something went wrong while processing your request.",
+                "status": 400
+            }));
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let error = pb
+            .collection("articles")
+            .get_list::<Value>()
+            .filter("title ~~ 'hello'")
+            .call()
             .await
-            .map_err(|error| RequestError::ParseError(error.to_string()))?;
+            .unwrap_err();
+
+        let RequestError::InvalidFilter { filter, response } = error else {
+            panic!("expected an invalid filter error, got {error:?}");
+        };
+        assert_eq!(filter, "title ~~ 'hello'");
+        assert_eq!(response.status, 400);
+        assert_eq!(
+            response.message,
+            "Something went wrong while processing your request."
+        );
+
+        // Without a filter, the error is left as-is.
+        let error = pb
+            .collection("articles")
+            .get_list::<Value>()
+            .call()
+            .await
+            .unwrap_err();
 
-        Ok(records)
+        assert!(matches!(error, RequestError::BadRequest(_)));
     }
 }
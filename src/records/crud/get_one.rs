@@ -1,14 +1,19 @@
 use serde::{Deserialize, de::DeserializeOwned};
+use tokio_util::sync::CancellationToken;
 
 use crate::error::RequestError;
-use crate::{Collection, PocketBase};
+use crate::options::{RequestKey, RequestOptions, cancellable};
+use crate::records::raw::RawBody;
+use crate::{ApiResponse, Collection, PocketBase, validate_record_id};
 
 pub struct CollectionGetOneBuilder<'a, T: Send + Deserialize<'a>> {
     client: &'a PocketBase,
     collection_name: &'a str,
     record_id: &'a str,
     expand: Option<&'a str>,
+    query_params: Vec<(&'a str, &'a str)>,
     _marker: std::marker::PhantomData<T>,
+    options: RequestOptions<'a>,
 }
 
 impl<'a> Collection<'a> {
@@ -16,7 +21,7 @@ impl<'a> Collection<'a> {
     ///
     /// # Example
     /// ```rust,ignore
-    /// #[derive(Default, Deserialize, Clone)]
+    /// #[derive(Deserialize)]
     /// struct Article {
     ///     id: String,
     ///     title: String,
@@ -30,7 +35,7 @@ impl<'a> Collection<'a> {
     ///     .await?;
     /// ```
     #[must_use]
-    pub const fn get_one<T: Default + DeserializeOwned + Clone + Send>(
+    pub const fn get_one<T: DeserializeOwned + Send>(
         self,
         record_id: &'a str,
     ) -> CollectionGetOneBuilder<'a, T> {
@@ -39,12 +44,14 @@ impl<'a> Collection<'a> {
             collection_name: self.name,
             record_id,
             expand: None,
+            query_params: Vec::new(),
+            options: RequestOptions::new(),
             _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetOneBuilder<'a, T> {
+impl<'a, T: DeserializeOwned + Send> CollectionGetOneBuilder<'a, T> {
     /// Auto expand record relations (up to 6-levels deep).
     ///
     /// Expanded relations are appended under the `expand` property.
@@ -59,42 +66,170 @@ impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetOneBuilder<'
         self
     }
 
+    /// Append a custom query parameter to the request.
+    ///
+    /// Useful for passing parameters read by `PocketBase` hooks, or route
+    /// parameters not yet covered by this builder.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query_param("tenant", "acme")
+    /// ```
+    pub fn query_param(mut self, key: &'a str, value: &'a str) -> Self {
+        self.query_params.push((key, value));
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Serve the response from the client cache if it was stored less than `ttl` ago.
+    ///
+    /// Outdated responses are revalidated with a conditional request when `PocketBase`
+    /// returned an `ETag`. The cache is shared by the clones of the client, see
+    /// [`PocketBase::clear_cache()`](crate::PocketBase::clear_cache).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .cache(Duration::from_secs(60))
+    /// ```
+    pub const fn cache(mut self, ttl: std::time::Duration) -> Self {
+        self.options.cache = Some(ttl);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Cancel the pending request with the same key when sending this request, and let a
+    /// newer request with the same key cancel this one, making it return a `Cancelled` error.
+    ///
+    /// Without a key, the method and URL of the request are used when auto-cancellation is
+    /// enabled on the client, see [`PocketBase::set_auto_cancellation()`](crate::PocketBase::set_auto_cancellation).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .request_key("articles-search")
+    /// ```
+    pub const fn request_key(mut self, key: &'a str) -> Self {
+        self.options.request_key = RequestKey::Custom(key);
+        self
+    }
+
+    /// Never auto-cancel this request, even if auto-cancellation is enabled on the client.
+    pub const fn no_auto_cancel(mut self) -> Self {
+        self.options.request_key = RequestKey::Disabled;
+        self
+    }
+
     /// Execute the request and return the record.
     pub async fn call(self) -> Result<T, RequestError> {
-        let url = format!(
-            "{}/api/collections/{}/records/{}",
-            self.client.base_url, self.collection_name, self.record_id
-        );
+        self.call_with_response()
+            .await
+            .map(|response| response.data)
+    }
 
-        let request = self.expand.map_or_else(
-            || self.client.request_get(&url, None),
-            |expand_value| {
-                let expand_params = vec![("expand", expand_value)];
+    /// Execute the request and return the record, or `None` if it doesn't exist.
+    ///
+    /// A `404 Not Found` response is returned as `Ok(None)` rather than
+    /// [`RequestError::NotFound`], every other error is returned as-is.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// match pb.collection("articles").get_one::<Article>("record_id_123").call_opt().await? {
+    ///     Some(article) => println!("{article:?}"),
+    ///     None => println!("No such article"),
+    /// }
+    /// ```
+    pub async fn call_opt(self) -> Result<Option<T>, RequestError> {
+        match self.call().await {
+            Ok(record) => Ok(Some(record)),
+            Err(RequestError::NotFound) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
 
-                self.client.request_get(&url, Some(expand_params))
-            },
-        );
+    /// Execute the request and return the record, along with the response status and headers.
+    ///
+    /// The headers of an unsuccessful response are kept in the error when available, see
+    /// [`RequestError::headers()`].
+    pub async fn call_with_response(self) -> Result<ApiResponse<T>, RequestError> {
+        let (cancellation_token, _pending) =
+            self.options.cancellation(self.client, || self.request());
 
-        let request = request.send().await;
-
-        let response = match request {
-            Ok(response) => response
-                .error_for_status()
-                .map_err(|err| match err.status() {
-                    Some(reqwest::StatusCode::FORBIDDEN) => RequestError::Forbidden,
-                    Some(reqwest::StatusCode::NOT_FOUND) => RequestError::NotFound,
-                    Some(reqwest::StatusCode::TOO_MANY_REQUESTS) => RequestError::TooManyRequests,
-                    _ => RequestError::Unhandled,
-                })?,
-            Err(error) => {
-                return Err(match error.status() {
-                    Some(reqwest::StatusCode::FORBIDDEN) => RequestError::Forbidden,
-                    Some(reqwest::StatusCode::NOT_FOUND) => RequestError::NotFound,
-                    Some(reqwest::StatusCode::TOO_MANY_REQUESTS) => RequestError::TooManyRequests,
-                    _ => RequestError::Unhandled,
-                });
-            }
-        };
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    /// Execute the request and return the raw response body, without deserializing it.
+    ///
+    /// See [`RawBody`] to deserialize the record into a type borrowing from the body.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let raw = pb
+    ///     .collection("articles")
+    ///     .get_one::<IgnoredAny>("record_id_123")
+    ///     .call_raw()
+    ///     .await?;
+    ///
+    /// let article = raw.parse::<ArticleRef<'_>>()?;
+    /// ```
+    pub async fn call_raw(self) -> Result<RawBody, RequestError> {
+        let (cancellation_token, _pending) =
+            self.options.cancellation(self.client, || self.request());
+
+        cancellable(
+            cancellation_token,
+            async move { RawBody::from_response(self.send().await?).await },
+            || RequestError::Cancelled,
+        )
+        .await
+    }
+
+    async fn execute(self) -> Result<ApiResponse<T>, RequestError> {
+        let response = self.send().await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
 
         // Parse JSON response
         let record = response
@@ -102,6 +237,67 @@ impl<'a, T: Default + DeserializeOwned + Clone + Send> CollectionGetOneBuilder<'
             .await
             .map_err(|error| RequestError::ParseError(error.to_string()))?;
 
-        Ok(record)
+        Ok(ApiResponse {
+            status,
+            headers,
+            data: record,
+        })
+    }
+
+    /// Builds the request.
+    fn request(&self) -> reqwest::RequestBuilder {
+        let url = format!(
+            "{}/api/collections/{}/records/{}",
+            self.client.base_url, self.collection_name, self.record_id
+        );
+
+        let mut query_parameters: Vec<(&str, &str)> = vec![];
+
+        if let Some(expand) = self.expand {
+            query_parameters.push(("expand", expand));
+        }
+
+        query_parameters.extend(self.query_params.iter().copied());
+
+        self.options
+            .apply(self.client.request_get(&url, Some(query_parameters)))
+    }
+
+    /// Sends the request and returns the successful response.
+    async fn send(self) -> Result<reqwest::Response, RequestError> {
+        validate_record_id(self.record_id).map_err(RequestError::InvalidRecordId)?;
+
+        let request = self
+            .client
+            .send_cached(self.options.cache, self.request())
+            .await;
+
+        match request {
+            Ok(response) if response.status().is_success() => Ok(response),
+            Ok(response) => Err(RequestError::from_response(response).await),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::Value;
+
+    use crate::error::RequestError;
+    use crate::test_utils::assert_error_statuses;
+
+    #[tokio::test]
+    async fn error_statuses() {
+        assert_error_statuses!(
+            GET,
+            "/api/collections/articles/records/abc123",
+            |pb| pb.collection("articles").get_one::<Value>("abc123").call(),
+            {
+                401 => RequestError::Unauthorized,
+                429 => RequestError::TooManyRequests,
+            }
+        );
     }
 }
@@ -0,0 +1,710 @@
+use serde::Serialize;
+use serde::de::value::{Error as CellError, MapDeserializer, StrDeserializer};
+use serde::de::{DeserializeOwned, Deserializer, Error as _, IntoDeserializer, Visitor};
+use serde_json::Value;
+use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+
+use crate::error::RawResponse;
+use crate::records::crud::create::CreateError;
+use crate::records::crud::upsert::{
+    BatchRequest, BatchRequestItem, batch_item_error, failed_batch_item, record_url,
+};
+use crate::{Collection, PocketBase, circuit_breaker};
+
+/// The progress of an import, given to the [`CollectionImportBuilder::on_progress()`] callback
+/// after each batch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImportProgress {
+    /// The amount of lines read so far.
+    pub lines: usize,
+    /// The amount of records created so far.
+    pub imported: usize,
+    /// The amount of records that were invalid or failed to be created so far.
+    pub failed: usize,
+}
+
+/// The outcome of an import.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// The amount of records created.
+    pub imported: usize,
+    /// The records that were invalid or failed to be created, in the order of the lines.
+    pub errors: Vec<ImportLineError>,
+}
+
+/// A record that could not be imported.
+#[derive(Error, Debug)]
+#[error("Line {line}: {error}")]
+pub struct ImportLineError {
+    /// The line of the record in the input, starting at 1.
+    pub line: usize,
+    /// Why the record could not be imported.
+    pub error: ImportFailure,
+}
+
+/// Represents the reasons why a record could not be imported.
+#[derive(Error, Debug)]
+pub enum ImportFailure {
+    /// The record could not be parsed into the expected type, it was not sent.
+    #[error("Invalid record: {0}")]
+    Invalid(String),
+    /// The record was rejected by `PocketBase`.
+    #[error("Failed to create the record: {0}")]
+    Create(CreateError),
+}
+
+type ProgressCallback<'a> = Box<dyn FnMut(&ImportProgress) + Send + 'a>;
+
+#[derive(Clone, Copy)]
+enum ImportFormat {
+    Ndjson,
+    Csv,
+}
+
+/// Builder for importing records from newline-delimited JSON or CSV.
+pub struct CollectionImportBuilder<'a, T, R> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    reader: R,
+    format: ImportFormat,
+    batch_size: usize,
+    on_progress: Option<ProgressCallback<'a>>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<'a> Collection<'a> {
+    /// Import records from newline-delimited JSON, one record per line.
+    ///
+    /// Each line is parsed into `T` before being sent, so that invalid records are reported
+    /// without reaching `PocketBase`. Records are read by batches, each batch being created
+    /// in a single request through the batch API: a failed record doesn't stop the import,
+    /// and is reported with its line in the [`ImportReport`].
+    ///
+    /// **The batch API must be enabled in the application settings.**
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let file = tokio::fs::File::open("articles.ndjson").await?;
+    ///
+    /// let report = pb
+    ///     .collection("articles")
+    ///     .import_ndjson::<Article, _>(file)
+    ///     .on_progress(|progress| println!("{} articles imported", progress.imported))
+    ///     .call()
+    ///     .await?;
+    ///
+    /// for error in &report.errors {
+    ///     eprintln!("{error}");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn import_ndjson<T, R>(self, reader: R) -> CollectionImportBuilder<'a, T, R>
+    where
+        T: Serialize + DeserializeOwned + Send,
+        R: AsyncRead + Unpin + Send,
+    {
+        self.import(reader, ImportFormat::Ndjson)
+    }
+
+    /// Import records from CSV, with a header row naming the fields.
+    ///
+    /// The cells are parsed according to the fields of `T`: empty cells are `None`, and
+    /// the lists or objects of `json` and multiple `relation` fields are read as JSON, as
+    /// written by `export_csv()`.
+    /// See [`Collection::import_ndjson()`] for how records are validated and created.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let file = tokio::fs::File::open("articles.csv").await?;
+    ///
+    /// let report = pb
+    ///     .collection("articles")
+    ///     .import_csv::<Article, _>(file)
+    ///     .call()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn import_csv<T, R>(self, reader: R) -> CollectionImportBuilder<'a, T, R>
+    where
+        T: Serialize + DeserializeOwned + Send,
+        R: AsyncRead + Unpin + Send,
+    {
+        self.import(reader, ImportFormat::Csv)
+    }
+
+    fn import<T, R>(self, reader: R, format: ImportFormat) -> CollectionImportBuilder<'a, T, R> {
+        CollectionImportBuilder {
+            client: self.client,
+            collection_name: self.name,
+            reader,
+            format,
+            batch_size: 50,
+            on_progress: None,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T, R> CollectionImportBuilder<'a, T, R>
+where
+    T: Serialize + DeserializeOwned + Send,
+    R: AsyncRead + Unpin + Send,
+{
+    /// Read and create up to `batch_size` records per batch request (default: 50).
+    ///
+    /// The batch size must not exceed the maximum amount of requests per batch set in the
+    /// application settings (50 by default).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .batch_size(100)
+    /// ```
+    #[must_use]
+    pub const fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = if batch_size == 0 { 1 } else { batch_size };
+        self
+    }
+
+    /// Call `on_progress` after each batch of records.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .on_progress(|progress| println!("{} lines read", progress.lines))
+    /// ```
+    #[must_use]
+    pub fn on_progress(mut self, on_progress: impl FnMut(&ImportProgress) + Send + 'a) -> Self {
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
+
+    /// Execute the import and return its report.
+    ///
+    /// Only failing to read the input stops the import, the records already created are kept.
+    pub async fn call(mut self) -> Result<ImportReport, std::io::Error> {
+        let mut reader = RecordReader::new(self.reader, self.format);
+        let mut report = ImportReport::default();
+
+        loop {
+            let mut batch = Vec::new();
+
+            while batch.len() < self.batch_size {
+                match reader.next_record::<T>().await? {
+                    Some((line, Ok(record))) => match serde_json::to_value::<T>(record) {
+                        Ok(record) => batch.push((line, record)),
+                        Err(error) => report.errors.push(ImportLineError {
+                            line,
+                            error: ImportFailure::Invalid(error.to_string()),
+                        }),
+                    },
+                    Some((line, Err(message))) => report.errors.push(ImportLineError {
+                        line,
+                        error: ImportFailure::Invalid(message),
+                    }),
+                    None => break,
+                }
+            }
+
+            let is_last_batch = batch.len() < self.batch_size;
+
+            create_batch(self.client, self.collection_name, batch, &mut report).await;
+
+            if let Some(on_progress) = &mut self.on_progress {
+                on_progress(&ImportProgress {
+                    lines: reader.line,
+                    imported: report.imported,
+                    failed: report.errors.len(),
+                });
+            }
+
+            if is_last_batch {
+                break;
+            }
+        }
+
+        report.errors.sort_by_key(|error| error.line);
+
+        Ok(report)
+    }
+}
+
+/// Creates the records of a batch, reporting the failed ones.
+///
+/// As a batch request is rolled back when one of its records fails, the failed record
+/// is reported and the batch is sent again without it.
+async fn create_batch(
+    client: &PocketBase,
+    collection_name: &str,
+    mut batch: Vec<(usize, Value)>,
+    report: &mut ImportReport,
+) {
+    while !batch.is_empty() {
+        match send_batch(client, collection_name, &batch).await {
+            Ok(()) => {
+                report.imported += batch.len();
+                return;
+            }
+            Err(BatchFailure::Record(index, error)) if index < batch.len() => {
+                let (line, _) = batch.remove(index);
+
+                report.errors.push(ImportLineError {
+                    line,
+                    error: ImportFailure::Create(error),
+                });
+            }
+            Err(failure) => {
+                for (line, _) in batch {
+                    report.errors.push(ImportLineError {
+                        line,
+                        error: ImportFailure::Create(failure.to_create_error()),
+                    });
+                }
+
+                return;
+            }
+        }
+    }
+}
+
+/// Sends the records of a batch in a single batch request.
+async fn send_batch(
+    client: &PocketBase,
+    collection_name: &str,
+    batch: &[(usize, Value)],
+) -> Result<(), BatchFailure> {
+    let url = record_url(collection_name, None, Some("id"));
+
+    let body = BatchRequest {
+        requests: batch
+            .iter()
+            .map(|(_, record)| BatchRequestItem {
+                method: "POST",
+                url: url.clone(),
+                body: record,
+            })
+            .collect(),
+    };
+
+    let endpoint = format!("{}/api/batch", client.base_url);
+
+    let request =
+        client.with_authorization_token(client.reqwest_client.post(&endpoint).json(&body));
+
+    let response = match client.send_request(request).await {
+        Ok(response) => response,
+        Err(error) => return Err(BatchFailure::Unreachable(error.to_string())),
+    };
+
+    if circuit_breaker::is_open_response(&response) {
+        return Err(BatchFailure::CircuitOpen);
+    }
+
+    if response.status() == reqwest::StatusCode::OK {
+        return Ok(());
+    }
+
+    let status = response.status().as_u16();
+    let text = response
+        .text()
+        .await
+        .map_err(|error| BatchFailure::ParseError(error.to_string()))?;
+    let body = serde_json::from_str(&text).unwrap_or(Value::String(text));
+
+    match failed_batch_item(&body) {
+        Some((index, error)) if status == 400 => Err(BatchFailure::Record(index, error)),
+        _ => Err(BatchFailure::Response { status, body }),
+    }
+}
+
+/// Why a batch request failed.
+enum BatchFailure {
+    /// A record was rejected, rolling back the others.
+    Record(usize, CreateError),
+    /// Communication with the `PocketBase` API failed.
+    Unreachable(String),
+    /// The request was not sent, as the circuit breaker is open.
+    CircuitOpen,
+    /// The response could not be read.
+    ParseError(String),
+    /// The batch request was rejected as a whole.
+    Response { status: u16, body: Value },
+}
+
+impl BatchFailure {
+    /// Returns the error of each record of a batch that failed as a whole.
+    fn to_create_error(&self) -> CreateError {
+        match self {
+            Self::Record(_, _) => {
+                CreateError::ParseError("The failed request is missing from the batch.".to_owned())
+            }
+            Self::Unreachable(error) => CreateError::Unreachable(error.clone()),
+            Self::CircuitOpen => CreateError::CircuitOpen,
+            Self::ParseError(error) => CreateError::ParseError(error.clone()),
+            Self::Response { status, body } => match body {
+                Value::String(body) => CreateError::UnexpectedResponse(RawResponse {
+                    status: *status,
+                    body: body.clone(),
+                    error: None,
+                    headers: Box::default(),
+                }),
+                body => batch_item_error(*status, body),
+            },
+        }
+    }
+}
+
+/// Reads the records of the input, one at a time.
+struct RecordReader<R> {
+    input: BufReader<R>,
+    format: ImportFormat,
+    /// The amount of lines read so far.
+    line: usize,
+    /// The CSV header row, once read.
+    columns: Option<Vec<String>>,
+}
+
+impl<R: AsyncRead + Unpin> RecordReader<R> {
+    fn new(input: R, format: ImportFormat) -> Self {
+        Self {
+            input: BufReader::new(input),
+            format,
+            line: 0,
+            columns: None,
+        }
+    }
+
+    /// Returns the next record along with its line, skipping the blank lines.
+    async fn next_record<T: DeserializeOwned>(
+        &mut self,
+    ) -> Result<Option<(usize, Result<T, String>)>, std::io::Error> {
+        loop {
+            let line = self.line + 1;
+
+            let Some(text) = self.read_line().await? else {
+                return Ok(None);
+            };
+
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            match self.format {
+                ImportFormat::Ndjson => {
+                    let record = serde_json::from_str(&text).map_err(|error| error.to_string());
+                    return Ok(Some((line, record)));
+                }
+                ImportFormat::Csv => {
+                    let text = self.complete_csv_row(text).await?;
+                    let cells = parse_csv_row(&text);
+
+                    let Some(columns) = &self.columns else {
+                        self.columns = Some(cells);
+                        continue;
+                    };
+
+                    let record = T::deserialize(MapDeserializer::new(
+                        columns
+                            .iter()
+                            .map(String::as_str)
+                            .zip(cells.iter().map(|cell| CsvCell(cell))),
+                    ))
+                    .map_err(|error: CellError| error.to_string());
+
+                    return Ok(Some((line, record)));
+                }
+            }
+        }
+    }
+
+    async fn read_line(&mut self) -> Result<Option<String>, std::io::Error> {
+        let mut text = String::new();
+
+        if self.input.read_line(&mut text).await? == 0 {
+            return Ok(None);
+        }
+
+        self.line += 1;
+
+        Ok(Some(text))
+    }
+
+    /// Appends the next lines while the row ends inside a quoted cell.
+    async fn complete_csv_row(&mut self, mut text: String) -> Result<String, std::io::Error> {
+        while text.matches('"').count() % 2 == 1 {
+            let Some(next) = self.read_line().await? else {
+                break;
+            };
+
+            text.push_str(&next);
+        }
+
+        Ok(text)
+    }
+}
+
+/// Splits a CSV row into its cells, unquoting them as described by RFC 4180.
+fn parse_csv_row(row: &str) -> Vec<String> {
+    let row = row.trim_end_matches(['\r', '\n']);
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = row.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => cells.push(std::mem::take(&mut cell)),
+            char => cell.push(char),
+        }
+    }
+
+    cells.push(cell);
+    cells
+}
+
+/// A CSV cell, parsed according to the type of the field it is deserialized into.
+struct CsvCell<'a>(&'a str);
+
+impl<'a> CsvCell<'a> {
+    fn parse<V: std::str::FromStr>(&self) -> Result<V, CellError> {
+        self.0
+            .parse()
+            .map_err(|_| CellError::custom(format!("invalid value `{}`", self.0)))
+    }
+
+    /// Deserializes the cell as JSON, for the lists and objects.
+    fn json<V>(
+        &self,
+        deserialize: impl FnOnce(
+            &mut serde_json::Deserializer<serde_json::de::StrRead<'a>>,
+        ) -> Result<V, serde_json::Error>,
+    ) -> Result<V, CellError> {
+        let mut json = serde_json::Deserializer::from_str(self.0);
+
+        deserialize(&mut json)
+            .and_then(|value| json.end().map(|()| value))
+            .map_err(CellError::custom)
+    }
+}
+
+impl<'de> IntoDeserializer<'de, CellError> for CsvCell<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CellError> {
+                visitor.$visit(self.parse()?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for CsvCell<'de> {
+    type Error = CellError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CellError> {
+        visitor.visit_str(self.0)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CellError> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CellError> {
+        if self.0.is_empty() {
+            visitor.visit_unit()
+        } else {
+            Err(CellError::custom(format!(
+                "expected an empty cell, found `{}`",
+                self.0
+            )))
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CellError> {
+        self.json(|json| json.deserialize_seq(visitor))
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CellError> {
+        self.json(|json| json.deserialize_map(visitor))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CellError> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, CellError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, CellError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, CellError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CellError> {
+        visitor.visit_enum(StrDeserializer::<CellError>::new(self.0))
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf unit_struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::{Value, json};
+
+    use super::ImportFailure;
+    use crate::PocketBase;
+    use crate::records::crud::create::CreateError;
+
+    const RECORDS: &str = "{\"title\":\"a\"}\n{\"title\":\"b\"}\n\n{\"title\":\"c\"}\n";
+
+    #[tokio::test]
+    async fn batch_without_the_failed_record() {
+        let server = MockServer::start_async().await;
+
+        let failed = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/batch")
+                .body_contains("\"title\":\"b\"");
+            then.status(400).json_body(json!({
+                "status": 400,
+                "message": "Batch transaction failed.",
+                "data": { "requests": { "1": {
+                    "code": "batch_request_failed",
+                    "message": "Batch request failed.",
+                    "response": { "status": 400, "body": {
+                        "status": 400,
+                        "message": "Failed to create record.",
+                        "data": { "title": {
+                            "code": "validation_not_unique",
+                            "message": "Value must be unique.",
+                        } },
+                    } },
+                } } },
+            }));
+        });
+
+        let created = server.mock(|when, then| {
+            when.method(POST).path("/api/batch");
+            then.status(200).json_body(json!([
+                { "status": 200, "body": { "id": "a" } },
+                { "status": 200, "body": { "id": "c" } },
+            ]));
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let report = pb
+            .collection("articles")
+            .import_ndjson::<Value, _>(RECORDS.as_bytes())
+            .call()
+            .await
+            .unwrap();
+
+        failed.assert_hits(1);
+        created.assert_hits(1);
+        assert_eq!(report.imported, 2);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].line, 2);
+        assert!(matches!(
+            &report.errors[0].error,
+            ImportFailure::Create(CreateError::NotUnique { field }) if field == "title"
+        ));
+    }
+
+    #[tokio::test]
+    async fn batch_rejected_as_a_whole() {
+        let server = MockServer::start_async().await;
+
+        let batch = server.mock(|when, then| {
+            when.method(POST).path("/api/batch");
+            then.status(403)
+                .json_body(json!({ "status": 403, "message": "", "data": {} }));
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let report = pb
+            .collection("articles")
+            .import_ndjson::<Value, _>(RECORDS.as_bytes())
+            .batch_size(2)
+            .call()
+            .await
+            .unwrap();
+
+        batch.assert_hits(2);
+        assert_eq!(report.imported, 0);
+        assert_eq!(
+            report
+                .errors
+                .iter()
+                .map(|error| error.line)
+                .collect::<Vec<_>>(),
+            [1, 2, 4]
+        );
+        assert!(
+            report
+                .errors
+                .iter()
+                .all(|error| matches!(error.error, ImportFailure::Create(CreateError::Forbidden)))
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Options of a records list query.
+///
+/// Accepted by [`get_list()`](crate::Collection::get_list),
+/// [`get_full_list()`](crate::Collection::get_full_list) and
+/// [`get_first_list_item()`](crate::Collection::get_first_list_item).
+///
+/// The options can be built once and applied to several queries with the `list_options()`
+/// method of the builders, or loaded from a configuration file. Unset options keep the
+/// builder's values, and the options not supported by a builder are ignored (e.g. the
+/// pagination of `get_first_list_item()`).
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::ListOptions;
+///
+/// let published = ListOptions {
+///     filter: Some("status='published'".to_owned()),
+///     sort: Some("-created".to_owned()),
+///     expand: Some("author".to_owned()),
+///     ..Default::default()
+/// };
+///
+/// let latest = pb
+///     .collection("articles")
+///     .get_first_list_item::<Article>()
+///     .list_options(&published)
+///     .call()
+///     .await?;
+///
+/// let all = pb
+///     .collection("articles")
+///     .get_full_list::<Article>()
+///     .list_options(&published)
+///     .call()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ListOptions {
+    /// The page of the list (the first page to fetch for `get_full_list()`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u16>,
+    /// The max returned records per page (the batch size for `get_full_list()`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u16>,
+    /// The records order, e.g. `-created,id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// The filter of the returned records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// The relations to expand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<String>,
+    /// Comma separated string of the fields to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    /// Whether to skip the total count query (only for `get_list()`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_total: Option<bool>,
+    /// Custom query parameters.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub query: BTreeMap<String, String>,
+}
+
+impl ListOptions {
+    /// Returns the `fields` option and the custom query parameters, as query parameters.
+    pub(crate) fn query_params(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .as_deref()
+            .map(|fields| ("fields", fields))
+            .into_iter()
+            .chain(
+                self.query
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str())),
+            )
+    }
+}
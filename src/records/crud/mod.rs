@@ -1,7 +1,15 @@
+pub mod count;
 pub mod create;
+pub mod create_many;
 pub mod delete;
-mod get_first_list_item;
-mod get_full_list;
-mod get_list;
-mod get_one;
+pub mod delete_many;
+pub mod exists;
+pub mod export;
+pub mod get_first_list_item;
+pub mod get_full_list;
+pub mod get_list;
+pub mod get_one;
+pub mod import;
+pub mod list_options;
 pub mod update;
+pub mod upsert;
@@ -1,8 +1,13 @@
+use reqwest::multipart::{Form, Part};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use thiserror::Error;
+use tokio_util::sync::CancellationToken;
 
-use crate::error::{BadRequestError, BadRequestResponse};
-use crate::{Collection, PocketBase};
+use crate::error::{BadRequestError, BadRequestResponse, RawResponse, RecordIdError};
+use crate::options::{RequestOptions, cancellable};
+use crate::{Collection, PocketBase, Timestamp, circuit_breaker, validate_record_id};
 
 /// Represents the various errors that can be obtained after a `update` request.
 #[derive(Error, Debug)]
@@ -14,6 +19,23 @@ pub enum UpdateError {
     #[error("One or more fields were not validated : {0:?}")]
     BadRequest(Vec<BadRequestError>),
     /// Communication with the `PocketBase` API was successful,
+    /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response,
+    /// as the value of a field with a unique index is already used by another record.
+    ///
+    /// Only returned when no other field was rejected, [`BadRequest`](Self::BadRequest) is
+    /// returned otherwise. Holds the name of the field, e.g. to fetch the existing record instead.
+    #[error("The value of the `{field}` field is already used by another record.")]
+    NotUnique {
+        /// The name of the field whose value must be unique.
+        field: String,
+    },
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [401 Unauthorized]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/401") HTTP error response.
+    ///
+    /// The request requires a valid Authorization Token.
+    #[error("The request requires a valid Authorization Token.")]
+    Unauthorized,
+    /// Communication with the `PocketBase` API was successful,
     /// but returned a [403 Forbidden]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/403") HTTP error response.
     ///
     /// The authorized record is not allowed to perform this action.
@@ -25,12 +47,29 @@ pub enum UpdateError {
     /// The requested resource wasn't found. Missing collection context.
     #[error("The requested resource wasn't found. Missing collection context.")]
     NotFound,
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [429 Too Many Requests]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/429") HTTP error response.
+    ///
+    /// The server is rate limiting requests. Wait before retrying.
+    #[error("The server is rate limiting requests. Please wait before retrying.")]
+    TooManyRequests,
+    /// The request was cancelled through its cancellation token.
+    #[error("The request was cancelled.")]
+    Cancelled,
+    /// The given record id can't be valid, the request was not sent.
+    #[error("Invalid record id: {0}")]
+    InvalidRecordId(RecordIdError),
     /// Communication with the `PocketBase` API failed.
     ///
     /// This could be caused by an internet outage, an error in the link given to the `PocketBase` SDK
     /// and similar errors.
     #[error("The communication with the PocketBase API failed: {0}")]
     Unreachable(String),
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    ///
+    /// See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
     /// The response could not be parsed into the expected data structure.
     #[error(
         "Could not parse response into the expected data structure. It usually means that there is a missmatch between the provided Generic Type Parameter and your Collection definition: {0}"
@@ -38,47 +77,75 @@ pub enum UpdateError {
     ParseError(String),
     /// The response from the `PocketBase` instance API was unexpected.
     /// If you think its an error, please [open an issue on GitHub]("https://github.com/fromhorizons/pocketbase-rs/issues").
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
     #[error("An unhandled status code was returned by the PocketBase API: {0}")]
-    UnexpectedResponse(String),
+    UnexpectedResponse(RawResponse),
+    /// The given record couldn't be serialized into a JSON object.
+    #[error("The given record couldn't be serialized into a JSON object: {0}")]
+    InvalidRecord(String),
 }
 
-pub struct CollectionUpdateBuilder<'a, T: Send + Serialize + Deserialize<'a>> {
+/// The body sent along an update request.
+enum UpdateBody {
+    Json(Map<String, Value>),
+    /// The files and form fields, along with the fields set on the builder, which are only
+    /// added to the form when the request is sent.
+    Multipart(Form, Map<String, Value>),
+    Invalid(String),
+}
+
+/// Builder for updating a single record.
+pub struct CollectionUpdateBuilder<'a, T> {
     client: &'a PocketBase,
     collection_name: &'a str,
     record_id: &'a str,
-    data: T,
+    body: UpdateBody,
+    expand: Option<&'a str>,
+    fields: Option<&'a str>,
     _marker: std::marker::PhantomData<T>,
+    options: RequestOptions<'a>,
 }
 
-// TODO: Include the actual record data based on Generic type parameter.
-// 
-// pub struct UpdateResponse<T> {
-//     pub collection_name: String,
-//     pub collection_id: String,
-//     pub id: String,
-//     pub updated: String,
-//     pub created: String,
-//     #[serde(flatten)]
-//     pub record: T, // The actual record data
-// }
-
-/// Contains information about the successfully updated Record
+/// Contains information about the successfully updated Record.
+///
+/// The final record data returned by `PocketBase` (after rules and hooks had a chance
+/// to modify it) is deserialized into `record`.
+///
+/// Note that `collectionName`, `collectionId`, `id`, `created` and `updated` are captured
+/// by this envelope and are therefore not forwarded to `T`. They are left empty when
+/// they are not part of the fields selected with `.fields()`.
 #[derive(Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateResponse {
+pub struct UpdateResponse<T> {
+    /// The name of the collection the record belongs to.
+    #[serde(default)]
     pub collection_name: String,
+    /// The ID of the collection the record belongs to.
+    #[serde(default)]
     pub collection_id: String,
+    /// The record's unique ID.
+    #[serde(default)]
     pub id: String,
-    pub updated: String,
-    pub created: String,
+    /// The timestamp when the record was last updated.
+    #[serde(default)]
+    pub updated: Timestamp,
+    /// The timestamp when the record was created.
+    #[serde(default)]
+    pub created: Timestamp,
+    /// The actual record data.
+    #[serde(flatten)]
+    pub record: T,
 }
 
 impl<'a> Collection<'a> {
     /// Update a single record.
     ///
+    /// For file uploads, use [`Collection::update_multipart()`].
+    ///
     /// # Example
     /// ```rust,ignore
-    /// #[derive(Default, Serialize, Clone, Debug)]
+    /// #[derive(Serialize, Deserialize, Debug)]
     /// struct Article {
     ///     name: String,
     ///     content: String,
@@ -92,67 +159,479 @@ impl<'a> Collection<'a> {
     /// let response = pb
     ///     .collection("articles")
     ///     .update::<Article>("record_id_123", updated_article)
+    ///     .call()
     ///     .await?;
+    ///
+    /// println!("Final article name: {}", response.record.name);
     /// ```
-    pub async fn update<T: Default + Serialize + Clone + Send>(
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn update<T: Serialize + DeserializeOwned + Send>(
         self,
         record_id: &'a str,
         record: T,
-    ) -> Result<UpdateResponse, UpdateError> {
-        let collection_name = self.name;
+    ) -> CollectionUpdateBuilder<'a, T> {
+        let body = match serde_json::to_value(&record) {
+            Ok(Value::Object(map)) => UpdateBody::Json(map),
+            Ok(value) => UpdateBody::Invalid(format!("expected an object, got `{value}`")),
+            Err(error) => UpdateBody::Invalid(error.to_string()),
+        };
+
+        CollectionUpdateBuilder {
+            client: self.client,
+            collection_name: self.name,
+            record_id,
+            body,
+            expand: None,
+            fields: None,
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Update only the given fields of a single record.
+    ///
+    /// The fields are set with [`CollectionUpdateBuilder::set()`], leaving the other
+    /// fields of the record untouched. `T` is the type the updated record is read into.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = pb
+    ///     .collection("articles")
+    ///     .patch::<Article>("record_id_123")
+    ///     .set("title", "Updated Article Title")
+    ///     .set("views", 0)
+    ///     .call()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn patch<T: DeserializeOwned>(self, record_id: &'a str) -> CollectionUpdateBuilder<'a, T> {
+        CollectionUpdateBuilder {
+            client: self.client,
+            collection_name: self.name,
+            record_id,
+            body: UpdateBody::Json(Map::new()),
+            expand: None,
+            fields: None,
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Update a single record with multipart form data (e.g., to replace a file).
+    ///
+    /// For simple JSON updates without files, use [`Collection::update()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use std::fs;
+    /// use pocketbase_rs::{Form, Part};
+    ///
+    /// let image = fs::read("./vulpes_vulpes.jpg")?;
+    ///
+    /// let image_part = Part::bytes(image)
+    ///     .file_name("vulpes_vulpes")
+    ///     .mime_str("image/jpeg")?;
+    ///
+    /// let form = Form::new().part("illustration", image_part);
+    ///
+    /// let record = pb
+    ///     .collection("foxes")
+    ///     .update_multipart::<Fox>("record_id_123", form)
+    ///     .call()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn update_multipart<T: DeserializeOwned>(
+        self,
+        record_id: &'a str,
+        form: Form,
+    ) -> CollectionUpdateBuilder<'a, T> {
+        CollectionUpdateBuilder {
+            client: self.client,
+            collection_name: self.name,
+            record_id,
+            body: UpdateBody::Multipart(form, Map::new()),
+            expand: None,
+            fields: None,
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T: DeserializeOwned> CollectionUpdateBuilder<'a, T> {
+    /// Auto expand relations of the updated record (up to 6-levels deep).
+    ///
+    /// Expanded relations are appended under the `expand` property.
+    /// Only relations the user has view permissions for will be expanded.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .expand("author")
+    /// ```
+    pub const fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Comma separated string of the fields to return in the response.
+    ///
+    /// The envelope fields of the [`UpdateResponse`] that are not returned are left empty.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .fields("id,title,expand.author.name")
+    /// ```
+    pub const fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Set the value of a field, overriding the value given with the record (if any).
+    ///
+    /// With a multipart body, the fields set on the builder are sent in the `@jsonPayload`
+    /// form field, and must not also be part of the form given to
+    /// [`Collection::update_multipart()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .set("title", "Updated Article Title")
+    /// ```
+    pub fn set<V: Serialize>(self, field: &str, value: V) -> Self {
+        self.modify(field.to_owned(), value, false)
+    }
+
+    /// Append a value to a multiple `relation`, `select` or `file` field
+    /// (using the `field+` modifier).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .append("tags", "rust")
+    /// ```
+    pub fn append<V: Serialize>(self, field: &str, value: V) -> Self {
+        self.modify(format!("{field}+"), value, true)
+    }
+
+    /// Prepend a value to a multiple `relation`, `select` or `file` field
+    /// (using the `+field` modifier).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .prepend("tags", "rust")
+    /// ```
+    pub fn prepend<V: Serialize>(self, field: &str, value: V) -> Self {
+        self.modify(format!("+{field}"), value, true)
+    }
+
+    /// Remove a value from a multiple `relation`, `select` or `file` field
+    /// (using the `field-` modifier).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .remove("tags", "rust")
+    /// ```
+    pub fn remove<V: Serialize>(self, field: &str, value: V) -> Self {
+        self.modify(format!("{field}-"), value, true)
+    }
+
+    /// Upload a new file and append it to a multiple `file` field, keeping the existing ones.
+    ///
+    /// The request is sent as multipart form data.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let part = Part::bytes(document).file_name("report.pdf");
+    ///
+    /// .append_file("documents", part)
+    /// ```
+    pub fn append_file(self, field: &str, part: Part) -> Self {
+        self.push_part(format!("{field}+"), part)
+    }
+
+    /// Upload a new file and prepend it to a multiple `file` field, keeping the existing ones.
+    ///
+    /// The request is sent as multipart form data.
+    pub fn prepend_file(self, field: &str, part: Part) -> Self {
+        self.push_part(format!("+{field}"), part)
+    }
+
+    /// Delete a single file, by its filename, from a `file` field.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .delete_file("documents", "report_52iwbgds7l.pdf")
+    /// ```
+    pub fn delete_file(self, field: &str, filename: &str) -> Self {
+        self.remove(field, filename)
+    }
+
+    fn modify<V: Serialize>(mut self, key: String, value: V, merge: bool) -> Self {
+        let value = match serde_json::to_value(value) {
+            Ok(value) => value,
+            Err(error) => {
+                self.body = UpdateBody::Invalid(error.to_string());
+                return self;
+            }
+        };
+
+        let (UpdateBody::Json(map) | UpdateBody::Multipart(_, map)) = &mut self.body else {
+            return self;
+        };
+
+        // Several values for the same modifier are merged into an array,
+        // while a set value replaces the previous one.
+        match map.remove(&key).filter(|_| merge) {
+            Some(Value::Array(mut values)) => {
+                values.push(value);
+                map.insert(key, Value::Array(values));
+            }
+            Some(previous) => {
+                map.insert(key, Value::Array(vec![previous, value]));
+            }
+            None => {
+                map.insert(key, value);
+            }
+        }
+
+        self
+    }
+
+    fn push_part(mut self, key: String, part: Part) -> Self {
+        self.body = match self.body {
+            UpdateBody::Json(map) => UpdateBody::Multipart(Form::new().part(key, part), map),
+            UpdateBody::Multipart(form, map) => UpdateBody::Multipart(form.part(key, part), map),
+            invalid @ UpdateBody::Invalid(_) => invalid,
+        };
+
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the updated record.
+    pub async fn call(self) -> Result<UpdateResponse<T>, UpdateError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            UpdateError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<UpdateResponse<T>, UpdateError> {
+        validate_record_id(self.record_id).map_err(UpdateError::InvalidRecordId)?;
 
         let endpoint = format!(
             "{}/api/collections/{}/records/{}",
-            self.client.base_url, collection_name, record_id
+            self.client.base_url, self.collection_name, self.record_id
         );
 
+        let request = match self.body {
+            UpdateBody::Json(map) => self.client.request_patch_json(&endpoint, &map),
+            // Files can't be sent in a JSON body, `PocketBase` reads the regular
+            // fields from the special `@jsonPayload` form field instead.
+            UpdateBody::Multipart(form, map) if map.is_empty() => {
+                self.client.request_patch_form(&endpoint, form)
+            }
+            UpdateBody::Multipart(form, map) => {
+                let payload = Value::Object(map).to_string();
+
+                self.client
+                    .request_patch_form(&endpoint, form.text("@jsonPayload", payload))
+            }
+            UpdateBody::Invalid(error) => return Err(UpdateError::InvalidRecord(error)),
+        };
+
+        let mut query_parameters: Vec<(&str, &str)> = vec![];
+
+        if let Some(expand) = self.expand {
+            query_parameters.push(("expand", expand));
+        }
+
+        if let Some(fields) = self.fields {
+            query_parameters.push(("fields", fields));
+        }
+
         let request = self
             .client
-            .request_patch_json(&endpoint, &record)
-            .send()
+            .send_request(self.options.apply(request).query(&query_parameters))
             .await;
 
-        match request {
-            Ok(response) => match response.status() {
-                reqwest::StatusCode::OK => {
-                    let data = response.json::<UpdateResponse>().await;
+        update_processing(request).await
+    }
+}
+
+async fn update_processing<T: DeserializeOwned>(
+    request: Result<reqwest::Response, crate::TransportError>,
+) -> Result<UpdateResponse<T>, UpdateError> {
+    match request {
+        Ok(response) => match response.status() {
+            _ if circuit_breaker::is_open_response(&response) => Err(UpdateError::CircuitOpen),
+            reqwest::StatusCode::OK => {
+                let data = response.json::<UpdateResponse<T>>().await;
 
-                    match data {
-                        Ok(data) => Ok(data),
-                        Err(error) => Err(UpdateError::ParseError(error.to_string())),
-                    }
+                match data {
+                    Ok(data) => Ok(data),
+                    Err(error) => Err(UpdateError::ParseError(error.to_string())),
                 }
+            }
 
-                reqwest::StatusCode::BAD_REQUEST => {
-                    let data = response.json::<BadRequestResponse>().await;
+            reqwest::StatusCode::BAD_REQUEST => {
+                let data = response.json::<BadRequestResponse>().await;
 
-                    match data {
-                        Ok(bad_response) => {
-                            let mut errors: Vec<BadRequestError> = vec![];
+                match data {
+                    Ok(bad_response) => {
+                        let mut errors: Vec<BadRequestError> = vec![];
 
-                            for (error_name, error_data) in bad_response.data {
-                                errors.push(BadRequestError {
-                                    name: error_name,
-                                    code: error_data.code,
-                                    message: error_data.message,
-                                });
-                            }
-
-                            Err(UpdateError::BadRequest(errors))
+                        for (error_name, error_data) in bad_response.data {
+                            errors.push(BadRequestError {
+                                name: error_name,
+                                code: error_data.code,
+                                message: error_data.message,
+                            });
                         }
-                        Err(error) => Err(UpdateError::ParseError(error.to_string())),
+
+                        Err(BadRequestError::not_unique_field(&errors)
+                            .map_or(UpdateError::BadRequest(errors), |field| {
+                                UpdateError::NotUnique { field }
+                            }))
                     }
+                    Err(error) => Err(UpdateError::ParseError(error.to_string())),
                 }
+            }
 
-                reqwest::StatusCode::FORBIDDEN => Err(UpdateError::Forbidden),
-                reqwest::StatusCode::NOT_FOUND => Err(UpdateError::NotFound),
+            reqwest::StatusCode::UNAUTHORIZED => Err(UpdateError::Unauthorized),
+            reqwest::StatusCode::FORBIDDEN => Err(UpdateError::Forbidden),
+            reqwest::StatusCode::NOT_FOUND => Err(UpdateError::NotFound),
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(UpdateError::TooManyRequests),
 
-                _ => Err(UpdateError::UnexpectedResponse(
-                    response.status().to_string(),
-                )),
-            },
+            _ => Err(UpdateError::UnexpectedResponse(
+                RawResponse::from_response(response).await,
+            )),
+        },
 
-            Err(error) => Err(UpdateError::Unreachable(error.to_string())),
-        }
+        Err(error) => Err(UpdateError::Unreachable(error.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::{Value, json};
+
+    use super::UpdateError;
+    use crate::test_utils::assert_error_statuses;
+    use crate::{Part, PocketBase};
+
+    #[tokio::test]
+    async fn error_statuses() {
+        assert_error_statuses!(
+            "PATCH",
+            "/api/collections/articles/records/abc123",
+            |pb| pb.collection("articles").update::<Value>("abc123", json!({ "title": "Hello" })).call(),
+            {
+                401 => UpdateError::Unauthorized,
+                429 => UpdateError::TooManyRequests,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn fields_subset() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.query_param("fields", "title");
+            then.status(200).json_body(json!({ "title": "Hello" }));
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        let response = pb
+            .collection("articles")
+            .update::<Value>("abc123", json!({ "title": "Hello" }))
+            .fields("title")
+            .call()
+            .await
+            .unwrap();
+
+        assert!(response.id.is_empty());
+        assert_eq!(response.record, json!({ "title": "Hello" }));
+    }
+
+    #[tokio::test]
+    async fn multipart_fields_are_sent_once() {
+        let server = MockServer::start_async().await;
+
+        let endpoint = server.mock(|when, then| {
+            when.method("PATCH")
+                .path("/api/collections/articles/records/abc123")
+                .body_contains(r#"{"title":"Updated","views":0}"#)
+                .matches(|request| {
+                    let body = String::from_utf8_lossy(request.body.as_deref().unwrap_or_default());
+
+                    body.matches(r#"name="@jsonPayload""#).count() == 1
+                        && !body.contains(r#"name="title""#)
+                });
+            then.status(200).json_body(json!({ "title": "Updated" }));
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        pb.collection("articles")
+            .patch::<Value>("abc123")
+            .set("title", "Draft")
+            .append_file(
+                "documents",
+                Part::bytes(vec![1, 2, 3]).file_name("report.pdf"),
+            )
+            .set("title", "Updated")
+            .set("views", 0)
+            .call()
+            .await
+            .unwrap();
+
+        endpoint.assert();
     }
 }
@@ -0,0 +1,292 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+
+use crate::error::{ApiErrorResponse, BadRequestError, RawResponse};
+use crate::options::{RequestOptions, cancellable};
+use crate::records::crud::create::CreateError;
+use crate::{Collection, PocketBase, circuit_breaker};
+
+/// Builder for creating or updating a record.
+pub struct CollectionUpsertBuilder<'a, T> {
+    client: &'a PocketBase,
+    collection_name: &'a str,
+    record: T,
+    expand: Option<&'a str>,
+    fields: Option<&'a str>,
+    options: RequestOptions<'a>,
+}
+
+/// The body of a request to the batch API.
+#[derive(Serialize)]
+pub struct BatchRequest<'a, T> {
+    pub requests: Vec<BatchRequestItem<'a, T>>,
+}
+
+#[derive(Serialize)]
+pub struct BatchRequestItem<'a, T> {
+    pub method: &'static str,
+    pub url: String,
+    pub body: &'a T,
+}
+
+#[derive(Deserialize)]
+struct BatchResponseItem {
+    status: u16,
+    #[serde(default)]
+    body: Value,
+}
+
+impl<'a> Collection<'a> {
+    /// Create a record, or update it if a record with the same `id` already exists.
+    ///
+    /// The record is sent as a `PUT` request through the batch API, in a single round trip.
+    /// If the record has no `id`, a new record is always created.
+    ///
+    /// **The batch API must be enabled in the application settings.**
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Article {
+    ///     id: String,
+    ///     name: String,
+    /// }
+    ///
+    /// let article = pb
+    ///     .collection("articles")
+    ///     .upsert::<Article>(Article {
+    ///         id: "article_id_12345".to_string(),
+    ///         name: "test".to_string(),
+    ///     })
+    ///     .call()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn upsert<T: Serialize + DeserializeOwned + Send>(
+        self,
+        record: T,
+    ) -> CollectionUpsertBuilder<'a, T> {
+        CollectionUpsertBuilder {
+            client: self.client,
+            collection_name: self.name,
+            record,
+            expand: None,
+            fields: None,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a, T: Serialize + DeserializeOwned + Send> CollectionUpsertBuilder<'a, T> {
+    /// Auto expand relations of the saved record (up to 6-levels deep).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .expand("author")
+    /// ```
+    pub const fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Comma separated string of the fields to return in the response.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .fields("id,title,expand.author.name")
+    /// ```
+    pub const fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the created or updated record.
+    ///
+    /// Unlike [`Collection::create()`], the record is returned as-is, as `T` has to hold
+    /// the `id` of the record.
+    pub async fn call(self) -> Result<T, CreateError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            CreateError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<T, CreateError> {
+        let request = {
+            let body = BatchRequest {
+                requests: vec![BatchRequestItem {
+                    method: "PUT",
+                    url: record_url(self.collection_name, self.expand, self.fields),
+                    body: &self.record,
+                }],
+            };
+
+            let endpoint = format!("{}/api/batch", self.client.base_url);
+
+            self.client
+                .with_authorization_token(self.client.reqwest_client.post(&endpoint).json(&body))
+        };
+
+        let response = match self.client.send_request(self.options.apply(request)).await {
+            Ok(response) => response,
+            Err(error) => return Err(CreateError::Unreachable(error.to_string())),
+        };
+
+        match response.status() {
+            _ if circuit_breaker::is_open_response(&response) => Err(CreateError::CircuitOpen),
+            reqwest::StatusCode::OK => {
+                let mut results = response
+                    .json::<Vec<BatchResponseItem>>()
+                    .await
+                    .map_err(|error| CreateError::ParseError(error.to_string()))?;
+
+                let Some(result) = results.pop() else {
+                    return Err(CreateError::ParseError(
+                        "The batch response is empty.".to_owned(),
+                    ));
+                };
+
+                if (200..300).contains(&result.status) {
+                    serde_json::from_value::<T>(result.body)
+                        .map_err(|error| CreateError::ParseError(error.to_string()))
+                } else {
+                    Err(batch_item_error(result.status, &result.body))
+                }
+            }
+
+            reqwest::StatusCode::BAD_REQUEST => {
+                let body = response
+                    .json::<Value>()
+                    .await
+                    .map_err(|error| CreateError::ParseError(error.to_string()))?;
+
+                Err(failed_batch_item(&body)
+                    .map_or_else(|| batch_item_error(400, &body), |(_, error)| error))
+            }
+
+            reqwest::StatusCode::UNAUTHORIZED => Err(CreateError::Unauthorized),
+            reqwest::StatusCode::FORBIDDEN => Err(CreateError::Forbidden),
+            reqwest::StatusCode::NOT_FOUND => Err(CreateError::NotFound),
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(CreateError::TooManyRequests),
+
+            _ => Err(CreateError::UnexpectedResponse(
+                RawResponse::from_response(response).await,
+            )),
+        }
+    }
+}
+
+/// Returns the index and the error of the request that made a batch request fail.
+///
+/// A failed batch request holds the response of the failed request, the others being rolled back.
+pub fn failed_batch_item(body: &Value) -> Option<(usize, CreateError)> {
+    let (index, response) = body
+        .pointer("/data/requests")?
+        .as_object()?
+        .iter()
+        .find_map(|(index, failed)| Some((index.parse().ok()?, failed.get("response")?)))?;
+
+    let status = response
+        .get("status")
+        .and_then(Value::as_u64)
+        .and_then(|status| u16::try_from(status).ok())
+        .unwrap_or(400);
+
+    Some((
+        index,
+        batch_item_error(status, response.get("body").unwrap_or(response)),
+    ))
+}
+
+/// Maps the response of a single request of a batch into a [`CreateError`].
+pub fn batch_item_error(status: u16, body: &Value) -> CreateError {
+    match status {
+        400 => {
+            let error =
+                serde_json::from_value::<ApiErrorResponse>(body.clone()).unwrap_or_default();
+            let errors = error.field_errors();
+
+            BadRequestError::not_unique_field(&errors)
+                .map_or(CreateError::BadRequest(errors), |field| {
+                    CreateError::NotUnique { field }
+                })
+        }
+        401 => CreateError::Unauthorized,
+        403 => CreateError::Forbidden,
+        404 => CreateError::NotFound,
+        429 => CreateError::TooManyRequests,
+        _ => CreateError::UnexpectedResponse(RawResponse {
+            status,
+            body: body.to_string(),
+            error: serde_json::from_value(body.clone()).ok(),
+            headers: Box::default(),
+        }),
+    }
+}
+
+/// Returns the path (relative to the base URL) of the records of a collection, with the query parameters.
+pub fn record_url(collection_name: &str, expand: Option<&str>, fields: Option<&str>) -> String {
+    let path = format!("/api/collections/{collection_name}/records");
+
+    // The URL is only used to encode the query parameters
+    let Ok(mut url) = reqwest::Url::parse(&format!("http://localhost{path}")) else {
+        return path;
+    };
+
+    for (key, value) in [("expand", expand), ("fields", fields)] {
+        if let Some(value) = value {
+            url.query_pairs_mut().append_pair(key, value);
+        }
+    }
+
+    match url.query() {
+        Some(query) => format!("{path}?{query}"),
+        None => path,
+    }
+}
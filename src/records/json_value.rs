@@ -0,0 +1,103 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// The value of a `json` field, telling a missing field apart from a `null` one.
+///
+/// `PocketBase` stores `null` in an empty `json` field, and omits the field when it was
+/// excluded with `fields`. When updating a record, a missing field is left untouched while
+/// a `null` one is cleared.
+///
+/// Use it with `#[serde(default, skip_serializing_if = "JsonValue::is_undefined")]` so that
+/// an undefined value is neither required when reading, nor sent when writing.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Article {
+///     title: String,
+///     #[serde(default, skip_serializing_if = "JsonValue::is_undefined")]
+///     metadata: JsonValue<Metadata>,
+/// }
+///
+/// // Clears the `metadata` field, leaving the other fields untouched
+/// #[derive(Serialize, Deserialize)]
+/// struct ClearMetadata {
+///     metadata: JsonValue<Metadata>,
+/// }
+///
+/// pb.collection("articles")
+///     .update::<ClearMetadata>("record_id_123", ClearMetadata { metadata: JsonValue::Null })
+///     .call()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum JsonValue<T = Value> {
+    /// The field is missing.
+    #[default]
+    Undefined,
+    /// The field is `null`.
+    Null,
+    /// The field holds a value.
+    Value(T),
+}
+
+impl<T> JsonValue<T> {
+    /// Returns `true` if the field is missing.
+    #[must_use]
+    pub const fn is_undefined(&self) -> bool {
+        matches!(self, Self::Undefined)
+    }
+
+    /// Returns `true` if the field is `null`.
+    #[must_use]
+    pub const fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns a reference to the value, if any.
+    #[must_use]
+    pub const fn get(&self) -> Option<&T> {
+        match self {
+            Self::Value(value) => Some(value),
+            Self::Undefined | Self::Null => None,
+        }
+    }
+
+    /// Consumes the field and returns the value, if any.
+    #[must_use]
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::Value(value) => Some(value),
+            Self::Undefined | Self::Null => None,
+        }
+    }
+}
+
+impl<T> From<T> for JsonValue<T> {
+    fn from(value: T) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl<T> From<Option<T>> for JsonValue<T> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Self::Value)
+    }
+}
+
+/// An undefined value serializes to `null`, skip it with `skip_serializing_if` to leave
+/// the field untouched.
+impl<T: Serialize> Serialize for JsonValue<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Value(value) => value.serialize(serializer),
+            Self::Undefined | Self::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for JsonValue<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(Into::into)
+    }
+}
@@ -1,2 +1,9 @@
 pub mod auth;
 pub mod crud;
+pub mod json_value;
+pub mod model;
+pub mod raw;
+pub mod relation;
+pub mod system;
+pub mod typed;
+pub mod view;
@@ -0,0 +1,235 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::Timestamp;
+
+/// Holds the relations expanded with `.expand()`.
+///
+/// `PocketBase` omits the `expand` property when nothing was expanded (or when the
+/// user lacks view permissions on the relations), so the content is optional.
+///
+/// Single relations expand into an object and multiple relations into an array,
+/// so `T` is usually a struct whose fields are the expanded relation names:
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Deserialize)]
+/// struct ArticleExpand {
+///     author: Author,
+///     #[serde(default)]
+///     tags: Vec<Tag>,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Article {
+///     title: String,
+///     #[serde(default)]
+///     expand: Expand<ArticleExpand>,
+/// }
+///
+/// let article = pb
+///     .collection("articles")
+///     .get_one::<Article>("record_id_123")
+///     .expand("author,tags")
+///     .call()
+///     .await?;
+///
+/// if let Some(expand) = article.expand.get() {
+///     println!("Written by {}", expand.author.name);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Expand<T>(Option<T>);
+
+impl<T> Default for Expand<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> Expand<T> {
+    /// Returns a reference to the expanded relations, if any.
+    #[must_use]
+    pub const fn get(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+
+    /// Consumes the wrapper and returns the expanded relations, if any.
+    #[must_use]
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+
+    /// Returns `true` if no relation was expanded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl Expand<Value> {
+    /// Deserializes the relation expanded at the given path, if any.
+    ///
+    /// The path follows the `.expand()` syntax, nested relations (including back-relations
+    /// such as `comments_via_post.user`) being separated with dots. When a multiple relation
+    /// is traversed, the nested relations of all its records are collected into an array.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let post = pb
+    ///     .collection("posts")
+    ///     .get_one::<Record<Post>>("record_id_123")
+    ///     .expand("author,comments_via_post.user")
+    ///     .call()
+    ///     .await?;
+    ///
+    /// let author: Option<User> = post.expand.get_as("author")?;
+    /// let commenters: Option<Vec<User>> = post.expand.get_as("comments_via_post.user")?;
+    /// ```
+    pub fn get_as<R: DeserializeOwned>(&self, path: &str) -> Result<Option<R>, serde_json::Error> {
+        let Some(value) = self.0.as_ref().and_then(|expand| {
+            let mut segments = path.split('.');
+            let first = expand.get(segments.next()?)?.clone();
+
+            segments.try_fold(first, |value, segment| nested_expand(&value, segment))
+        }) else {
+            return Ok(None);
+        };
+
+        serde_json::from_value(value).map(Some)
+    }
+}
+
+/// Returns the relation expanded under `field` in the record (or records) `value`.
+fn nested_expand(value: &Value, field: &str) -> Option<Value> {
+    match value {
+        Value::Object(record) => record.get("expand")?.get(field).cloned(),
+        Value::Array(records) => Some(Value::Array(
+            records
+                .iter()
+                .filter_map(|record| nested_expand(record, field))
+                .flat_map(|value| match value {
+                    Value::Array(values) => values,
+                    value => vec![value],
+                })
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+impl<T> From<Option<T>> for Expand<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+/// A record envelope holding the system fields common to every record.
+///
+/// The collection-specific fields are deserialized into `data`, and the expanded
+/// relations (if any) into `expand`. When no expand type is given, the expanded
+/// relations are kept as a raw [`serde_json::Value`].
+///
+/// Note that `id`, `collectionId`, `collectionName`, `created`, `updated` and `expand`
+/// are captured by this envelope and are therefore not forwarded to `T`.
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::Record;
+///
+/// let article = pb
+///     .collection("articles")
+///     .get_one::<Record<Article, ArticleExpand>>("record_id_123")
+///     .expand("author")
+///     .call()
+///     .await?;
+///
+/// println!("{} was created on {}", article.data.title, article.created);
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Record<T, E = Value> {
+    /// The record's unique ID.
+    pub id: String,
+    /// The ID of the collection the record belongs to.
+    #[serde(default)]
+    pub collection_id: String,
+    /// The name of the collection the record belongs to.
+    #[serde(default)]
+    pub collection_name: String,
+    /// The timestamp when the record was created *(empty if the collection has no `created` field)*.
+    #[serde(default)]
+    pub created: Timestamp,
+    /// The timestamp when the record was last updated *(empty if the collection has no `updated` field)*.
+    #[serde(default)]
+    pub updated: Timestamp,
+    /// The expanded relations.
+    #[serde(default, skip_serializing_if = "Expand::is_empty")]
+    pub expand: Expand<E>,
+    /// The actual record data.
+    #[serde(flatten)]
+    pub data: T,
+}
+
+impl<T> Record<T, Value> {
+    /// Deserializes the relation expanded at the given path, if any. See [`Expand::get_as()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let comments: Option<Vec<Comment>> = post.expand("comments_via_post")?;
+    /// ```
+    pub fn expand<R: DeserializeOwned>(&self, path: &str) -> Result<Option<R>, serde_json::Error> {
+        self.expand.get_as(path)
+    }
+}
+
+/// A record whose fields are not known at compile time.
+///
+/// The system fields are read into the [`Record`] envelope, and the other fields are kept
+/// in a JSON map. Any read builder accepts it, as well as a plain [`serde_json::Value`].
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::DynamicRecord;
+///
+/// let records = pb
+///     .collection(collection_name)
+///     .get_full_list::<DynamicRecord>()
+///     .call()
+///     .await?;
+///
+/// for record in records {
+///     let title: Option<String> = record.get_as("title")?;
+///     println!("{} ({}): {title:?}", record.id, record.collection_name);
+/// }
+/// ```
+pub type DynamicRecord = Record<Map<String, Value>>;
+
+impl Record<Map<String, Value>> {
+    /// Returns the value of the given field, if any.
+    #[must_use]
+    pub fn get(&self, field: &str) -> Option<&Value> {
+        self.data.get(field)
+    }
+
+    /// Deserializes the value of the given field, if any.
+    pub fn get_as<V: DeserializeOwned>(&self, field: &str) -> Result<Option<V>, serde_json::Error> {
+        self.data
+            .get(field)
+            .map(|value| V::deserialize(value))
+            .transpose()
+    }
+
+    /// Sets the value of the given field, returning its previous value.
+    pub fn set(&mut self, field: impl Into<String>, value: impl Into<Value>) -> Option<Value> {
+        self.data.insert(field.into(), value.into())
+    }
+
+    /// Returns the fields of the record, along with their values.
+    #[must_use]
+    pub const fn fields(&self) -> &Map<String, Value> {
+        &self.data
+    }
+}
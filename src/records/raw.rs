@@ -0,0 +1,111 @@
+use bytes::Bytes;
+use serde::Deserialize;
+use serde_json::value::RawValue;
+
+use crate::RecordList;
+use crate::error::RequestError;
+
+/// The raw body of a successful response, returned by the `call_raw()` variants of the builders.
+///
+/// The body is kept as-is, so that it can be forwarded without being parsed, or deserialized
+/// into types borrowing from it (e.g. `&str` fields) instead of allocating a copy of every value.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Deserialize)]
+/// struct ArticleRef<'a> {
+///     id: &'a str,
+///     title: &'a str,
+/// }
+///
+/// let raw = pb
+///     .collection("articles")
+///     .get_list::<IgnoredAny>()
+///     .per_page(500)
+///     .call_raw()
+///     .await?;
+///
+/// let list = raw.parse::<RecordList<ArticleRef<'_>>>()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RawBody {
+    /// The HTTP status code of the response.
+    pub status: reqwest::StatusCode,
+    /// The HTTP headers of the response.
+    pub headers: reqwest::header::HeaderMap,
+    body: Bytes,
+}
+
+impl RawBody {
+    /// Reads the body of a successful response.
+    pub(crate) async fn from_response(response: reqwest::Response) -> Result<Self, RequestError> {
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let body = response.bytes().await.map_err(|error| {
+            if error.is_timeout() {
+                RequestError::Unreachable
+            } else {
+                RequestError::ParseError(error.to_string())
+            }
+        })?;
+
+        Ok(Self {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    /// Returns the body of the response.
+    #[must_use]
+    pub const fn bytes(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Consumes the response and returns its body.
+    #[must_use]
+    pub fn into_bytes(self) -> Bytes {
+        self.body
+    }
+
+    /// Deserializes the body, allowing `T` to borrow from it.
+    pub fn parse<'de, T: Deserialize<'de>>(&'de self) -> Result<T, RequestError> {
+        serde_json::from_slice(&self.body)
+            .map_err(|error| RequestError::ParseError(error.to_string()))
+    }
+
+    /// Reads the body as a records list without deserializing the records.
+    ///
+    /// The records are only deserialized when accessed with [`RecordList::parse_item()`]
+    /// or [`RecordList::parse_items()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let list = raw.records()?;
+    ///
+    /// // Only the first record is deserialized
+    /// let first: Option<ArticleRef<'_>> = list.parse_item(0).transpose()?;
+    /// ```
+    pub fn records(&self) -> Result<RecordList<&RawValue>, RequestError> {
+        self.parse()
+    }
+}
+
+impl<'a> RecordList<&'a RawValue> {
+    /// Deserializes the record at the given index, if any.
+    pub fn parse_item<T: Deserialize<'a>>(&self, index: usize) -> Option<Result<T, RequestError>> {
+        self.items.get(index).copied().map(parse_raw)
+    }
+
+    /// Deserializes the records one at a time, as the iterator is consumed.
+    pub fn parse_items<'s, T: Deserialize<'a> + 's>(
+        &'s self,
+    ) -> impl Iterator<Item = Result<T, RequestError>> + 's {
+        self.items.iter().copied().map(parse_raw)
+    }
+}
+
+fn parse_raw<'a, T: Deserialize<'a>>(item: &'a RawValue) -> Result<T, RequestError> {
+    serde_json::from_str(item.get()).map_err(|error| RequestError::ParseError(error.to_string()))
+}
@@ -0,0 +1,297 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// The value of a single `relation` field: the id of the related record, and the record
+/// itself when it was expanded.
+///
+/// A `Relation` always serializes to the id of the related record, so the same struct can be
+/// used to read and write records. When reading, it accepts either an id or an expanded record
+/// *(see [`InlineExpand`])*. An unset relation has an empty id.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Article {
+///     title: String,
+///     author: Relation<Author>,
+///     tags: Relations<Tag>,
+/// }
+///
+/// let article = pb
+///     .collection("articles")
+///     .get_one::<InlineExpand<Article>>("record_id_123")
+///     .expand("author,tags")
+///     .call()
+///     .await?;
+///
+/// if let Some(author) = article.author.record() {
+///     println!("Written by {}", author.name);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Relation<T = Value> {
+    id: String,
+    record: Option<T>,
+}
+
+impl<T> Relation<T> {
+    /// Creates a relation to the record with the given id.
+    #[must_use]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            record: None,
+        }
+    }
+
+    /// Returns the id of the related record *(empty if the relation is unset)*.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns `true` if the relation is unset.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.id.is_empty()
+    }
+
+    /// Returns the related record, if it was expanded.
+    #[must_use]
+    pub const fn record(&self) -> Option<&T> {
+        self.record.as_ref()
+    }
+
+    /// Consumes the relation and returns the related record, if it was expanded.
+    #[must_use]
+    pub fn into_record(self) -> Option<T> {
+        self.record
+    }
+
+    /// Returns `true` if the related record was expanded.
+    #[must_use]
+    pub const fn is_expanded(&self) -> bool {
+        self.record.is_some()
+    }
+}
+
+impl<T> Default for Relation<T> {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+impl<T> From<&str> for Relation<T> {
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl<T> From<String> for Relation<T> {
+    fn from(id: String) -> Self {
+        Self::new(id)
+    }
+}
+
+impl<T> Serialize for Relation<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.id)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Relation<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::from_value(Value::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+impl<T: DeserializeOwned> Relation<T> {
+    fn from_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Null => Ok(Self::default()),
+            Value::String(id) => Ok(Self::new(id)),
+            Value::Object(object) => {
+                let id = object
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .ok_or("expanded relation is missing its `id`")?
+                    .to_owned();
+
+                let record = serde_json::from_value(Value::Object(object))
+                    .map_err(|error| error.to_string())?;
+
+                Ok(Self {
+                    id,
+                    record: Some(record),
+                })
+            }
+            other => Err(format!(
+                "expected a record id or an expanded record, found `{other}`"
+            )),
+        }
+    }
+}
+
+/// The value of a multiple `relation` field.
+///
+/// Like [`Relation`], it serializes to the ids of the related records, and accepts either
+/// ids or expanded records when reading.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Relations<T = Value>(Vec<Relation<T>>);
+
+impl<T> Relations<T> {
+    /// Returns the ids of the related records.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(Relation::id)
+    }
+
+    /// Returns the related records that were expanded.
+    pub fn records(&self) -> impl Iterator<Item = &T> {
+        self.0.iter().filter_map(Relation::record)
+    }
+
+    /// Consumes the relations and returns the inner list.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<Relation<T>> {
+        self.0
+    }
+}
+
+impl<T> Default for Relations<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> Deref for Relations<T> {
+    type Target = Vec<Relation<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Relations<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, R: Into<Relation<T>>> FromIterator<R> for Relations<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        Self(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T> IntoIterator for Relations<T> {
+    type Item = Relation<T>;
+    type IntoIter = std::vec::IntoIter<Relation<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> Serialize for Relations<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.ids())
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Relations<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = match Value::deserialize(deserializer)? {
+            Value::Null => Vec::new(),
+            Value::Array(values) => values,
+            Value::String(id) if id.is_empty() => Vec::new(),
+            value => vec![value],
+        };
+
+        values
+            .into_iter()
+            .map(Relation::from_value)
+            .collect::<Result<_, _>>()
+            .map(Self)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// A record whose expanded relations replace the relation ids before being deserialized.
+///
+/// `PocketBase` returns the expanded relations under the `expand` property, leaving the ids in
+/// the relation fields. Wrapping the record type in `InlineExpand` moves every expanded
+/// relation into its field (recursively for nested expands), so that [`Relation`] and
+/// [`Relations`] fields hold the expanded records.
+///
+/// Note that the ids of the related records the user can't view are dropped from multiple
+/// relations that were expanded.
+///
+/// # Example
+/// ```rust,ignore
+/// let article = pb
+///     .collection("articles")
+///     .get_one::<InlineExpand<Article>>("record_id_123")
+///     .expand("author")
+///     .call()
+///     .await?
+///     .into_inner();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InlineExpand<T>(pub T);
+
+impl<T> InlineExpand<T> {
+    /// Consumes the wrapper and returns the record.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for InlineExpand<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for InlineExpand<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Serialize> Serialize for InlineExpand<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for InlineExpand<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = Value::deserialize(deserializer)?;
+        inline_expand(&mut value);
+
+        serde_json::from_value(value)
+            .map(Self)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Moves the content of the `expand` property into the record fields, recursively.
+fn inline_expand(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::Object(expand)) = object.remove("expand") {
+                for (field, mut expanded) in expand {
+                    inline_expand(&mut expanded);
+                    object.insert(field, expanded);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(inline_expand),
+        _ => {}
+    }
+}
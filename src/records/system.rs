@@ -0,0 +1,196 @@
+use serde::Deserialize;
+
+use crate::records::auth::external_auths::EXTERNAL_AUTHS_COLLECTION;
+use crate::records::auth::superusers::SUPERUSERS_COLLECTION;
+use crate::{ExternalAuth, PocketBase, PocketBaseRecord, Records, Timestamp};
+
+/// Name of the system collection storing the one-time passwords sent to auth records.
+pub const OTPS_COLLECTION: &str = "_otps";
+
+/// Name of the system collection storing the pending multi-factor authentications.
+pub const MFAS_COLLECTION: &str = "_mfas";
+
+/// Name of the system collection storing the devices (origins) auth records logged in from.
+pub const AUTH_ORIGINS_COLLECTION: &str = "_authOrigins";
+
+/// Typed access to the system collections, created with [`PocketBase::system()`].
+///
+/// The system collections are only accessible to superusers.
+pub struct SystemCollections<'a> {
+    client: &'a PocketBase,
+}
+
+/// Represents a record of the `_superusers` system collection.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Superuser {
+    /// The superuser unique ID.
+    pub id: String,
+    /// The email of the superuser.
+    pub email: String,
+    /// Whether the email of the superuser has been verified.
+    pub verified: bool,
+    /// The timestamp when the superuser was created.
+    #[serde(default)]
+    pub created: Timestamp,
+    /// The timestamp when the superuser was last updated.
+    #[serde(default)]
+    pub updated: Timestamp,
+}
+
+/// Represents a record of the `_otps` system collection, a one-time password sent to an auth record.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Otp {
+    /// The OTP unique ID, to give to `auth_with_otp()`.
+    pub id: String,
+    /// The ID of the collection the auth record belongs to.
+    pub collection_ref: String,
+    /// The ID of the auth record.
+    pub record_ref: String,
+    /// The email the OTP was sent to, if any.
+    pub sent_to: String,
+    /// The timestamp when the OTP was created.
+    #[serde(default)]
+    pub created: Timestamp,
+    /// The timestamp when the OTP was last updated.
+    #[serde(default)]
+    pub updated: Timestamp,
+}
+
+/// Represents a record of the `_mfas` system collection, a pending multi-factor authentication.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Mfa {
+    /// The MFA unique ID, to give as `mfa_id` to the second authentication.
+    pub id: String,
+    /// The ID of the collection the auth record belongs to.
+    pub collection_ref: String,
+    /// The ID of the auth record.
+    pub record_ref: String,
+    /// The authentication method used for the first factor *(example: `password`)*.
+    pub method: String,
+    /// The timestamp when the MFA was created.
+    #[serde(default)]
+    pub created: Timestamp,
+    /// The timestamp when the MFA was last updated.
+    #[serde(default)]
+    pub updated: Timestamp,
+}
+
+/// Represents a record of the `_authOrigins` system collection, a device an auth record logged in from.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AuthOrigin {
+    /// The auth origin unique ID.
+    pub id: String,
+    /// The ID of the collection the auth record belongs to.
+    pub collection_ref: String,
+    /// The ID of the auth record.
+    pub record_ref: String,
+    /// The fingerprint of the device (user agent and IP address).
+    pub fingerprint: String,
+    /// The timestamp of the first login from this device.
+    #[serde(default)]
+    pub created: Timestamp,
+    /// The timestamp of the last login from this device.
+    #[serde(default)]
+    pub updated: Timestamp,
+}
+
+impl PocketBaseRecord for Superuser {
+    const COLLECTION: &'static str = SUPERUSERS_COLLECTION;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl PocketBaseRecord for Otp {
+    const COLLECTION: &'static str = OTPS_COLLECTION;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl PocketBaseRecord for Mfa {
+    const COLLECTION: &'static str = MFAS_COLLECTION;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl PocketBaseRecord for AuthOrigin {
+    const COLLECTION: &'static str = AUTH_ORIGINS_COLLECTION;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl PocketBaseRecord for ExternalAuth {
+    const COLLECTION: &'static str = EXTERNAL_AUTHS_COLLECTION;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl PocketBase {
+    /// Creates a typed access to the system collections.
+    ///
+    /// Requires to be authenticated as a superuser.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let sessions = pb
+    ///     .system()
+    ///     .auth_origins()
+    ///     .get_full_list()
+    ///     .filter("recordRef='USER_RECORD_ID'")
+    ///     .call()
+    ///     .await?;
+    ///
+    /// for session in sessions {
+    ///     pb.system().auth_origins().delete(&session.id).await?;
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn system(&self) -> SystemCollections<'_> {
+        SystemCollections { client: self }
+    }
+}
+
+impl<'a> SystemCollections<'a> {
+    /// The `_superusers` collection.
+    #[must_use]
+    pub fn superusers(&self) -> Records<'a, Superuser> {
+        self.client.records()
+    }
+
+    /// The `_otps` collection.
+    #[must_use]
+    pub fn otps(&self) -> Records<'a, Otp> {
+        self.client.records()
+    }
+
+    /// The `_mfas` collection.
+    #[must_use]
+    pub fn mfas(&self) -> Records<'a, Mfa> {
+        self.client.records()
+    }
+
+    /// The `_externalAuths` collection.
+    #[must_use]
+    pub fn external_auths(&self) -> Records<'a, ExternalAuth> {
+        self.client.records()
+    }
+
+    /// The `_authOrigins` collection.
+    #[must_use]
+    pub fn auth_origins(&self) -> Records<'a, AuthOrigin> {
+        self.client.records()
+    }
+}
@@ -0,0 +1,140 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::records::crud::create::CollectionCreateBuilder;
+use crate::records::crud::delete::CollectionDeleteBuilder;
+use crate::records::crud::get_first_list_item::CollectionGetFirstListItemBuilder;
+use crate::records::crud::get_full_list::CollectionGetFullListBuilder;
+use crate::records::crud::get_list::CollectionGetListBuilder;
+use crate::records::crud::get_one::CollectionGetOneBuilder;
+use crate::records::crud::update::CollectionUpdateBuilder;
+use crate::records::crud::upsert::CollectionUpsertBuilder;
+use crate::{Collection, PocketBase};
+
+/// A type stored in a `PocketBase` collection.
+///
+/// Implementing this trait couples the type to its collection, so that the records can be
+/// queried with [`PocketBase::records()`] without repeating the collection name.
+///
+/// With the `derive` feature, the trait can be derived. The collection name is given with
+/// the `#[pocketbase(collection = "...")]` attribute, and the id is read from the `id` field
+/// (or the field marked with `#[pocketbase(id)]`). The derive also generates a `fields()`
+/// accessor returning a [`Field`](crate::Field) per struct field.
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::PocketBaseRecord;
+///
+/// #[derive(Serialize, Deserialize, PocketBaseRecord)]
+/// #[pocketbase(collection = "articles")]
+/// struct Article {
+///     id: String,
+///     title: String,
+/// }
+///
+/// let articles = pb.records::<Article>().get_list().call().await?;
+/// ```
+pub trait PocketBaseRecord: DeserializeOwned {
+    /// The name of the collection the records are stored in.
+    const COLLECTION: &'static str;
+
+    /// Returns the id of the record.
+    fn id(&self) -> &str;
+}
+
+/// Typed access to the records of a collection, created with [`PocketBase::records()`].
+///
+/// Every operation uses the collection and the record type given by `T`.
+pub struct Records<'a, T> {
+    collection: Collection<'a>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl PocketBase {
+    /// Creates a typed access to the records of the collection `T` is stored in.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let article = pb
+    ///     .records::<Article>()
+    ///     .get_one("record_id_123")
+    ///     .call()
+    ///     .await?;
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if [`PocketBaseRecord::COLLECTION`] is empty or contains
+    /// invalid characters.
+    #[must_use]
+    pub fn records<T: PocketBaseRecord>(&self) -> Records<'_, T> {
+        Records {
+            collection: self.collection(T::COLLECTION),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T: PocketBaseRecord + Send> Records<'a, T> {
+    /// Returns the untyped [`Collection`], for the operations not covered by this wrapper.
+    #[must_use]
+    pub const fn collection(&self) -> &Collection<'a> {
+        &self.collection
+    }
+
+    /// Fetch a single record. See [`Collection::get_one()`].
+    #[must_use]
+    pub const fn get_one(&self, record_id: &'a str) -> CollectionGetOneBuilder<'a, T> {
+        self.collection.get_one(record_id)
+    }
+
+    /// Fetch a paginated records list. See [`Collection::get_list()`].
+    #[must_use]
+    pub const fn get_list(&self) -> CollectionGetListBuilder<'a, T> {
+        self.collection.get_list()
+    }
+
+    /// Fetch the first record matching a filter. See [`Collection::get_first_list_item()`].
+    #[must_use]
+    pub const fn get_first_list_item(&self) -> CollectionGetFirstListItemBuilder<'a, T> {
+        self.collection.get_first_list_item()
+    }
+
+    /// Fetch all the records. See [`Collection::get_full_list()`].
+    #[must_use]
+    pub const fn get_full_list(&self) -> CollectionGetFullListBuilder<'a, T> {
+        self.collection.get_full_list()
+    }
+
+    /// Update only the given fields of an existing record. See [`Collection::patch()`].
+    #[must_use]
+    pub fn patch(&self, record_id: &'a str) -> CollectionUpdateBuilder<'a, T> {
+        self.collection.patch(record_id)
+    }
+
+    /// Delete a single record. See [`Collection::delete()`].
+    #[must_use]
+    pub const fn delete(&self, record_id: &'a str) -> CollectionDeleteBuilder<'a> {
+        self.collection.delete(record_id)
+    }
+}
+
+impl<'a, T: PocketBaseRecord + Serialize + Send> Records<'a, T> {
+    /// Create a new record. See [`Collection::create()`].
+    #[must_use]
+    pub fn create(&self, record: T) -> CollectionCreateBuilder<'a, T> {
+        self.collection.create(record)
+    }
+
+    /// Update an existing record. See [`Collection::update()`].
+    #[must_use]
+    pub fn update(&self, record_id: &'a str, record: T) -> CollectionUpdateBuilder<'a, T> {
+        self.collection.update(record_id, record)
+    }
+
+    /// Create a record, or update it if it already exists. See [`Collection::upsert()`].
+    #[must_use]
+    pub const fn upsert(&self, record: T) -> CollectionUpsertBuilder<'a, T> {
+        self.collection.upsert(record)
+    }
+}
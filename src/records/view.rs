@@ -0,0 +1,101 @@
+use serde::de::DeserializeOwned;
+
+use crate::Collection;
+use crate::records::crud::count::CollectionCountBuilder;
+use crate::records::crud::exists::CollectionExistsBuilder;
+use crate::records::crud::get_first_list_item::CollectionGetFirstListItemBuilder;
+use crate::records::crud::get_full_list::CollectionGetFullListBuilder;
+use crate::records::crud::get_list::CollectionGetListBuilder;
+use crate::records::crud::get_one::CollectionGetOneBuilder;
+
+/// Read-only access to a view collection, created with [`Collection::as_view()`].
+///
+/// View collections are backed by a SQL `SELECT` statement, so their records can't be
+/// created, updated or deleted. This wrapper only exposes the read operations, turning
+/// such misuse into a compile-time error. As they emit no realtime events, they can't be
+/// subscribed to either.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Deserialize)]
+/// struct PostStats {
+///     id: String,
+///     total_comments: u64,
+/// }
+///
+/// let stats = pb.collection("post_stats").as_view();
+///
+/// let top = stats
+///     .get_list::<PostStats>()
+///     .sort("-total_comments")
+///     .call()
+///     .await?;
+///
+/// // Doesn't compile: `ViewCollection` has no `create` method
+/// // stats.create(PostStats::default());
+/// ```
+#[derive(Clone, Copy)]
+pub struct ViewCollection<'a> {
+    collection: Collection<'a>,
+}
+
+impl<'a> Collection<'a> {
+    /// Restrict this collection to the read operations, for view collections.
+    ///
+    /// See [`ViewCollection`].
+    #[must_use]
+    pub const fn as_view(self) -> ViewCollection<'a> {
+        ViewCollection { collection: self }
+    }
+}
+
+impl<'a> ViewCollection<'a> {
+    /// Fetch a single record. See [`Collection::get_one()`].
+    #[must_use]
+    pub const fn get_one<T: DeserializeOwned + Send>(
+        self,
+        record_id: &'a str,
+    ) -> CollectionGetOneBuilder<'a, T> {
+        self.collection.get_one(record_id)
+    }
+
+    /// Fetch a paginated records list. See [`Collection::get_list()`].
+    #[must_use]
+    pub const fn get_list<T: DeserializeOwned + Send>(self) -> CollectionGetListBuilder<'a, T> {
+        self.collection.get_list()
+    }
+
+    /// Fetch the first record matching a filter. See [`Collection::get_first_list_item()`].
+    #[must_use]
+    pub const fn get_first_list_item<T: DeserializeOwned + Send>(
+        self,
+    ) -> CollectionGetFirstListItemBuilder<'a, T> {
+        self.collection.get_first_list_item()
+    }
+
+    /// Fetch all the records. See [`Collection::get_full_list()`].
+    #[must_use]
+    pub const fn get_full_list<T: DeserializeOwned + Send>(
+        self,
+    ) -> CollectionGetFullListBuilder<'a, T> {
+        self.collection.get_full_list()
+    }
+
+    /// Count the records. See [`Collection::count()`].
+    #[must_use]
+    pub fn count(self) -> CollectionCountBuilder<'a> {
+        self.collection.count()
+    }
+
+    /// Check whether a record exists. See [`Collection::exists()`].
+    #[must_use]
+    pub fn exists(self, record_id: &'a str) -> CollectionExistsBuilder<'a> {
+        self.collection.exists(record_id)
+    }
+
+    /// Check whether a record matches a filter. See [`Collection::exists_where()`].
+    #[must_use]
+    pub fn exists_where(self, filter: &'a str) -> CollectionExistsBuilder<'a> {
+        self.collection.exists_where(filter)
+    }
+}
@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use reqwest::header::{HeaderName, RETRY_AFTER};
+use reqwest::{Method, Request, RequestBuilder, Response, StatusCode};
+
+use crate::{PocketBase, TransportError, circuit_breaker};
+
+/// Header marking a non-idempotent request as safe to retry.
+pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Policy used to automatically retry failed idempotent requests.
+///
+/// Only `GET`, `HEAD`, `PUT`, `DELETE` and `OPTIONS` requests are retried, along with the
+/// requests having an `Idempotency-Key` header (e.g. a create marked as `.idempotent()`).
+/// Requests whose body is streamed (e.g. a backup upload) can't be replayed and are never retried.
+///
+/// # Example
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use pocketbase_rs::{PocketBase, RetryPolicy};
+///
+/// let pb = PocketBase::new("http://localhost:8090");
+///
+/// pb.set_retry_policy(RetryPolicy {
+///     max_retries: 5,
+///     backoff: Duration::from_millis(500),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum amount of retries after the first attempt *(default to 3)*.
+    pub max_retries: u32,
+    /// The delay before the first retry, doubled after each attempt *(default to 200ms)*.
+    pub backoff: Duration,
+    /// The maximum delay between two attempts, including the delays asked by a
+    /// `Retry-After` header *(default to 10s)*.
+    pub max_backoff: Duration,
+    /// The HTTP status codes triggering a retry *(default to 429, 500, 502, 503 and 504)*.
+    ///
+    /// Connection errors and timeouts are always retried.
+    pub retry_on: Vec<StatusCode>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            retry_on: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the delay to wait for before the given retry attempt (starting at 0).
+    fn backoff_for(&self, attempt: u32) -> Duration {
+        self.backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+/// Sends the request, retrying it according to the given policy.
+pub async fn send_with_retry(
+    client: &PocketBase,
+    policy: &RetryPolicy,
+    request: RequestBuilder,
+) -> Result<Response, TransportError> {
+    let (http_client, request) = request.build_split();
+    let request = request?;
+
+    if !is_idempotent(&request) {
+        return client.transmit(&http_client, request).await;
+    }
+
+    let mut attempt = 0;
+
+    loop {
+        // The last attempt (or a request that can't be cloned) consumes the original request
+        let Some(attempt_request) = (attempt < policy.max_retries)
+            .then(|| request.try_clone())
+            .flatten()
+        else {
+            return client.transmit(&http_client, request).await;
+        };
+
+        let result = client.transmit(&http_client, attempt_request).await;
+
+        let delay = match &result {
+            Ok(response) if circuit_breaker::is_open_response(response) => return result,
+            Ok(response) if policy.retry_on.contains(&response.status()) => retry_after(response)
+                .map_or_else(
+                    || policy.backoff_for(attempt),
+                    |delay| delay.min(policy.max_backoff),
+                ),
+            Err(error) if error.is_connect() || error.is_timeout() => policy.backoff_for(attempt),
+            _ => return result,
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            attempt = attempt + 1,
+            status = result.as_ref().ok().map(|response| response.status().as_u16()),
+            delay = ?delay,
+            "retrying PocketBase request"
+        );
+
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+fn is_idempotent(request: &Request) -> bool {
+    [
+        Method::GET,
+        Method::HEAD,
+        Method::PUT,
+        Method::DELETE,
+        Method::OPTIONS,
+    ]
+    .contains(request.method())
+        || request.headers().contains_key(IDEMPOTENCY_KEY)
+}
+
+/// Parses the delay (in seconds) asked by the `Retry-After` header.
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
@@ -0,0 +1,150 @@
+use reqwest::{Method, Request, Url};
+
+use crate::PocketBase;
+
+/// Returns `true` if the request only reads data, and can be sent to another instance.
+///
+/// The realtime connection is excluded, as the subscriptions are bound to the instance
+/// holding the connection.
+pub fn is_read(request: &Request) -> bool {
+    matches!(*request.method(), Method::GET | Method::HEAD)
+        && !request.url().path().ends_with("/api/realtime")
+}
+
+/// Returns the given URL, moved from the `from` base URL to the `to` base URL.
+///
+/// The URLs are compared once parsed, so that the base URLs don't have to be normalized
+/// (e.g. `http://LOCALHOST:80` is the same base URL as `http://localhost`).
+pub fn rebase(url: &Url, from: &str, to: &str) -> Option<Url> {
+    let from = Url::parse(from).ok()?;
+    let mut rebased = Url::parse(to).ok()?;
+
+    if url.origin() != from.origin() {
+        return None;
+    }
+
+    let path = url
+        .path()
+        .strip_prefix(from.path().trim_end_matches('/'))
+        .filter(|path| path.is_empty() || path.starts_with('/'))?;
+
+    rebased.set_path(&format!("{}{path}", rebased.path().trim_end_matches('/')));
+    rebased.set_query(url.query());
+    rebased.set_fragment(url.fragment());
+
+    Some(rebased)
+}
+
+impl PocketBase {
+    /// Sends the reads to the read base URL, if any.
+    pub(crate) fn route(&self, request: &mut Request) {
+        if let Some(read_base_url) = &self.read_base_url
+            && is_read(request)
+            && let Some(url) = rebase(request.url(), &self.base_url, read_base_url)
+        {
+            *request.url_mut() = url;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use reqwest::Url;
+    use serde_json::{Value, json};
+
+    use super::rebase;
+    use crate::PocketBase;
+
+    fn rebased(url: &str, from: &str, to: &str) -> Option<String> {
+        rebase(&Url::parse(url).unwrap(), from, to).map(String::from)
+    }
+
+    #[test]
+    fn rebase_moves_the_path_and_query() {
+        assert_eq!(
+            rebased(
+                "http://primary:8090/api/collections/articles/records?page=2",
+                "http://primary:8090",
+                "http://replica:8090",
+            )
+            .as_deref(),
+            Some("http://replica:8090/api/collections/articles/records?page=2"),
+        );
+    }
+
+    #[test]
+    fn rebase_handles_base_paths() {
+        assert_eq!(
+            rebased(
+                "https://example.com/pb/api/health",
+                "https://example.com/pb/",
+                "https://replica.example.com/pocketbase",
+            )
+            .as_deref(),
+            Some("https://replica.example.com/pocketbase/api/health"),
+        );
+
+        assert_eq!(
+            rebased(
+                "https://example.com/pbx/api/health",
+                "https://example.com/pb",
+                "https://replica.example.com",
+            ),
+            None,
+        );
+    }
+
+    #[test]
+    fn rebase_normalizes_the_base_urls() {
+        for (url, from) in [
+            ("http://localhost:8090/api/health", "http://LOCALHOST:8090"),
+            ("http://host/api/health", "http://host:80"),
+            ("https://host/api/health", "https://host:443"),
+            ("http://host/api/health", "HTTP://host"),
+        ] {
+            assert_eq!(
+                rebased(url, from, "http://replica:8090").as_deref(),
+                Some("http://replica:8090/api/health"),
+                "{from}",
+            );
+        }
+    }
+
+    #[test]
+    fn rebase_ignores_other_origins() {
+        assert_eq!(
+            rebased(
+                "http://other:8090/api/health",
+                "http://primary:8090",
+                "http://replica:8090",
+            ),
+            None,
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_are_routed_from_a_non_normalized_base_url() {
+        let server = MockServer::start_async().await;
+
+        let read = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/collections/articles/records/abc123");
+            then.status(200).json_body(json!({ "id": "abc123" }));
+        });
+
+        // Nothing listens on the primary instance
+        let pb = PocketBase::builder("HTTP://127.0.0.1:1/")
+            .read_base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        pb.collection("articles")
+            .get_one::<Value>("abc123")
+            .call()
+            .await
+            .unwrap();
+
+        read.assert_hits(1);
+    }
+}
@@ -0,0 +1,194 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tokio_util::sync::CancellationToken;
+
+use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+use crate::{ApiResponse, PocketBase};
+
+/// Builder for sending a request to an arbitrary endpoint of the `PocketBase` API.
+///
+/// Created with [`PocketBase::send()`].
+pub struct SendBuilder<'a, T> {
+    client: &'a PocketBase,
+    request: reqwest::RequestBuilder,
+    query_params: Vec<(&'a str, &'a str)>,
+    options: RequestOptions<'a>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl PocketBase {
+    /// Send a request to an arbitrary endpoint, such as a custom route registered with
+    /// `PocketBase` hooks.
+    ///
+    /// The request reuses the base URL, the auth token, the retry policy and the error
+    /// mapping of the client. The path is relative to the base URL.
+    ///
+    /// Empty responses (e.g. `204 No Content`) are deserialized from `null`, so `T` can be
+    /// `()` or an `Option`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use pocketbase_rs::Method;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Stats {
+    ///     articles: u64,
+    /// }
+    ///
+    /// let stats = pb
+    ///     .send::<Stats>(Method::GET, "/api/myapp/stats")
+    ///     .query("period", "week")
+    ///     .call()
+    ///     .await?;
+    ///
+    /// pb.send::<()>(Method::POST, "/api/myapp/publish")
+    ///     .json(&serde_json::json!({ "article": "record_id_123" }))
+    ///     .call()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn send<T: DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+    ) -> SendBuilder<'_, T> {
+        let url = format!("{}/{}", self.base_url, path.trim_start_matches('/'));
+
+        SendBuilder {
+            client: self,
+            request: self.with_authorization_token(self.reqwest_client.request(method, url)),
+            query_params: Vec::new(),
+            options: RequestOptions::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T: DeserializeOwned> SendBuilder<'a, T> {
+    /// Add a query parameter to the request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query("period", "week")
+    /// ```
+    #[must_use]
+    pub fn query(mut self, key: &'a str, value: &'a str) -> Self {
+        self.query_params.push((key, value));
+        self
+    }
+
+    /// Send the given value as the JSON body of the request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .json(&serde_json::json!({ "article": "record_id_123" }))
+    /// ```
+    #[must_use]
+    pub fn json(mut self, body: &impl Serialize) -> Self {
+        self.request = self.request.json(body);
+        self
+    }
+
+    /// Send the given multipart form as the body of the request.
+    #[must_use]
+    pub fn multipart(mut self, form: reqwest::multipart::Form) -> Self {
+        self.request = self.request.multipart(form);
+        self
+    }
+
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(2))
+    /// ```
+    #[must_use]
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    #[must_use]
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    #[must_use]
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request and return the parsed response body.
+    pub async fn call(self) -> Result<T, RequestError> {
+        self.call_with_response()
+            .await
+            .map(|response| response.data)
+    }
+
+    /// Execute the request and return the parsed response body, along with the
+    /// status code and headers of the response.
+    pub async fn call_with_response(self) -> Result<ApiResponse<T>, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<ApiResponse<T>, RequestError> {
+        let request = self
+            .client
+            .send_request(self.options.apply(self.request).query(&self.query_params))
+            .await;
+
+        let response = match request {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => return Err(RequestError::from_response(response).await),
+            Err(error) => return Err(error.into()),
+        };
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|error| RequestError::ParseError(error.to_string()))?;
+
+        let body: &[u8] = if body.is_empty() { b"null" } else { &body };
+
+        let data = serde_json::from_slice::<T>(body)
+            .map_err(|error| RequestError::ParseError(error.to_string()))?;
+
+        Ok(ApiResponse {
+            status,
+            headers,
+            data,
+        })
+    }
+}
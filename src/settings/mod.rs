@@ -0,0 +1,34 @@
+use crate::PocketBase;
+
+pub mod get_all;
+pub mod model;
+pub mod test;
+pub mod update;
+
+/// Provides access to the settings API of a `PocketBase` instance.
+///
+/// Instances of this struct are created using the [`PocketBase::settings`] method.
+/// All operations require the client to be authenticated as a superuser.
+pub struct Settings<'a> {
+    pub(crate) client: &'a PocketBase,
+}
+
+impl PocketBase {
+    /// Creates a new [`Settings`] instance to read and update the application settings.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let pb = PocketBase::new("http://localhost:8090");
+    ///
+    /// pb.admin_auth_with_password("admin@example.com", "YOUR_PASSWORD")
+    ///     .await?;
+    ///
+    /// let settings = pb.settings().get_all().await?;
+    ///
+    /// println!("SMTP enabled: {}", settings.smtp.enabled);
+    /// ```
+    #[must_use]
+    pub const fn settings(&self) -> Settings<'_> {
+        Settings { client: self }
+    }
+}
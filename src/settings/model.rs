@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Represents the application settings of a `PocketBase` instance.
+///
+/// Only the most commonly used sections are typed, every other section
+/// (e.g. `backups`, `rateLimits`, `logs`) is kept as-is in `other`,
+/// so that settings added by newer `PocketBase` versions aren't lost.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AppSettings {
+    /// General application settings.
+    #[serde(default)]
+    pub meta: MetaSettings,
+    /// Mail server settings.
+    #[serde(default)]
+    pub smtp: SmtpSettings,
+    /// Files storage settings.
+    #[serde(default)]
+    pub s3: S3Settings,
+    /// Any other settings section.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// General application settings.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaSettings {
+    /// The application name.
+    #[serde(default)]
+    pub app_name: String,
+    /// The application URL, used in emails.
+    #[serde(default, rename = "appURL")]
+    pub app_url: String,
+    /// The name used as sender of the emails.
+    #[serde(default)]
+    pub sender_name: String,
+    /// The address used as sender of the emails.
+    #[serde(default)]
+    pub sender_address: String,
+    /// Whether the collection create and edit controls are hidden in the dashboard.
+    #[serde(default)]
+    pub hide_controls: bool,
+    /// Any other meta setting.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// Mail server settings.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmtpSettings {
+    /// Whether emails are sent through the SMTP server (instead of `sendmail`).
+    #[serde(default)]
+    pub enabled: bool,
+    /// The SMTP server host.
+    #[serde(default)]
+    pub host: String,
+    /// The SMTP server port.
+    #[serde(default)]
+    pub port: u16,
+    /// The SMTP username.
+    #[serde(default)]
+    pub username: String,
+    /// The SMTP password.
+    ///
+    /// Never returned by `PocketBase`, and not sent when empty so that the current one is kept.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub password: String,
+    /// The SMTP auth method *(`PLAIN` or `LOGIN`)*.
+    #[serde(default)]
+    pub auth_method: String,
+    /// Whether to enforce TLS.
+    #[serde(default)]
+    pub tls: bool,
+    /// Optional domain name or IP address used for the `EHLO`/`HELO` exchange.
+    #[serde(default)]
+    pub local_name: String,
+    /// Any other SMTP setting.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// S3 storage settings.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Settings {
+    /// Whether files are stored on the S3 storage (instead of the local filesystem).
+    #[serde(default)]
+    pub enabled: bool,
+    /// The bucket name.
+    #[serde(default)]
+    pub bucket: String,
+    /// The bucket region.
+    #[serde(default)]
+    pub region: String,
+    /// The S3 endpoint.
+    #[serde(default)]
+    pub endpoint: String,
+    /// The access key.
+    #[serde(default)]
+    pub access_key: String,
+    /// The secret key.
+    ///
+    /// Never returned by `PocketBase`, and not sent when empty so that the current one is kept.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub secret: String,
+    /// Whether to use path-style addressing instead of virtual-hosted-style.
+    #[serde(default)]
+    pub force_path_style: bool,
+    /// Any other S3 setting.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
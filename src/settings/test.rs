@@ -0,0 +1,393 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+
+use super::Settings;
+use crate::error::{BadRequestError, RawResponse};
+use crate::options::{RequestOptions, cancellable};
+use crate::{PocketBase, circuit_breaker};
+
+/// Represents the various errors that can be obtained after a `test_s3` or `test_email` request.
+#[derive(Error, Debug)]
+pub enum SettingsTestError {
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response.
+    ///
+    /// The test itself failed: the S3 storage or the mail server couldn't be reached
+    /// with the current settings. Contains the reason given by `PocketBase`.
+    #[error("The settings test failed, the configuration may be wrong: {0}")]
+    TestFailed(String),
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [400 Bad Request]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/400") HTTP error response.
+    ///
+    /// The test parameters were not validated (e.g. invalid email address or unknown collection).
+    #[error("One or more fields were not validated : {0:?}")]
+    BadRequest(Vec<BadRequestError>),
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [401 Unauthorized]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/401") HTTP error response.
+    ///
+    /// The request requires a superuser authorization token.
+    #[error("The request requires a superuser authorization token.")]
+    Unauthorized,
+    /// Communication with the `PocketBase` API was successful,
+    /// but returned a [403 Forbidden]("https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/403") HTTP error response.
+    ///
+    /// Only superusers can perform this action.
+    #[error("The authorized record is not allowed to perform this action.")]
+    Forbidden,
+    /// Communication with the `PocketBase` API failed.
+    ///
+    /// This could be caused by an internet outage, an error in the link given to the `PocketBase` SDK
+    /// and similar errors.
+    #[error("The communication with the PocketBase API failed: {0}")]
+    Unreachable(String),
+    /// The request was not sent, as the circuit breaker is open after too many connection failures.
+    ///
+    /// See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("The PocketBase API is unreachable, the request was not sent.")]
+    CircuitOpen,
+    /// The request was cancelled through its cancellation token.
+    #[error("The request was cancelled.")]
+    Cancelled,
+    /// The response from the `PocketBase` instance API was unexpected.
+    /// If you think its an error, please [open an issue on GitHub]("https://github.com/fromhorizons/pocketbase-rs/issues").
+    ///
+    /// The status code and raw body of the response are kept for debugging purposes.
+    #[error("An unexpected response was returned by the PocketBase API: {0}")]
+    UnexpectedResponse(RawResponse),
+}
+
+/// The S3 filesystems that can be tested.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum S3Filesystem {
+    /// The storage used for uploaded files.
+    #[default]
+    Storage,
+    /// The storage used for backups.
+    Backups,
+}
+
+/// The email templates that can be tested.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmailTemplate {
+    /// The account verification email.
+    #[default]
+    Verification,
+    /// The password reset email.
+    PasswordReset,
+    /// The email change confirmation email.
+    EmailChange,
+    /// The One-Time Password email.
+    Otp,
+    /// The new login alert email.
+    LoginAlert,
+}
+
+#[derive(Clone, Default, Serialize)]
+struct S3TestRequest {
+    filesystem: S3Filesystem,
+}
+
+#[derive(Clone, Default, Serialize)]
+struct EmailTestRequest<'a> {
+    template: EmailTemplate,
+    email: &'a str,
+    collection: &'a str,
+}
+
+#[derive(Deserialize)]
+struct TestFailureResponse {
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    data: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Builder for testing the S3 connection.
+///
+/// The builder can be awaited directly, or executed through [`SettingsTestS3Builder::call()`].
+pub struct SettingsTestS3Builder<'a> {
+    client: &'a PocketBase,
+    filesystem: S3Filesystem,
+    options: RequestOptions<'a>,
+}
+
+/// Builder for sending a test email.
+///
+/// The builder can be awaited directly, or executed through [`SettingsTestEmailBuilder::call()`].
+pub struct SettingsTestEmailBuilder<'a> {
+    client: &'a PocketBase,
+    template: EmailTemplate,
+    email: &'a str,
+    collection: &'a str,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Settings<'a> {
+    /// Test the S3 connection of the given filesystem with the current settings.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.settings().test_s3(S3Filesystem::Backups).await?;
+    /// ```
+    #[must_use]
+    pub const fn test_s3(&self, filesystem: S3Filesystem) -> SettingsTestS3Builder<'a> {
+        SettingsTestS3Builder {
+            client: self.client,
+            filesystem,
+            options: RequestOptions::new(),
+        }
+    }
+
+    /// Send a test email of the given template to `email`, using the current mail settings.
+    ///
+    /// `collection` is the auth collection whose email templates are used.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// pb.settings()
+    ///     .test_email(EmailTemplate::Verification, "test@example.com", "users")
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn test_email(
+        &self,
+        template: EmailTemplate,
+        email: &'a str,
+        collection: &'a str,
+    ) -> SettingsTestEmailBuilder<'a> {
+        SettingsTestEmailBuilder {
+            client: self.client,
+            template,
+            email,
+            collection,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a> SettingsTestS3Builder<'a> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(30))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<(), SettingsTestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            SettingsTestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<(), SettingsTestError> {
+        let url = format!("{}/api/settings/test/s3", self.client.base_url);
+
+        let body = S3TestRequest {
+            filesystem: self.filesystem,
+        };
+
+        let request = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_post_json(&url, &body)),
+            )
+            .await;
+
+        test_processing(request).await
+    }
+}
+
+impl<'a> IntoFuture for SettingsTestS3Builder<'a> {
+    type Output = Result<(), SettingsTestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
+
+impl<'a> SettingsTestEmailBuilder<'a> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(30))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<(), SettingsTestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            SettingsTestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<(), SettingsTestError> {
+        let url = format!("{}/api/settings/test/email", self.client.base_url);
+
+        let body = EmailTestRequest {
+            template: self.template,
+            email: self.email,
+            collection: self.collection,
+        };
+
+        let request = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_post_json(&url, &body)),
+            )
+            .await;
+
+        test_processing(request).await
+    }
+}
+
+impl<'a> IntoFuture for SettingsTestEmailBuilder<'a> {
+    type Output = Result<(), SettingsTestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
+
+async fn test_processing(
+    request: Result<reqwest::Response, crate::TransportError>,
+) -> Result<(), SettingsTestError> {
+    match request {
+        Ok(response) => match response.status() {
+            _ if circuit_breaker::is_open_response(&response) => {
+                Err(SettingsTestError::CircuitOpen)
+            }
+            reqwest::StatusCode::NO_CONTENT | reqwest::StatusCode::OK => Ok(()),
+
+            reqwest::StatusCode::BAD_REQUEST => {
+                let response = RawResponse::from_response(response).await;
+
+                let Ok(failure) = serde_json::from_str::<TestFailureResponse>(&response.body)
+                else {
+                    return Err(SettingsTestError::UnexpectedResponse(response));
+                };
+
+                // Validation errors of the test parameters come with field level details,
+                // while failed tests only come with a message.
+                if failure.data.is_empty() {
+                    return Err(SettingsTestError::TestFailed(failure.message));
+                }
+
+                let errors = failure
+                    .data
+                    .into_iter()
+                    .map(|(name, error)| BadRequestError {
+                        name,
+                        code: error
+                            .get("code")
+                            .and_then(serde_json::Value::as_str)
+                            .unwrap_or_default()
+                            .to_string(),
+                        message: error
+                            .get("message")
+                            .and_then(serde_json::Value::as_str)
+                            .unwrap_or_default()
+                            .to_string(),
+                    })
+                    .collect();
+
+                Err(SettingsTestError::BadRequest(errors))
+            }
+
+            reqwest::StatusCode::UNAUTHORIZED => Err(SettingsTestError::Unauthorized),
+            reqwest::StatusCode::FORBIDDEN => Err(SettingsTestError::Forbidden),
+
+            _ => Err(SettingsTestError::UnexpectedResponse(
+                RawResponse::from_response(response).await,
+            )),
+        },
+
+        Err(error) => Err(SettingsTestError::Unreachable(error.to_string())),
+    }
+}
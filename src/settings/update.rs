@@ -0,0 +1,136 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use super::Settings;
+use super::model::AppSettings;
+use crate::PocketBase;
+use crate::error::RequestError;
+use crate::options::{RequestOptions, cancellable};
+
+/// Builder for updating the application settings.
+///
+/// The builder can be awaited directly, or executed through [`SettingsUpdateBuilder::call()`].
+pub struct SettingsUpdateBuilder<'a, T> {
+    client: &'a PocketBase,
+    patch: &'a T,
+    options: RequestOptions<'a>,
+}
+
+impl<'a> Settings<'a> {
+    /// Update the application settings and return the new settings.
+    ///
+    /// `patch` can be a full [`AppSettings`] or any serializable partial
+    /// representation of it (e.g. a `serde_json::Value`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let settings = pb
+    ///     .settings()
+    ///     .update(&serde_json::json!({
+    ///         "smtp": {
+    ///             "enabled": true,
+    ///             "host": "smtp.example.com",
+    ///             "port": 587,
+    ///         }
+    ///     }))
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub const fn update<T: Serialize + Send + Sync>(
+        &self,
+        patch: &'a T,
+    ) -> SettingsUpdateBuilder<'a, T> {
+        SettingsUpdateBuilder {
+            client: self.client,
+            patch,
+            options: RequestOptions::new(),
+        }
+    }
+}
+
+impl<'a, T: Serialize + Send + Sync> SettingsUpdateBuilder<'a, T> {
+    /// Override the client timeout for this request only.
+    ///
+    /// The timeout covers the whole request, from connecting until the response body has been read.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .timeout(Duration::from_secs(30))
+    /// ```
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request only.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .header("X-Request-Id", "4f1c2a")
+    /// ```
+    pub fn header(mut self, key: &'a str, value: &'a str) -> Self {
+        self.options.headers.push((key, value));
+        self
+    }
+
+    /// Cancel the request when the given token is cancelled, making it return a `Cancelled` error.
+    ///
+    /// Dropping the future returned by `call()` also aborts the in-flight request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let cancellation_token = CancellationToken::new();
+    ///
+    /// // e.g. from a "Cancel" button handler
+    /// cancellation_token.cancel();
+    ///
+    /// .cancellation_token(cancellation_token.clone())
+    /// ```
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.options.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn call(self) -> Result<AppSettings, RequestError> {
+        let cancellation_token = self.options.cancellation_token.clone();
+
+        cancellable(cancellation_token, self.execute(), || {
+            RequestError::Cancelled
+        })
+        .await
+    }
+
+    async fn execute(self) -> Result<AppSettings, RequestError> {
+        let url = format!("{}/api/settings", self.client.base_url);
+
+        let response = self
+            .client
+            .send_request(
+                self.options
+                    .apply(self.client.request_patch_json(&url, self.patch)),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RequestError::from_response(response).await);
+        }
+
+        response
+            .json::<AppSettings>()
+            .await
+            .map_err(|error| RequestError::ParseError(error.to_string()))
+    }
+}
+
+impl<'a, T: Serialize + Send + Sync> IntoFuture for SettingsUpdateBuilder<'a, T> {
+    type Output = Result<AppSettings, RequestError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.call())
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt::Write as _;
+use std::sync::{Arc, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+use reqwest::{Method, Request};
+
+use crate::PocketBase;
+
+/// A callback computing the signature headers of an outgoing request.
+pub type RequestSigner = Arc<dyn Fn(&SigningRequest<'_>) -> HeaderMap + Send + Sync>;
+
+/// The parts of an outgoing request covered by its signature, given to the request signer.
+///
+/// See [`PocketBase::set_request_signer()`].
+#[derive(Clone, Copy, Debug)]
+pub struct SigningRequest<'a> {
+    /// The HTTP method of the request.
+    pub method: &'a Method,
+    /// The path of the request, along with its query string if any *(example: `/api/collections/articles/records?page=2`)*.
+    pub path: &'a str,
+    /// The SHA-256 hash of the body, as lowercase hexadecimal characters.
+    ///
+    /// A request without a body has the hash of an empty body. `None` for the streamed bodies
+    /// (e.g. a backup upload), which can't be read before being sent.
+    pub body_sha256: Option<&'a str>,
+    /// The time at which the request is signed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl PocketBase {
+    /// Sets a callback signing every outgoing request, e.g. for gateways requiring HMAC-signed requests.
+    ///
+    /// The callback is given the method, path, body hash and timestamp of the request, and
+    /// returns the headers to add to it. It is called right before the request is sent, after
+    /// the [`before_send()`](PocketBase::before_send) hooks, and once per attempt when the
+    /// request is retried, so that the timestamp is always fresh.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use hmac::{Hmac, Mac};
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    /// use sha2::Sha256;
+    ///
+    /// pb.set_request_signer(move |request| {
+    ///     let payload = format!(
+    ///         "{}\n{}\n{}\n{}",
+    ///         request.method,
+    ///         request.path,
+    ///         request.body_sha256.unwrap_or("UNSIGNED-PAYLOAD"),
+    ///         request.timestamp,
+    ///     );
+    ///
+    ///     let mut mac = Hmac::<Sha256>::new_from_slice(&secret).unwrap();
+    ///     mac.update(payload.as_bytes());
+    ///     let signature = hex::encode(mac.finalize().into_bytes());
+    ///
+    ///     let mut headers = HeaderMap::new();
+    ///     headers.insert("X-Signature", HeaderValue::from_str(&signature).unwrap());
+    ///     headers.insert("X-Timestamp", HeaderValue::from(request.timestamp));
+    ///     headers
+    /// });
+    /// ```
+    pub fn set_request_signer(
+        &self,
+        signer: impl Fn(&SigningRequest<'_>) -> HeaderMap + Send + Sync + 'static,
+    ) {
+        *self
+            .request_signer
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(signer));
+    }
+
+    /// Adds the signature headers to the request, if a request signer is set.
+    pub(crate) fn sign(&self, request: &mut Request) {
+        let Some(signer) = self
+            .request_signer
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+        else {
+            return;
+        };
+
+        let url = request.url();
+        let path = url.query().map_or_else(
+            || url.path().to_owned(),
+            |query| format!("{}?{query}", url.path()),
+        );
+
+        // Streamed bodies can't be read without consuming them
+        let body_sha256 = request.body().map_or_else(
+            || Some(sha256_hex(&[])),
+            |body| body.as_bytes().map(sha256_hex),
+        );
+
+        let headers = signer(&SigningRequest {
+            method: request.method(),
+            path: &path,
+            body_sha256: body_sha256.as_deref(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+        });
+
+        request.headers_mut().extend(headers);
+    }
+}
+
+/// Returns the SHA-256 hash of the given bytes, as lowercase hexadecimal characters.
+fn sha256_hex(bytes: &[u8]) -> String {
+    ring::digest::digest(&ring::digest::SHA256, bytes)
+        .as_ref()
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use serde_json::{Value, json};
+
+    use crate::{FailoverPolicy, PocketBase};
+
+    #[tokio::test]
+    async fn multi_valued_signature_headers() {
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/collections/articles/records/abc123")
+                .header("x-signature", "v1=first")
+                .header("x-signature", "v2=second");
+            then.status(200).json_body(json!({ "id": "abc123" }));
+        });
+
+        let pb = PocketBase::new(&server.base_url());
+
+        pb.set_request_signer(|_| {
+            let mut headers = HeaderMap::new();
+            headers.append("X-Signature", HeaderValue::from_static("v1=first"));
+            headers.append("X-Signature", HeaderValue::from_static("v2=second"));
+            headers
+        });
+
+        pb.collection("articles")
+            .get_one::<Value>("abc123")
+            .call()
+            .await
+            .unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn signed_after_failing_over_to_a_replica() {
+        let replica = MockServer::start_async().await;
+
+        let read = replica.mock(|when, then| {
+            when.method(GET)
+                .path("/pb/api/collections/articles/records/abc123")
+                .header(
+                    "x-signed-path",
+                    "/pb/api/collections/articles/records/abc123",
+                );
+            then.status(200).json_body(json!({ "id": "abc123" }));
+        });
+
+        // Nothing listens on the primary instance
+        let pb = PocketBase::builder("http://127.0.0.1:1")
+            .failover(FailoverPolicy::new([format!("{}/pb", replica.base_url())]))
+            .build()
+            .unwrap();
+
+        pb.set_request_signer(|request| {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                "X-Signed-Path",
+                HeaderValue::from_str(request.path).unwrap(),
+            );
+            headers
+        });
+
+        pb.collection("articles")
+            .get_one::<Value>("abc123")
+            .call()
+            .await
+            .unwrap();
+
+        read.assert_hits(1);
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+use std::ops::Deref;
+
+/// A `PocketBase` sort expression, made of one or more fields and their order.
+///
+/// A `Sort` dereferences to `&str`, so it can be given as-is to the `.sort()`
+/// method of the list builders, and reused across queries.
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::Sort;
+///
+/// let sort = Sort::desc("created").then_asc("id"); // "-created,id"
+///
+/// let articles = pb
+///     .collection("articles")
+///     .get_list::<Article>()
+///     .sort(&sort)
+///     .call()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sort {
+    expression: String,
+}
+
+impl Sort {
+    /// Sort by the given field in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the field name is empty or contains invalid characters.
+    #[must_use]
+    pub fn asc(field: &str) -> Self {
+        Self {
+            expression: String::new(),
+        }
+        .push("", field)
+    }
+
+    /// Sort by the given field in descending order.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the field name is empty or contains invalid characters.
+    #[must_use]
+    pub fn desc(field: &str) -> Self {
+        Self {
+            expression: String::new(),
+        }
+        .push("-", field)
+    }
+
+    /// Sort the records in a random order.
+    #[must_use]
+    pub fn random() -> Self {
+        Self {
+            expression: "@random".to_owned(),
+        }
+    }
+
+    /// Then sort by the given field in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the field name is empty or contains invalid characters.
+    #[must_use]
+    pub fn then_asc(self, field: &str) -> Self {
+        self.push("", field)
+    }
+
+    /// Then sort by the given field in descending order.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the field name is empty or contains invalid characters.
+    #[must_use]
+    pub fn then_desc(self, field: &str) -> Self {
+        self.push("-", field)
+    }
+
+    fn push(mut self, prefix: &str, field: &str) -> Self {
+        assert!(!field.is_empty(), "Sort field cannot be empty");
+
+        // Fields can be nested relation paths (`author.name`) or special fields (`@rowid`)
+        assert!(
+            field
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '@')),
+            "Sort field contains invalid characters. Only alphanumeric characters, underscores, dots and `@` are allowed"
+        );
+
+        if !self.expression.is_empty() {
+            self.expression.push(',');
+        }
+
+        self.expression.push_str(prefix);
+        self.expression.push_str(field);
+        self
+    }
+
+    /// Returns the rendered sort expression.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.expression
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+impl Deref for Sort {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.expression
+    }
+}
+
+impl AsRef<str> for Sort {
+    fn as_ref(&self) -> &str {
+        &self.expression
+    }
+}
+
+impl From<Sort> for String {
+    fn from(sort: Sort) -> Self {
+        sort.expression
+    }
+}
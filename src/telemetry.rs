@@ -0,0 +1,64 @@
+use std::time::Instant;
+
+use reqwest::{RequestBuilder, Response};
+use tracing::Instrument;
+
+use crate::{PocketBase, TransportError, circuit_breaker};
+
+/// Sends the request inside a `pocketbase.request` span, recording its method,
+/// endpoint, collection, status code and latency.
+///
+/// Only the path of the URL is recorded, as the query string may contain
+/// sensitive data (e.g. file tokens).
+pub async fn send_instrumented(
+    client: &PocketBase,
+    request: RequestBuilder,
+) -> Result<Response, TransportError> {
+    let (http_client, request) = request.build_split();
+    let request = request?;
+
+    let endpoint = request.url().path().to_owned();
+
+    let span = tracing::info_span!(
+        "pocketbase.request",
+        method = %request.method(),
+        endpoint = %endpoint,
+        collection = collection_name(&endpoint),
+        status = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    );
+
+    let start = Instant::now();
+
+    let result = client
+        .dispatch(RequestBuilder::from_parts(http_client, request))
+        .instrument(span.clone())
+        .await;
+
+    #[allow(clippy::cast_possible_truncation)]
+    span.record("latency_ms", start.elapsed().as_millis() as u64);
+
+    match &result {
+        Ok(response) if circuit_breaker::is_open_response(response) => {
+            span.in_scope(|| {
+                tracing::debug!("PocketBase request not sent, the circuit breaker is open");
+            });
+        }
+        Ok(response) => {
+            span.record("status", response.status().as_u16());
+        }
+        Err(error) => {
+            span.in_scope(|| tracing::debug!(error = %error, "PocketBase request failed"));
+        }
+    }
+
+    result
+}
+
+/// Extracts the collection name from a `/api/collections/{collection}/...` path.
+fn collection_name(path: &str) -> Option<&str> {
+    path.strip_prefix("/api/collections/")?
+        .split('/')
+        .next()
+        .filter(|name| !name.is_empty())
+}
@@ -0,0 +1,62 @@
+//! Fixtures shared by the unit tests.
+
+use httpmock::prelude::*;
+use serde_json::json;
+
+use crate::PocketBase;
+
+/// Starts a mock server answering `method path` with the given status and an empty
+/// `PocketBase` error payload, and returns it along with a client using it.
+///
+/// The server must be kept alive until the request is sent.
+pub async fn error_response(
+    method: impl Into<httpmock::Method>,
+    path: &str,
+    status: u16,
+) -> (MockServer, PocketBase) {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(method).path(path);
+        then.status(status)
+            .json_body(json!({ "status": status, "message": "", "data": {} }));
+    });
+
+    let pb = PocketBase::new(&server.base_url());
+
+    (server, pb)
+}
+
+/// Asserts that each given status, returned by `method path`, is mapped to the given error
+/// by the request built with `call`.
+///
+/// # Example
+/// ```rust,ignore
+/// assert_error_statuses!(
+///     GET,
+///     "/api/collections/articles/records/abc123",
+///     |pb| pb.collection("articles").get_one::<Value>("abc123").call(),
+///     {
+///         401 => RequestError::Unauthorized,
+///         429 => RequestError::TooManyRequests,
+///     }
+/// );
+/// ```
+macro_rules! assert_error_statuses {
+    ($method:expr, $path:expr, |$pb:ident| $call:expr, { $($status:literal => $error:pat),+ $(,)? }) => {
+        $({
+            let (_server, $pb) =
+                $crate::test_utils::error_response($method, $path, $status).await;
+
+            let result = $call.await.map(|_| ());
+
+            assert!(
+                matches!(result, Err($error)),
+                "status {} returned {result:?}",
+                $status
+            );
+        })+
+    };
+}
+
+pub(crate) use assert_error_statuses;
@@ -0,0 +1,350 @@
+//! Utilities for unit testing code that uses a [`PocketBase`] client, without a running server.
+//!
+//! Requires the `testing` feature.
+
+use std::sync::{Arc, Mutex, PoisonError};
+
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, Request, Response};
+use serde::Serialize;
+
+use crate::PocketBase;
+use crate::transport::{HttpTransport, TransportFuture};
+
+/// A mocked `PocketBase` server, answering the requests of its clients with canned responses.
+///
+/// Responses are registered per endpoint with [`MockPocketBase::on()`]. When several
+/// endpoints match a request, the last registered one is used, so that a test can override
+/// the responses set up by a shared fixture. Requests matching no endpoint are answered with
+/// a `404 Not Found` error.
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::Method;
+/// use pocketbase_rs::testing::MockPocketBase;
+///
+/// let mock = MockPocketBase::new();
+///
+/// mock.on(Method::GET, "/api/collections/articles/records/record_id_123")
+///     .respond_json(200, &serde_json::json!({ "id": "record_id_123", "title": "Hello" }));
+///
+/// let pb = mock.client();
+///
+/// let article = pb
+///     .collection("articles")
+///     .get_one::<Article>("record_id_123")
+///     .call()
+///     .await?;
+///
+/// assert_eq!(article.title, "Hello");
+/// assert_eq!(mock.requests().len(), 1);
+/// ```
+#[derive(Clone, Default)]
+pub struct MockPocketBase {
+    state: Arc<MockState>,
+}
+
+#[derive(Default)]
+struct MockState {
+    routes: Mutex<Vec<Route>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+struct Route {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    response: MockResponse,
+}
+
+/// An endpoint being registered on a [`MockPocketBase`], created with [`MockPocketBase::on()`].
+///
+/// The endpoint is only registered once its response is set.
+pub struct MockEndpoint<'a> {
+    mock: &'a MockPocketBase,
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+}
+
+/// A canned response returned by a [`MockPocketBase`].
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    status: u16,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+/// A request received by a [`MockPocketBase`].
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    /// The HTTP method of the request.
+    pub method: Method,
+    /// The path of the request, without the query string.
+    pub path: String,
+    /// The decoded query parameters of the request.
+    pub query: Vec<(String, String)>,
+    /// The headers of the request.
+    pub headers: HeaderMap,
+    /// The body of the request, empty for streamed bodies (e.g. multipart forms).
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Parses the body of the request as JSON.
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.body)
+    }
+}
+
+impl MockPocketBase {
+    /// Creates a mock without any registered endpoint.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a client sending its requests to this mock.
+    ///
+    /// The clients share the registered endpoints and the recorded requests of the mock.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the underlying HTTP client could not be created.
+    #[must_use]
+    pub fn client(&self) -> PocketBase {
+        PocketBase::builder("http://pocketbase.mock")
+            .transport(self.clone())
+            .build()
+            .expect("the mocked client must be valid")
+    }
+
+    /// Starts registering the response of the given endpoint.
+    ///
+    /// The path is matched exactly, without the query string. Query parameters can be
+    /// required with [`MockEndpoint::query_param()`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// mock.on(Method::GET, "/api/collections/articles/records")
+    ///     .query_param("filter", "published=true")
+    ///     .respond_json(200, &serde_json::json!({
+    ///         "page": 1,
+    ///         "perPage": 30,
+    ///         "totalItems": 0,
+    ///         "totalPages": 0,
+    ///         "items": [],
+    ///     }));
+    /// ```
+    #[must_use]
+    pub fn on(&self, method: Method, path: &str) -> MockEndpoint<'_> {
+        MockEndpoint {
+            mock: self,
+            method,
+            path: path.to_owned(),
+            query: Vec::new(),
+        }
+    }
+
+    /// Returns the requests received so far, in order.
+    #[must_use]
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Removes the registered endpoints and the recorded requests.
+    pub fn reset(&self) {
+        self.state
+            .routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Records the request and returns the response of the matching endpoint.
+    fn respond(&self, request: &Request) -> MockResponse {
+        let recorded = RecordedRequest {
+            method: request.method().clone(),
+            path: request.url().path().to_owned(),
+            query: request
+                .url()
+                .query_pairs()
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(reqwest::Body::as_bytes)
+                .map(<[u8]>::to_vec)
+                .unwrap_or_default(),
+        };
+
+        let response = self
+            .state
+            .routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .rev()
+            .find(|route| route.matches(&recorded))
+            .map_or_else(
+                || {
+                    MockResponse::json(
+                        404,
+                        &serde_json::json!({
+                            "status": 404,
+                            "message": format!("No mocked response for {} {}.", recorded.method, recorded.path),
+                            "data": {},
+                        }),
+                    )
+                },
+                |route| route.response.clone(),
+            );
+
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(recorded);
+
+        response
+    }
+}
+
+impl HttpTransport for MockPocketBase {
+    fn execute(&self, request: Request) -> TransportFuture<'_> {
+        let response = self.respond(&request).into_response();
+
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+impl Route {
+    fn matches(&self, request: &RecordedRequest) -> bool {
+        self.method == request.method
+            && self.path == request.path
+            && self.query.iter().all(|param| request.query.contains(param))
+    }
+}
+
+impl MockEndpoint<'_> {
+    /// Only match the requests with the given query parameter.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .query_param("expand", "author")
+    /// ```
+    #[must_use]
+    pub fn query_param(mut self, key: &str, value: &str) -> Self {
+        self.query.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Answer the matching requests with the given response.
+    pub fn respond(self, response: MockResponse) {
+        self.mock
+            .state
+            .routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Route {
+                method: self.method,
+                path: self.path,
+                query: self.query,
+                response,
+            });
+    }
+
+    /// Answer the matching requests with the given status code and JSON body.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .respond_json(400, &serde_json::json!({
+    ///     "status": 400,
+    ///     "message": "Failed to create record.",
+    ///     "data": { "title": { "code": "validation_required", "message": "Missing required value." } },
+    /// }))
+    /// ```
+    pub fn respond_json(self, status: u16, body: &impl Serialize) {
+        self.respond(MockResponse::json(status, body));
+    }
+
+    /// Answer the matching requests with the given status code and an empty body,
+    /// e.g. `204` for a deletion.
+    pub fn respond_status(self, status: u16) {
+        self.respond(MockResponse::new(status));
+    }
+}
+
+impl MockResponse {
+    /// Creates a response with the given status code and an empty body.
+    #[must_use]
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Creates a response with the given status code and JSON body.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the body can't be serialized to JSON.
+    #[must_use]
+    pub fn json(status: u16, body: &impl Serialize) -> Self {
+        let body = serde_json::to_vec(body).expect("the mocked response body must be valid JSON");
+
+        Self::new(status)
+            .header("Content-Type", "application/json")
+            .body(body)
+    }
+
+    /// Adds a header to the response.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the name or the value of the header is invalid.
+    #[must_use]
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        let name = HeaderName::from_bytes(key.as_bytes()).expect("invalid mocked header name");
+        let value = HeaderValue::from_str(value).expect("invalid mocked header value");
+
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Sets the raw body of the response.
+    #[must_use]
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    fn into_response(self) -> Response {
+        let mut response = http::Response::new(self.body);
+
+        *response.status_mut() = http::StatusCode::from_u16(self.status)
+            .unwrap_or(http::StatusCode::INTERNAL_SERVER_ERROR);
+        *response.headers_mut() = self.headers;
+
+        if !response.headers().contains_key(CONTENT_TYPE) && !response.body().is_empty() {
+            response.headers_mut().insert(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/octet-stream"),
+            );
+        }
+
+        Response::from(response)
+    }
+}
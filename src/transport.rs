@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::fmt;
+use std::pin::Pin;
+
+use reqwest::{Request, Response, StatusCode};
+
+/// The future returned by [`HttpTransport::execute()`].
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Response, TransportError>> + Send + 'a>>;
+
+/// Sends the HTTP requests of a [`PocketBase`](crate::PocketBase) client.
+///
+/// The requests are still built with `reqwest` types, but a custom transport decides how they
+/// are sent, e.g. through another HTTP client or a test double. By default, the requests are
+/// sent with the `reqwest::Client` of the client.
+///
+/// The auth token, the hooks, the retry policy and the request IDs are applied before the
+/// request reaches the transport. A transport that fails to send a request returns a
+/// [`TransportError`], created from any error type.
+///
+/// # Example
+/// ```rust,ignore
+/// use pocketbase_rs::{HttpTransport, TransportFuture};
+///
+/// struct LoggingTransport(reqwest::Client);
+///
+/// impl HttpTransport for LoggingTransport {
+///     fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+///         println!("{} {}", request.method(), request.url());
+///         HttpTransport::execute(&self.0, request)
+///     }
+/// }
+///
+/// let pb = PocketBase::builder("http://localhost:8090")
+///     .transport(LoggingTransport(reqwest::Client::new()))
+///     .build()?;
+/// ```
+pub trait HttpTransport: Send + Sync {
+    /// Sends the request and returns its response.
+    fn execute(&self, request: Request) -> TransportFuture<'_>;
+}
+
+impl HttpTransport for reqwest::Client {
+    fn execute(&self, request: Request) -> TransportFuture<'_> {
+        let response = Self::execute(self, request);
+
+        Box::pin(async move { Ok(response.await?) })
+    }
+}
+
+/// The error of a request that could not be sent, or whose response could not be received.
+///
+/// Wraps the error of the `reqwest::Client`, or the one returned by a custom [`HttpTransport`].
+/// The retry policy, the failover and the circuit breaker treat the errors of a custom
+/// transport as connection failures.
+///
+/// # Example
+/// ```rust,ignore
+/// fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+///     Box::pin(async move {
+///         let response = self.send_with_ureq(request).map_err(TransportError::new)?;
+///         // ...
+///     })
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TransportError(Box<dyn Error + Send + Sync>);
+
+impl TransportError {
+    /// Wraps the error of a custom transport.
+    pub fn new(error: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self(error.into())
+    }
+
+    /// Returns the error of the `reqwest::Client`, if the request was sent with it.
+    #[must_use]
+    pub fn as_reqwest(&self) -> Option<&reqwest::Error> {
+        self.0.downcast_ref()
+    }
+
+    /// Returns `true` if the request timed out.
+    #[must_use]
+    pub fn is_timeout(&self) -> bool {
+        self.as_reqwest().is_some_and(reqwest::Error::is_timeout)
+    }
+
+    /// Returns `true` if the connection failed, or if a custom transport failed.
+    #[must_use]
+    pub fn is_connect(&self) -> bool {
+        self.as_reqwest().is_none_or(reqwest::Error::is_connect)
+    }
+
+    /// Returns the status code of the response, for the errors raised from a response.
+    #[must_use]
+    pub fn status(&self) -> Option<StatusCode> {
+        self.as_reqwest().and_then(reqwest::Error::status)
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Error for TransportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(error: reqwest::Error) -> Self {
+        Self(Box::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use reqwest::Request;
+    use serde_json::Value;
+
+    use super::{HttpTransport, TransportError, TransportFuture};
+    use crate::{PocketBase, RequestError, RetryPolicy};
+
+    struct FailingTransport(Arc<AtomicUsize>);
+
+    impl HttpTransport for FailingTransport {
+        fn execute(&self, _request: Request) -> TransportFuture<'_> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+
+            Box::pin(async { Err(TransportError::new("connection reset")) })
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_transport_errors_are_connection_failures() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        let pb = PocketBase::builder("http://localhost:8090")
+            .transport(FailingTransport(attempts.clone()))
+            .retry(RetryPolicy {
+                max_retries: 2,
+                backoff: Duration::ZERO,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let result = pb
+            .collection("articles")
+            .get_one::<Value>("record_id_123")
+            .call()
+            .await;
+
+        assert!(matches!(result, Err(RequestError::Unreachable)));
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+    }
+}